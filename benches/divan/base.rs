@@ -13,7 +13,7 @@ fn push_pop_local(b: divan::Bencher) {
     let buf = LocalHeapRB::default(BUFFER_SIZE);
     let (mut prod, mut cons) = buf.split();
 
-    prod.push_slice(&[1; BUFFER_SIZE / 2]);
+    prod.push_slice(&[1; BUFFER_SIZE / 2]).unwrap();
 
     b.bench_local(|| {
         prod.push(1).unwrap();
@@ -26,7 +26,7 @@ fn push_pop_shared(b: divan::Bencher) {
     let buf = LocalHeapRB::default(BUFFER_SIZE);
     let (mut prod, mut cons) = buf.split();
 
-    prod.push_slice(&[1; BUFFER_SIZE / 2]);
+    prod.push_slice(&[1; BUFFER_SIZE / 2]).unwrap();
 
     b.bench_local(|| {
         prod.push(1).unwrap();
@@ -40,7 +40,7 @@ fn push_pop_x100(b: divan::Bencher) {
 
     let (mut prod, mut cons) = buf.split();
 
-    prod.push_slice(&[1; BUFFER_SIZE / 2]);
+    prod.push_slice(&[1; BUFFER_SIZE / 2]).unwrap();
 
     b.bench_local(|| {
         for _ in 0..BATCH_SIZE {
@@ -58,7 +58,7 @@ fn push_pop_x100_local(b: divan::Bencher) {
 
     let (mut prod, mut cons) = buf.split();
 
-    prod.push_slice(&[1; BUFFER_SIZE / 2]);
+    prod.push_slice(&[1; BUFFER_SIZE / 2]).unwrap();
 
     b.bench_local(|| {
         for _ in 0..BATCH_SIZE {
@@ -12,7 +12,7 @@ fn advance(b: divan::Bencher) {
     let buf = SharedHeapRB::default(BUFFER_SIZE);
     let (mut prod, mut cons) = buf.split();
 
-    prod.push_slice(&[1; BUFFER_SIZE / 2]);
+    prod.push_slice(&[1; BUFFER_SIZE / 2]).unwrap();
 
     b.bench_local(|| {
         unsafe {
@@ -29,9 +29,9 @@ fn available(b: divan::Bencher) {
     let buf = SharedHeapRB::default(BUFFER_SIZE);
     let (mut prod, mut cons) = buf.split();
 
-    prod.push_slice(&[0; BUFFER_SIZE / 4]);
+    prod.push_slice(&[0; BUFFER_SIZE / 4]).unwrap();
     cons.reset_index();
-    prod.push_slice(&[1; BUFFER_SIZE / 2]);
+    prod.push_slice(&[1; BUFFER_SIZE / 2]).unwrap();
 
     b.bench_local(|| {
         black_box(prod.available());
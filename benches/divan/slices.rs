@@ -12,12 +12,12 @@ fn slice_x10(b: divan::Bencher) {
     let buf = SharedHeapRB::default(BUFFER_SIZE);
     let (mut prod, mut cons) = buf.split();
 
-    prod.push_slice(&[1; BUFFER_SIZE / 2]);
+    prod.push_slice(&[1; BUFFER_SIZE / 2]).unwrap();
 
     let mut data = [1; 10];
     b.bench_local(|| {
-        prod.push_slice(&data);
-        cons.copy_slice(&mut data);
+        prod.push_slice(&data).unwrap();
+        cons.copy_slice(&mut data).unwrap();
         black_box(data);
     });
 }
@@ -27,12 +27,12 @@ fn slice_x100(b: divan::Bencher) {
     let buf = SharedHeapRB::default(BUFFER_SIZE);
     let (mut prod, mut cons) = buf.split();
 
-    prod.push_slice(&[1; BUFFER_SIZE / 2]);
+    prod.push_slice(&[1; BUFFER_SIZE / 2]).unwrap();
 
     let mut data = [1; 100];
     b.bench_local(|| {
-        prod.push_slice(&data);
-        cons.copy_slice(&mut data);
+        prod.push_slice(&data).unwrap();
+        cons.copy_slice(&mut data).unwrap();
         black_box(data);
     });
 }
@@ -42,12 +42,12 @@ fn slice_x1000_local(b: divan::Bencher) {
     let buf = SharedHeapRB::default(BUFFER_SIZE);
     let (mut prod, mut cons) = buf.split();
 
-    prod.push_slice(&[1; BUFFER_SIZE / 2]);
+    prod.push_slice(&[1; BUFFER_SIZE / 2]).unwrap();
 
     let mut data = [1; 1000];
     b.bench_local(|| {
-        prod.push_slice(&data);
-        cons.copy_slice(&mut data);
+        prod.push_slice(&data).unwrap();
+        cons.copy_slice(&mut data).unwrap();
         black_box(data);
     });
 }
@@ -57,12 +57,12 @@ fn slice_x1000(b: divan::Bencher) {
     let buf = SharedHeapRB::default(BUFFER_SIZE);
     let (mut prod, mut cons) = buf.split();
 
-    prod.push_slice(&[1; BUFFER_SIZE / 2]);
+    prod.push_slice(&[1; BUFFER_SIZE / 2]).unwrap();
 
     let mut data = [1; 1000];
     b.bench_local(|| {
-        prod.push_slice(&data);
-        cons.copy_slice(&mut data);
+        prod.push_slice(&data).unwrap();
+        cons.copy_slice(&mut data).unwrap();
         black_box(data);
     });
 }
@@ -72,12 +72,12 @@ fn slice_x1000_clone(b: divan::Bencher) {
     let buf = SharedHeapRB::default(BUFFER_SIZE);
     let (mut prod, mut cons) = buf.split();
 
-    prod.push_slice_clone(&[1; BUFFER_SIZE / 2]);
+    prod.push_slice_clone(&[1; BUFFER_SIZE / 2]).unwrap();
 
     let mut data = [1; 1000];
     b.bench_local(|| {
-        prod.push_slice_clone(&data);
-        cons.clone_slice(&mut data);
+        prod.push_slice_clone(&data).unwrap();
+        cons.clone_slice(&mut data).unwrap();
         black_box(data);
     });
 }
@@ -87,12 +87,12 @@ fn slice_xbuf_size(b: divan::Bencher) {
     let buf = SharedHeapRB::default(BUFFER_SIZE);
     let (mut prod, mut cons) = buf.split();
 
-    prod.push_slice(&[1; BUFFER_SIZE / 2]);
+    prod.push_slice(&[1; BUFFER_SIZE / 2]).unwrap();
 
     let mut data = [1; BUFFER_SIZE - 1];
     b.bench_local(|| {
-        prod.push_slice(&data);
-        cons.copy_slice(&mut data);
+        prod.push_slice(&data).unwrap();
+        cons.copy_slice(&mut data).unwrap();
         black_box(data);
     });
 }
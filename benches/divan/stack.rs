@@ -14,7 +14,7 @@ fn push_pop_x100(b: divan::Bencher) {
 
     let (mut prod, mut cons) = buf.split();
 
-    prod.push_slice(&[1; BUFFER_SIZE / 2]);
+    prod.push_slice(&[1; BUFFER_SIZE / 2]).unwrap();
 
     b.bench_local(|| {
         for _ in 0..BATCH_SIZE {
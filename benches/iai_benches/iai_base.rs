@@ -12,7 +12,7 @@ pub fn push_pop_local(value: u64) {
     let buf = LocalHeapRB::default(BUFFER_SIZE);
     let (mut prod, mut cons) = buf.split();
 
-    prod.push_slice(&[1; BUFFER_SIZE / 2]);
+    prod.push_slice(&[1; BUFFER_SIZE / 2]).unwrap();
 
     for _ in 0..value {
         prod.push(1).unwrap();
@@ -26,7 +26,7 @@ pub fn push_pop_shared(value: u64) {
     let buf = LocalHeapRB::default(BUFFER_SIZE);
     let (mut prod, mut cons) = buf.split();
 
-    prod.push_slice(&[1; BUFFER_SIZE / 2]);
+    prod.push_slice(&[1; BUFFER_SIZE / 2]).unwrap();
 
     for _ in 0..value {
         prod.push(1).unwrap();
@@ -76,12 +76,12 @@ fn slice_x10(value: u64) {
     let buf = LocalHeapRB::default(BUFFER_SIZE);
     let (mut prod, mut cons) = buf.split();
 
-    prod.push_slice(&[1; BUFFER_SIZE / 2]);
+    prod.push_slice(&[1; BUFFER_SIZE / 2]).unwrap();
 
     let mut data = [1; 10];
     for _ in 0..value {
-        prod.push_slice(&data);
-        cons.copy_slice(&mut data);
+        prod.push_slice(&data).unwrap();
+        cons.copy_slice(&mut data).unwrap();
         black_box(data);
     }
 }
@@ -92,12 +92,12 @@ fn slice_x100(value: u64) {
     let buf = LocalHeapRB::default(BUFFER_SIZE);
     let (mut prod, mut cons) = buf.split();
 
-    prod.push_slice(&[1; BUFFER_SIZE / 2]);
+    prod.push_slice(&[1; BUFFER_SIZE / 2]).unwrap();
 
     let mut data = [1; 100];
     for _ in 0..value {
-        prod.push_slice(&data);
-        cons.copy_slice(&mut data);
+        prod.push_slice(&data).unwrap();
+        cons.copy_slice(&mut data).unwrap();
         black_box(data);
     }
 }
@@ -37,7 +37,7 @@ fn main() {
         let mut acc = vec![0f32; delay_samples];
 
         while !stop_clone.load(Relaxed) {
-            if let Some((h, t)) = work.get_mut_slice_exact(delay_samples) {
+            if let Ok((h, t)) = work.get_mut_slice_exact(delay_samples) {
                 let len = h.len() + t.len();
                 h.swap_with_slice(&mut acc[..h.len()]);
                 t.swap_with_slice(&mut acc[h.len()..]);
@@ -59,7 +59,7 @@ fn main() {
         .build_input_stream(
             &in_cfg,
             move |slice: &[f32], _info: &InputCallbackInfo| {
-                if prod.push_slice(slice).is_none() {
+                if prod.push_slice(slice).is_err() {
                     println!("Input iter fell behind!");
                 }
             },
@@ -74,7 +74,7 @@ fn main() {
             move |slice: &mut [f32], _info: &OutputCallbackInfo| {
                 let len = slice.len();
 
-                if let Some((h, t)) = cons.peek_slice(len) {
+                if let Ok((h, t)) = cons.peek_slice(len) {
                     slice[..h.len()].copy_from_slice(h);
                     slice[h.len()..].copy_from_slice(t);
 
@@ -119,7 +119,7 @@ async fn main() {
 
         // (Optionally, detach the worker and) do something...
         let mut detached = work.detach();
-        if let Some(x) = detached.get_mut().await {
+        if let Ok(x) = detached.get_mut().await {
             *x += 1;
             unsafe {
                 detached.advance(1);
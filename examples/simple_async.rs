@@ -11,9 +11,9 @@ async fn main() {
     let buf = AsyncHeapRBMut::from(vec![0; BUFFER_SIZE + 1]);
     let (mut as_prod, mut as_work, mut as_cons) = buf.split_async_mut();
 
-    as_prod.push(1).await;
+    as_prod.push(1).await.unwrap();
 
-    if let Some(res) = as_work.get_mut().await {
+    if let Ok(res) = as_work.get_mut().await {
         *res += 1;
         unsafe {
             as_work.advance(1);
@@ -27,10 +27,10 @@ async fn main() {
     }
 
     let slice: Vec<i32> = (0..BUFFER_SIZE as i32 / 2).collect();
-    as_prod.push_slice(&slice).await;
+    as_prod.push_slice(&slice).await.unwrap();
 
     #[cfg(not(all(feature = "vmem", unix)))]
-    if let Some((h, t)) = as_work.get_mut_slice_avail().await {
+    if let Ok((h, t)) = as_work.get_mut_slice_avail().await {
         let len = h.len() + t.len();
 
         for x in h.iter_mut().chain(t) {
@@ -42,7 +42,7 @@ async fn main() {
         }
     }
 
-    if let Some((h, t)) = as_cons.peek_available().await {
+    if let Ok((h, t)) = as_cons.peek_available().await {
         for (x, y) in h.iter().chain(t).zip(&slice) {
             assert_eq!(*x, y + 1);
         }
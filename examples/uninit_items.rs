@@ -6,23 +6,19 @@ fn main() {
 
     const RB_SIZE: usize = 4095;
 
-    // Indices from 0 to RB_SIZE+1 are uninitialised
-    let buf = unsafe { SharedHeapRB::new_zeroed(RB_SIZE + 1) };
-    let (mut prod, mut cons) = buf.split();
-
     let slice = (0..RB_SIZE).map(Rc::new).collect::<Vec<Rc<usize>>>();
 
-    for x in &slice {
-        prod.push_init(x.clone()).unwrap();
-    } // RB_SIZE indices out of RB_SIZE+1 are initialised.
+    // Indices from 0 to RB_SIZE+1 are uninitialised, so the buffer cannot be split yet.
+    let mut buf = SharedHeapRB::new_zeroed(RB_SIZE + 1);
+    buf.push_iter(slice.iter().cloned()); // RB_SIZE indices out of RB_SIZE+1 are initialised.
 
-    unsafe {
-        cons.advance(RB_SIZE);
-    }
+    // All indices are now initialised, so the buffer can be split and normal methods can be used.
+    let buf = buf.fill_with(|| Rc::new(0));
+    let (mut prod, mut cons) = buf.split();
 
     for x in &slice {
-        prod.push_init(x.clone()).unwrap();
-    } // All indices are now initialised. It would be now safe to use normal methods from prod instead of `*_init` ones.
+        prod.push(x.clone()).unwrap();
+    }
 
     for _ in &slice {
         unsafe {
@@ -54,6 +50,19 @@ fn main() {
 
     drop(prod);
     drop(cons);
+
+    // A zeroed buffer can also be split right away: items are then written by an `UninitProdIter`,
+    // which can be converted into a normal producer once it has lapped the whole storage.
+    let (mut prod, mut cons) = SharedHeapRB::new_zeroed(RB_SIZE + 1).split();
+
+    prod.push_slice_clone(&slice).unwrap(); // RB_SIZE indices out of RB_SIZE+1 are initialised.
+    unsafe {
+        cons.advance(RB_SIZE);
+    }
+    prod.push(Rc::new(0)).unwrap(); // All indices are now initialised.
+
+    let mut prod = prod.into_init().ok().unwrap();
+    prod.push(Rc::new(1)).unwrap();
 }
 
 #[cfg(not(feature = "alloc"))]
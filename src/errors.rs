@@ -0,0 +1,191 @@
+use core::error::Error;
+use core::fmt::{Debug, Display, Formatter};
+
+/// Error returned when pushing an item fails.
+///
+/// Both variants give back the item which could not be pushed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PushError<T> {
+    /// The buffer is full. Pushing may succeed later, once the consumer has made room.
+    Full(T),
+    /// The iterators following the producer have been dropped, so that pushing is pointless.
+    Disconnected(T),
+}
+
+impl<T> PushError<T> {
+    /// Returns the item which could not be pushed.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        match self {
+            Self::Full(v) | Self::Disconnected(v) => v,
+        }
+    }
+
+    /// Returns `true` if the buffer was full.
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        matches!(self, Self::Full(_))
+    }
+
+    /// Returns `true` if the producer was disconnected.
+    #[inline]
+    pub fn is_disconnected(&self) -> bool {
+        matches!(self, Self::Disconnected(_))
+    }
+}
+
+impl<T> Display for PushError<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Full(_) => f.write_str("pushing into a full buffer"),
+            Self::Disconnected(_) => f.write_str("pushing into a disconnected buffer"),
+        }
+    }
+}
+
+impl<T: Debug> Error for PushError<T> {}
+
+/// Error returned when popping an item fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PopError {
+    /// The buffer is empty. Popping may succeed later, once the producer has pushed new items.
+    Empty,
+    /// The iterators preceding the consumer have been dropped and every item they released
+    /// has been read, so that the buffer is going to stay empty.
+    Disconnected,
+}
+
+impl Display for PopError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Empty => f.write_str("popping from an empty buffer"),
+            Self::Disconnected => f.write_str("popping from a disconnected buffer"),
+        }
+    }
+}
+
+impl Error for PopError {}
+
+/// Error returned by operations involving more than one item, such as pushing or copying a slice.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SliceError {
+    /// Fewer than `requested` items were available.
+    /// The operation may succeed later, once the other iterators have moved.
+    Insufficient {
+        /// Number of items needed by the operation.
+        requested: usize,
+        /// Number of items available when the operation was attempted.
+        available: usize,
+    },
+    /// The iterators this one depends on have been dropped,
+    /// so that `requested` items are never going to be available.
+    Disconnected,
+}
+
+impl Display for SliceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Insufficient {
+                requested,
+                available,
+            } => write!(
+                f,
+                "requested {requested} items, but only {available} are available"
+            ),
+            Self::Disconnected => f.write_str("the buffer is disconnected"),
+        }
+    }
+}
+
+impl Error for SliceError {}
+
+/// Error returned by framed iterators, such as [`FramedProd`](crate::iterators::FramedProd)
+/// and [`FramedCons`](crate::iterators::FramedCons).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameError {
+    /// The frame is longer than `max` bytes.
+    TooLarge {
+        /// Length of the frame.
+        len: usize,
+        /// Maximum frame length allowed.
+        max: usize,
+    },
+    /// Fewer than `requested` bytes were available, either to write the whole frame or to read it.
+    /// The operation may succeed later, once the other iterators have moved.
+    Insufficient {
+        /// Number of bytes needed by the operation.
+        requested: usize,
+        /// Number of bytes available when the operation was attempted.
+        available: usize,
+    },
+    /// The iterators this one depends on have been dropped,
+    /// so that the frame is never going to fit or to be complete.
+    Disconnected,
+}
+
+impl From<SliceError> for FrameError {
+    #[inline]
+    fn from(value: SliceError) -> Self {
+        match value {
+            SliceError::Insufficient {
+                requested,
+                available,
+            } => Self::Insufficient {
+                requested,
+                available,
+            },
+            SliceError::Disconnected => Self::Disconnected,
+        }
+    }
+}
+
+impl Display for FrameError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::TooLarge { len, max } => {
+                write!(f, "frame of {len} bytes exceeds the maximum of {max}")
+            }
+            Self::Insufficient {
+                requested,
+                available,
+            } => write!(
+                f,
+                "requested {requested} bytes, but only {available} are available"
+            ),
+            Self::Disconnected => f.write_str("the buffer is disconnected"),
+        }
+    }
+}
+
+impl Error for FrameError {}
+
+/// Builds the error a future resolves to when its iterator gets disconnected,
+/// from the parameter of the future.
+#[cfg(any(feature = "async", doc))]
+pub(crate) trait FromDisconnected<P> {
+    fn from_disconnected(p: P) -> Self;
+}
+
+#[cfg(any(feature = "async", doc))]
+impl<T> FromDisconnected<T> for PushError<T> {
+    #[inline]
+    fn from_disconnected(p: T) -> Self {
+        Self::Disconnected(p)
+    }
+}
+
+#[cfg(any(feature = "async", doc))]
+impl<P> FromDisconnected<P> for PopError {
+    #[inline]
+    fn from_disconnected(_: P) -> Self {
+        Self::Disconnected
+    }
+}
+
+#[cfg(any(feature = "async", doc))]
+impl<P> FromDisconnected<P> for SliceError {
+    #[inline]
+    fn from_disconnected(_: P) -> Self {
+        Self::Disconnected
+    }
+}
@@ -1,11 +1,13 @@
 use core::marker::PhantomData;
 use core::task::Waker;
 
-use crate::iterators::ConsIter;
+use crate::errors::{PopError, SliceError};
 use crate::iterators::async_iterators::async_macros::gen_common_futs_fn;
 use crate::iterators::async_iterators::{AsyncIterator, ORBFuture};
 use crate::iterators::iterator_trait::{ORBIterator, PrivateORBIterator};
 use crate::iterators::util_macros::delegate;
+use crate::iterators::util_macros::muncher;
+use crate::iterators::{ConsIter, Observer};
 use crate::iters_components::async_iters::AsyncIterComp;
 use crate::ring_buffer::OneRB;
 use crate::ring_buffer::storage_components::StorageComponent;
@@ -55,9 +57,12 @@ impl<'buf, B: IntoRef + OneRB<Iters: AsyncIterComp>> AsyncIterator<'buf> for Asy
 
 impl<'buf, B: IntoRef + OneRB<Iters: AsyncIterComp>> AsyncConsIter<B> {
     delegate!(ConsIter, pub fn reset_index(&(mut) self));
+    delegate!(ConsIter, pub fn observer(&self) -> Observer<B>);
 
     /// Async version of [`ConsIter::peek_ref`].
-    pub fn peek_ref<'b>(&'b mut self) -> ORBFuture<'buf, 'b, Self, (), &'b B::Item, true> {
+    pub fn peek_ref<'b>(
+        &'b mut self,
+    ) -> ORBFuture<'buf, 'b, Self, (), &'b B::Item, PopError, true> {
         #[inline]
         fn f<'b, B: IntoRef + OneRB<Iters: AsyncIterComp>>(
             s: &mut AsyncConsIter<B>,
@@ -79,14 +84,21 @@ impl<'buf, B: IntoRef + OneRB<Iters: AsyncIterComp>> AsyncConsIter<B> {
     pub fn peek_slice<'b>(
         &'b mut self,
         count: usize,
-    ) -> ORBFuture<'buf, 'b, Self, usize, <B::Storage as StorageComponent>::SliceOutput<'b>, true>
-    {
+    ) -> ORBFuture<
+        'buf,
+        'b,
+        Self,
+        usize,
+        <B::Storage as StorageComponent>::SliceOutput<'b>,
+        SliceError,
+        true,
+    > {
         #[inline]
         fn f<'b, B: IntoRef + OneRB<Iters: AsyncIterComp>>(
             s: &mut AsyncConsIter<B>,
             count: &mut usize,
         ) -> Option<<B::Storage as StorageComponent>::SliceOutput<'b>> {
-            s.inner_mut().peek_slice(*count)
+            s.inner_mut().peek_slice(*count).ok()
         }
 
         ORBFuture {
@@ -101,8 +113,15 @@ impl<'buf, B: IntoRef + OneRB<Iters: AsyncIterComp>> AsyncConsIter<B> {
     /// Async version of [`ConsIter::peek_available`].
     pub fn peek_available<'b>(
         &'b mut self,
-    ) -> ORBFuture<'buf, 'b, Self, (), <B::Storage as StorageComponent>::SliceOutput<'b>, true>
-    {
+    ) -> ORBFuture<
+        'buf,
+        'b,
+        Self,
+        (),
+        <B::Storage as StorageComponent>::SliceOutput<'b>,
+        SliceError,
+        true,
+    > {
         #[inline]
         fn f<'b, B: IntoRef + OneRB<Iters: AsyncIterComp>>(
             s: &mut AsyncConsIter<B>,
@@ -123,13 +142,15 @@ impl<'buf, B: IntoRef + OneRB<Iters: AsyncIterComp>> AsyncConsIter<B> {
     /// Async version of [`ConsIter::pop_unsafe`].
     /// # Safety
     /// See above.
-    pub unsafe fn pop_unsafe<'b>(&'b mut self) -> ORBFuture<'buf, 'b, Self, (), B::Item, true> {
+    pub unsafe fn pop_unsafe<'b>(
+        &'b mut self,
+    ) -> ORBFuture<'buf, 'b, Self, (), B::Item, PopError, true> {
         #[inline]
         fn f<B: IntoRef + OneRB<Iters: AsyncIterComp>>(
             s: &mut AsyncConsIter<B>,
             _: &mut (),
         ) -> Option<B::Item> {
-            unsafe { s.inner_mut().pop_unsafe() }
+            unsafe { s.inner_mut().pop_unsafe().ok() }
         }
 
         ORBFuture {
@@ -142,7 +163,7 @@ impl<'buf, B: IntoRef + OneRB<Iters: AsyncIterComp>> AsyncConsIter<B> {
     }
 
     /// Async version of [`ConsIter::pop`].
-    pub fn pop<'b>(&'b mut self) -> ORBFuture<'buf, 'b, Self, (), B::Item, true>
+    pub fn pop<'b>(&'b mut self) -> ORBFuture<'buf, 'b, Self, (), B::Item, PopError, true>
     where
         B::Item: Copy,
     {
@@ -154,7 +175,7 @@ impl<'buf, B: IntoRef + OneRB<Iters: AsyncIterComp>> AsyncConsIter<B> {
         where
             B::Item: Copy,
         {
-            s.inner_mut().pop()
+            s.inner_mut().pop().ok()
         }
 
         ORBFuture {
@@ -167,7 +188,7 @@ impl<'buf, B: IntoRef + OneRB<Iters: AsyncIterComp>> AsyncConsIter<B> {
     }
 
     /// Async version of [`ConsIter::pop_clone`].
-    pub fn pop_clone<'b>(&'b mut self) -> ORBFuture<'buf, 'b, Self, (), B::Item, true>
+    pub fn pop_clone<'b>(&'b mut self) -> ORBFuture<'buf, 'b, Self, (), B::Item, PopError, true>
     where
         B::Item: Clone,
     {
@@ -179,7 +200,7 @@ impl<'buf, B: IntoRef + OneRB<Iters: AsyncIterComp>> AsyncConsIter<B> {
         where
             B::Item: Clone,
         {
-            s.inner_mut().pop_clone()
+            s.inner_mut().pop_clone().ok()
         }
 
         ORBFuture {
@@ -194,13 +215,15 @@ impl<'buf, B: IntoRef + OneRB<Iters: AsyncIterComp>> AsyncConsIter<B> {
     /// Async version of [`ConsIter::pop_move`].
     /// # Safety
     /// See above.
-    pub unsafe fn pop_move<'b>(&'b mut self) -> ORBFuture<'buf, 'b, Self, (), B::Item, true> {
+    pub unsafe fn pop_move<'b>(
+        &'b mut self,
+    ) -> ORBFuture<'buf, 'b, Self, (), B::Item, PopError, true> {
         #[inline]
         fn f<B: IntoRef + OneRB<Iters: AsyncIterComp>>(
             s: &mut AsyncConsIter<B>,
             _: &mut (),
         ) -> Option<B::Item> {
-            unsafe { s.inner_mut().pop_move() }
+            unsafe { s.inner_mut().pop_move().ok() }
         }
 
         ORBFuture {
@@ -216,7 +239,7 @@ impl<'buf, B: IntoRef + OneRB<Iters: AsyncIterComp>> AsyncConsIter<B> {
     pub fn copy_item<'b>(
         &'b mut self,
         dst: &'b mut B::Item,
-    ) -> ORBFuture<'buf, 'b, Self, &'b mut B::Item, (), true>
+    ) -> ORBFuture<'buf, 'b, Self, &'b mut B::Item, (), PopError, true>
     where
         B::Item: Copy,
     {
@@ -228,7 +251,7 @@ impl<'buf, B: IntoRef + OneRB<Iters: AsyncIterComp>> AsyncConsIter<B> {
         where
             B::Item: Copy,
         {
-            s.inner_mut().copy_item(*dst)
+            s.inner_mut().copy_item(*dst).ok()
         }
 
         ORBFuture {
@@ -244,7 +267,7 @@ impl<'buf, B: IntoRef + OneRB<Iters: AsyncIterComp>> AsyncConsIter<B> {
     pub fn clone_item<'b>(
         &'b mut self,
         dst: &'b mut B::Item,
-    ) -> ORBFuture<'buf, 'b, Self, &'b mut B::Item, (), true>
+    ) -> ORBFuture<'buf, 'b, Self, &'b mut B::Item, (), PopError, true>
     where
         B::Item: Clone,
     {
@@ -256,7 +279,7 @@ impl<'buf, B: IntoRef + OneRB<Iters: AsyncIterComp>> AsyncConsIter<B> {
         where
             B::Item: Clone,
         {
-            s.inner_mut().clone_item(*dst)
+            s.inner_mut().clone_item(*dst).ok()
         }
 
         ORBFuture {
@@ -272,7 +295,7 @@ impl<'buf, B: IntoRef + OneRB<Iters: AsyncIterComp>> AsyncConsIter<B> {
     pub fn copy_slice<'b>(
         &'b mut self,
         dst: &'b mut [B::Item],
-    ) -> ORBFuture<'buf, 'b, Self, &'b mut [B::Item], (), true>
+    ) -> ORBFuture<'buf, 'b, Self, &'b mut [B::Item], (), SliceError, true>
     where
         B::Item: Copy,
     {
@@ -284,7 +307,7 @@ impl<'buf, B: IntoRef + OneRB<Iters: AsyncIterComp>> AsyncConsIter<B> {
         where
             B::Item: Copy,
         {
-            s.inner_mut().copy_slice(dst)
+            s.inner_mut().copy_slice(dst).ok()
         }
 
         ORBFuture {
@@ -300,7 +323,7 @@ impl<'buf, B: IntoRef + OneRB<Iters: AsyncIterComp>> AsyncConsIter<B> {
     pub fn clone_slice<'b>(
         &'b mut self,
         dst: &'b mut [B::Item],
-    ) -> ORBFuture<'buf, 'b, Self, &'b mut [B::Item], (), true>
+    ) -> ORBFuture<'buf, 'b, Self, &'b mut [B::Item], (), SliceError, true>
     where
         B::Item: Clone,
     {
@@ -312,7 +335,7 @@ impl<'buf, B: IntoRef + OneRB<Iters: AsyncIterComp>> AsyncConsIter<B> {
         where
             B::Item: Clone,
         {
-            s.inner_mut().clone_slice(dst)
+            s.inner_mut().clone_slice(dst).ok()
         }
 
         ORBFuture {
@@ -1,6 +1,8 @@
 use crate::OneRB;
 use crate::StorageComponent;
+use crate::errors::{PopError, SliceError};
 use crate::iterators::async_iterators::AsyncIterator;
+use crate::ring_buffer::storage_components::PStorageComponent;
 use core::marker::PhantomData;
 
 use crate::ORBIterator;
@@ -11,6 +13,10 @@ use crate::iterators::iterator_trait::PrivateORBIterator;
 use crate::iterators::util_macros::delegate;
 use crate::iterators::util_macros::muncher;
 
+/// Mutable slices yielded by the iterator wrapped by `I`.
+type SliceOutputMut<'buf, 'b, I> =
+    <<<<I as AsyncIterator<'buf>>::I as ORBIterator>::Buffer as OneRB>::Storage as StorageComponent>::SliceOutputMut<'b>;
+
 #[doc = r##"
 Async version of [`Detached`].
 "##]
@@ -44,7 +50,7 @@ impl<'buf, I: AsyncIterator<'buf>> AsyncDetached<'buf, I> {
 
     /// Same as [`Detached::sync_index`].
     pub fn sync_index(&self) {
-        self.inner.inner().set_atomic_index(self.inner.index())
+        self.inner.inner().publish_index()
     }
 
     /// Same as [`Detached::advance`].
@@ -61,10 +67,11 @@ impl<'buf, I: AsyncIterator<'buf>> AsyncDetached<'buf, I> {
     /// Same as [`Detached::go_back`].
     pub unsafe fn go_back(&mut self, count: usize) {
         let idx = self.inner.inner_mut().index();
-        let buf_len = self.inner.inner_mut().buf_len();
+        let end = self.inner.inner().buffer().storage().wrap_mark();
+        let end = end.unwrap_or(self.inner.inner().buf_len());
 
         self.inner.inner_mut().set_local_index(match idx < count {
-            true => unsafe { buf_len.unchecked_sub(count).unchecked_sub(idx) },
+            true => unsafe { end.unchecked_sub(count.unchecked_sub(idx)) },
             false => unsafe { idx.unchecked_sub(count) },
         });
 
@@ -77,27 +84,27 @@ impl<'buf, I: AsyncIterator<'buf>> AsyncDetached<'buf, I> {
     delegate!(
         AsyncIterator (inline),
         pub fn get_mut<'b>(&'b (mut) self) ->
-        ORBFuture<'buf, 'b, I, (), &'b mut <I::I as ORBIterator>::Item, true>
+        ORBFuture<'buf, 'b, I, (), &'b mut <I::I as ORBIterator>::Item, PopError, true>
     );
     delegate!(
         AsyncIterator (inline),
         pub fn get_mut_slice_exact<'b>(&'b (mut) self, count: usize) ->
         ORBFuture<'buf,'b, I, usize,
-            <<<I::I as ORBIterator>::Buffer as OneRB>::Storage as StorageComponent>::SliceOutputMut<'b,>, true
+            SliceOutputMut<'buf, 'b, I>, SliceError, true
         >
     );
     delegate!(
         AsyncIterator (inline),
         pub fn get_mut_slice_avail<'b>(&'b (mut) self) ->
         ORBFuture<'buf,'b, I, (),
-            <<<I::I as ORBIterator>::Buffer as OneRB>::Storage as StorageComponent>::SliceOutputMut<'b,>, true
+            SliceOutputMut<'buf, 'b, I>, SliceError, true
         >
     );
     delegate!(
         AsyncIterator (inline),
         pub fn get_mut_slice_multiple_of<'b>(&'b (mut) self, count: usize) ->
         ORBFuture<'buf,'b, I, usize,
-            <<<I::I as ORBIterator>::Buffer as OneRB>::Storage as StorageComponent>::SliceOutputMut<'b,>, true
+            SliceOutputMut<'buf, 'b, I>, SliceError, true
         >
     );
 }
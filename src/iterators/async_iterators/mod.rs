@@ -5,7 +5,10 @@
 use crate::ORBIterator;
 use crate::OneRB;
 use crate::StorageComponent;
+use crate::errors::{FromDisconnected, PopError, SliceError};
+use crate::iterators::ExclusiveIter;
 use crate::iterators::async_iterators::detached::AsyncDetached;
+use crate::iterators::iterator_trait::PrivateORBIterator;
 use crate::iterators::util_macros::delegate;
 use crate::iterators::util_macros::muncher;
 use core::future::Future;
@@ -18,9 +21,12 @@ pub(crate) mod detached;
 pub(crate) mod prod_iter;
 pub(crate) mod work_iter;
 
+type SliceOutputMut<'b, I> =
+    <<<I as ORBIterator>::Buffer as OneRB>::Storage as StorageComponent>::SliceOutputMut<'b>;
+
 /// Trait implemented by async iterators.
 pub trait AsyncIterator<'buf> {
-    type I: ORBIterator;
+    type I: ExclusiveIter;
 
     fn register_waker(&self, waker: &Waker);
     fn take_waker(&self) -> Option<Waker>;
@@ -44,59 +50,43 @@ pub trait AsyncIterator<'buf> {
 
     fn get_mut<'b>(
         &'b mut self,
-    ) -> ORBFuture<'buf, 'b, Self, (), &'b mut <Self::I as ORBIterator>::Item, true>;
+    ) -> ORBFuture<'buf, 'b, Self, (), &'b mut <Self::I as ORBIterator>::Item, PopError, true>;
 
     fn get_mut_slice_exact<'b>(
         &'b mut self,
         count: usize,
-    ) -> ORBFuture<
-        'buf,
-        'b,
-        Self,
-        usize,
-        <<<Self::I as ORBIterator>::Buffer as OneRB>::Storage as StorageComponent>::SliceOutputMut<
-            'b,
-        >,
-        true,
-    >;
+    ) -> ORBFuture<'buf, 'b, Self, usize, SliceOutputMut<'b, Self::I>, SliceError, true>;
 
     fn get_mut_slice_avail<'b>(
         &'b mut self,
-    ) -> ORBFuture<
-        'buf,
-        'b,
-        Self,
-        (),
-        <<<Self::I as ORBIterator>::Buffer as OneRB>::Storage as StorageComponent>::SliceOutputMut<
-            'b,
-        >,
-        true,
-    >;
+    ) -> ORBFuture<'buf, 'b, Self, (), SliceOutputMut<'b, Self::I>, SliceError, true>;
 
     fn get_mut_slice_multiple_of<'b>(
         &'b mut self,
         count: usize,
-    ) -> ORBFuture<
-        'buf,
-        'b,
-        Self,
-        usize,
-        <<<Self::I as ORBIterator>::Buffer as OneRB>::Storage as StorageComponent>::SliceOutputMut<
-            'b,
-        >,
-        true,
-    >;
+    ) -> ORBFuture<'buf, 'b, Self, usize, SliceOutputMut<'b, Self::I>, SliceError, true>;
 
     delegate!(ORBIterator, fn prod_index(&self) -> usize);
     delegate!(ORBIterator, fn work_index(&self) -> usize);
+    delegate!(ORBIterator, fn stage_index(&self, stage: usize) -> Option<usize>);
     delegate!(ORBIterator, fn cons_index(&self) -> usize);
+    delegate!(ORBIterator, fn reader_index(&self, reader: usize) -> Option<usize>);
     delegate!(ORBIterator, fn alive_iters(&self) -> u8);
+    delegate!(ORBIterator, fn prod_alive(&self) -> bool);
+    delegate!(ORBIterator, fn work_alive(&self) -> bool);
+    delegate!(ORBIterator, fn stage_alive(&self, stage: usize) -> bool);
+    delegate!(ORBIterator, fn cons_alive(&self) -> bool);
+    delegate!(ORBIterator, fn reader_alive(&self, reader: usize) -> bool);
+    delegate!(ORBIterator, fn is_disconnected(&self) -> bool);
     delegate!(ORBIterator, fn index(&self) -> usize);
     delegate!(ORBIterator, fn available(&(mut) self) -> usize);
 }
 
 /// Future returned by methods in async iterators.
-pub struct ORBFuture<'buf, 'a, I, P, O, const R: bool>
+///
+/// It resolves to `Err(E)`, holding the `Disconnected` variant of the error, if the iterators
+/// it depends on are dropped, so that it could never complete.
+pub struct ORBFuture<'buf, 'a, I, P, O, E, const R: bool>
 where
     I: AsyncIterator<'buf> + ?Sized,
 {
@@ -104,44 +94,59 @@ where
     p: Option<P>,
     f_r: Option<fn(&mut I, &mut P) -> Option<O>>,
     f_m: Option<fn(&mut I, P) -> Result<O, P>>,
-    phantom: PhantomData<&'buf ()>,
+    phantom: PhantomData<(&'buf (), E)>,
 }
 
-impl<'buf, 'a, I: AsyncIterator<'buf>, P, O, const R: bool> Unpin
-    for ORBFuture<'buf, 'a, I, P, O, R>
+impl<'buf, 'a, I: AsyncIterator<'buf>, P, O, E, const R: bool> Unpin
+    for ORBFuture<'buf, 'a, I, P, O, E, R>
 {
 }
 
-impl<'buf, 'a, I: AsyncIterator<'buf>, P, O, const R: bool> Future
-    for ORBFuture<'buf, 'a, I, P, O, R>
+impl<'buf, 'a, I: AsyncIterator<'buf>, P, O, E: FromDisconnected<P>, const R: bool> Future
+    for ORBFuture<'buf, 'a, I, P, O, E, R>
 {
-    type Output = Option<O>;
+    type Output = Result<O, E>;
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         let f_r = self.f_r.take();
         let f_m = self.f_m.take();
-        let p = self.p.take();
-
-        let res = if R {
-            let mut p = p.unwrap();
-            let ret = f_r.as_ref().unwrap()(self.iter, &mut p);
-            ret.ok_or(p)
-        } else {
-            f_m.as_ref().unwrap()(self.iter, p.unwrap())
-        };
+        let mut p = self.p.take().unwrap();
+        let mut ended = false;
+
+        loop {
+            let res = if R {
+                let ret = f_r.as_ref().unwrap()(self.iter, &mut p);
+                ret.ok_or(p)
+            } else {
+                f_m.as_ref().unwrap()(self.iter, p)
+            };
+
+            match res {
+                Ok(r) => {
+                    return Poll::Ready(Ok(r));
+                }
+                Err(ret) => p = ret,
+            }
 
-        match res {
-            Ok(r) => {
-                return Poll::Ready(Some(r));
+            // The successor ended before the last attempt: it is never going to succeed.
+            if ended {
+                return Poll::Ready(Err(E::from_disconnected(p)));
             }
-            Err(p) => {
-                self.f_r = f_r;
-                self.f_m = f_m;
-                self.p = Some(p);
+
+            self.iter.register_waker(cx.waker());
+
+            // Retry once if the successor ended in the meantime, as it may have released
+            // some items just before.
+            ended = self.iter.inner().succ_ended();
+            if !ended {
+                break;
             }
         }
 
-        self.iter.register_waker(cx.waker());
+        self.f_r = f_r;
+        self.f_m = f_m;
+        self.p = Some(p);
+
         Poll::Pending
     }
 }
@@ -152,7 +157,7 @@ pub(crate) mod async_macros {
             /// Async version of [`ORBIterator::get_mut`].
             fn get_mut<'b>(
                 &'b mut self,
-            ) -> ORBFuture<'buf, 'b, Self, (), &'b mut B::Item, true> {
+            ) -> ORBFuture<'buf, 'b, Self, (), &'b mut B::Item, PopError, true> {
                 fn f<'buf, 'b, I: AsyncIterator<'buf, I: ORBIterator<Item = T>>, T>(
                     s: &mut I,
                     _: &mut (),
@@ -179,6 +184,7 @@ pub(crate) mod async_macros {
                 Self,
                 usize,
                 <B::Storage as StorageComponent>::SliceOutputMut<'b>,
+                SliceError,
                 true,
             > {
                 fn f<'buf, 'b, I: AsyncIterator<'buf, I: ORBIterator<Item = T>>, T>(
@@ -189,7 +195,7 @@ pub(crate) mod async_macros {
                         'b,
                     >,
                 > {
-                    s.inner_mut().get_mut_slice_exact(*count)
+                    s.inner_mut().get_mut_slice_exact(*count).ok()
                 }
 
                 ORBFuture {
@@ -210,6 +216,7 @@ pub(crate) mod async_macros {
                 Self,
                 (),
                 <B::Storage as StorageComponent>::SliceOutputMut<'b>,
+                SliceError,
                 true,
             > {
                 fn f<'buf, 'b, I: AsyncIterator<'buf, I: ORBIterator<Item = T>>, T>(
@@ -242,6 +249,7 @@ pub(crate) mod async_macros {
                 Self,
                 usize,
                 <B::Storage as StorageComponent>::SliceOutputMut<'b>,
+                SliceError,
                 true,
             > {
                 fn f<'buf, 'b, I: AsyncIterator<'buf, I: ORBIterator<Item = T>>, T>(
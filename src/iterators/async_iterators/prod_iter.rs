@@ -1,10 +1,14 @@
 use core::marker::PhantomData;
 use core::task::Waker;
 
+use crate::errors::{PopError, PushError, SliceError};
+use crate::iterators::Observer;
 use crate::iterators::ProdIter;
 use crate::iterators::async_iterators::async_macros::gen_common_futs_fn;
 use crate::iterators::async_iterators::{AsyncIterator, ORBFuture};
 use crate::iterators::iterator_trait::{ORBIterator, PrivateORBIterator};
+use crate::iterators::util_macros::delegate;
+use crate::iterators::util_macros::muncher;
 use crate::iters_components::async_iters::AsyncIterComp;
 use crate::ring_buffer::OneRB;
 use crate::ring_buffer::storage_components::StorageComponent;
@@ -53,14 +57,19 @@ impl<'buf, B: IntoRef + OneRB<Iters: AsyncIterComp>> AsyncIterator<'buf> for Asy
 }
 
 impl<'buf, B: IntoRef + OneRB<Iters: AsyncIterComp>> AsyncProdIter<B> {
+    delegate!(ProdIter, pub fn observer(&self) -> Observer<B>);
+
     /// Async version of [`ProdIter::push`].
-    pub fn push<'b>(&'b mut self, item: B::Item) -> ORBFuture<'buf, 'b, Self, B::Item, (), false> {
+    pub fn push<'b>(
+        &'b mut self,
+        item: B::Item,
+    ) -> ORBFuture<'buf, 'b, Self, B::Item, (), PushError<B::Item>, false> {
         #[inline]
         fn f<B: IntoRef + OneRB<Iters: AsyncIterComp>>(
             s: &mut AsyncProdIter<B>,
             item: B::Item,
         ) -> Result<(), B::Item> {
-            s.inner_mut().push(item)
+            s.inner_mut().push(item).map_err(PushError::into_inner)
         }
 
         ORBFuture {
@@ -76,7 +85,7 @@ impl<'buf, B: IntoRef + OneRB<Iters: AsyncIterComp>> AsyncProdIter<B> {
     pub fn push_slice<'b>(
         &'b mut self,
         slice: &'b [B::Item],
-    ) -> ORBFuture<'buf, 'b, Self, &'b [B::Item], (), true>
+    ) -> ORBFuture<'buf, 'b, Self, &'b [B::Item], (), SliceError, true>
     where
         B::Item: Copy,
     {
@@ -88,7 +97,7 @@ impl<'buf, B: IntoRef + OneRB<Iters: AsyncIterComp>> AsyncProdIter<B> {
         where
             B::Item: Copy,
         {
-            let ret = s.inner_mut().push_slice(slice);
+            let ret = s.inner_mut().push_slice(slice).ok();
             s.wake_next();
             ret
         }
@@ -106,7 +115,7 @@ impl<'buf, B: IntoRef + OneRB<Iters: AsyncIterComp>> AsyncProdIter<B> {
     pub fn push_slice_clone<'b>(
         &'b mut self,
         slice: &'b [B::Item],
-    ) -> ORBFuture<'buf, 'b, Self, &'b [B::Item], (), true>
+    ) -> ORBFuture<'buf, 'b, Self, &'b [B::Item], (), SliceError, true>
     where
         B::Item: Clone,
     {
@@ -118,7 +127,7 @@ impl<'buf, B: IntoRef + OneRB<Iters: AsyncIterComp>> AsyncProdIter<B> {
         where
             B::Item: Clone,
         {
-            s.inner_mut().push_slice_clone(slice)
+            s.inner_mut().push_slice_clone(slice).ok()
         }
 
         ORBFuture {
@@ -135,7 +144,7 @@ impl<'buf, B: IntoRef + OneRB<Iters: AsyncIterComp>> AsyncProdIter<B> {
     /// Same as [`ProdIter::get_next_item_mut`].
     pub unsafe fn get_next_item_mut<'b>(
         &'b mut self,
-    ) -> ORBFuture<'buf, 'b, Self, (), &'b mut B::Item, true> {
+    ) -> ORBFuture<'buf, 'b, Self, (), &'b mut B::Item, SliceError, true> {
         #[inline]
         fn f<'b, B: IntoRef + OneRB<Iters: AsyncIterComp>>(
             s: &mut AsyncProdIter<B>,
@@ -156,7 +165,7 @@ impl<'buf, B: IntoRef + OneRB<Iters: AsyncIterComp>> AsyncProdIter<B> {
     /// Async version of [`ProdIter::get_next_item_mut_init`].
     pub fn get_next_item_mut_init<'b>(
         &'b mut self,
-    ) -> ORBFuture<'buf, 'b, Self, (), *mut B::Item, true> {
+    ) -> ORBFuture<'buf, 'b, Self, (), *mut B::Item, SliceError, true> {
         #[inline]
         fn f<B: IntoRef + OneRB<Iters: AsyncIterComp>>(
             s: &mut AsyncProdIter<B>,
@@ -180,8 +189,15 @@ impl<'buf, B: IntoRef + OneRB<Iters: AsyncIterComp>> AsyncProdIter<B> {
     pub unsafe fn get_next_slices_mut<'b>(
         &'b mut self,
         count: usize,
-    ) -> ORBFuture<'buf, 'b, Self, usize, <B::Storage as StorageComponent>::SliceOutputMut<'b>, true>
-    {
+    ) -> ORBFuture<
+        'buf,
+        'b,
+        Self,
+        usize,
+        <B::Storage as StorageComponent>::SliceOutputMut<'b>,
+        SliceError,
+        true,
+    > {
         #[inline]
         fn f<'b, B: IntoRef + OneRB<Iters: AsyncIterComp>>(
             s: &mut AsyncProdIter<B>,
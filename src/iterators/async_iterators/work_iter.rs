@@ -1,13 +1,18 @@
+use crate::errors::{PopError, SliceError};
 use crate::iterators::async_iterators::async_macros::gen_common_futs_fn;
 use crate::iterators::async_iterators::{AsyncIterator, ORBFuture};
 use crate::iterators::iterator_trait::PrivateORBIterator;
 use crate::iterators::util_macros::delegate;
+use crate::iterators::util_macros::muncher;
 use crate::iters_components::async_iters::AsyncIterComp;
 use crate::ring_buffer::OneRB;
 use crate::ring_buffer::storage_components::StorageComponent;
 use crate::ring_buffer::wrappers::refs::IntoRef;
 #[allow(unused_imports)]
-use crate::{ORBIterator, iterators::WorkIter};
+use crate::{
+    ORBIterator,
+    iterators::{Observer, WorkIter},
+};
 use core::marker::PhantomData;
 use core::task::Waker;
 
@@ -24,15 +29,24 @@ impl<'buf, B: IntoRef + OneRB<Iters: AsyncIterComp>> AsyncIterator<'buf> for Asy
     type I = WorkIter<B>;
 
     fn register_waker(&self, waker: &Waker) {
-        self.inner.buffer().iters().register_work_waker(waker);
+        self.inner
+            .buffer()
+            .iters()
+            .register_stage_waker(self.inner.stage(), waker);
     }
 
     fn take_waker(&self) -> Option<Waker> {
-        self.inner.buffer().iters().take_work_waker()
+        self.inner
+            .buffer()
+            .iters()
+            .take_stage_waker(self.inner.stage())
     }
 
     fn wake_next(&self) {
-        self.inner.buffer().iters().wake_cons();
+        self.inner
+            .buffer()
+            .iters()
+            .wake_next_stage(self.inner.stage());
     }
 
     #[inline]
@@ -55,4 +69,11 @@ impl<'buf, B: IntoRef + OneRB<Iters: AsyncIterComp>> AsyncIterator<'buf> for Asy
 
 impl<'buf, B: IntoRef + OneRB<Iters: AsyncIterComp>> AsyncWorkIter<B> {
     delegate!(WorkIter, pub fn reset_index(&(mut) self));
+    delegate!(WorkIter, pub fn observer(&self) -> Observer<B>);
+
+    /// Same as [`WorkIter::stage`].
+    #[inline]
+    pub fn stage(&self) -> usize {
+        self.inner.stage()
+    }
 }
@@ -1,7 +1,13 @@
+use crate::errors::{PopError, SliceError};
 use crate::iterators::sync_iterators::detached::Detached;
 use crate::ring_buffer::OneRB;
 use crate::ring_buffer::iters_components::PIterComponent;
+#[cfg(feature = "stats")]
+use crate::ring_buffer::stats::Stats;
 use crate::ring_buffer::storage_components::{PStorageComponent, StorageComponent};
+use crate::ring_buffer::wait::{CancelToken, WaitLimit, WaitState};
+#[cfg(feature = "std")]
+use std::time::{Duration, Instant};
 
 /// Trait implemented by iterators.
 ///
@@ -39,9 +45,45 @@ pub trait ORBIterator: PrivateORBIterator<_Buffer = Self::Buffer> {
         self._available()
     }
 
-    /// Waits, blocking the thread in a loop, until there are at least `count` available items.
+    /// Waits, blocking the thread, until there are at least `count` available items.
+    ///
+    /// How the thread waits depends on the [`WaitStrategy`](crate::WaitStrategy) of the buffer.
+    /// Returns early if the iterators this one depends on are gone, so that
+    /// `count` items will never be available.
     fn wait_for(&mut self, count: usize) {
-        while self.available() < count {}
+        self.wait_until(|it| it.available() >= count, &WaitLimit::default());
+    }
+
+    /// Same as [`Self::wait_for`], but gives up after `timeout`.
+    ///
+    /// Returns `true` if at least `count` items became available, `false` otherwise
+    /// (e.g. if the iterator got disconnected).
+    #[cfg(feature = "std")]
+    fn wait_for_timeout(&mut self, count: usize, timeout: Duration) -> bool {
+        let limit = WaitLimit::until(Instant::now().checked_add(timeout));
+
+        self.wait_until(|it| it.available() >= count, &limit)
+    }
+
+    /// Same as [`Self::wait_for`], but gives up when `deadline` is reached.
+    ///
+    /// Returns `true` if at least `count` items became available, `false` otherwise
+    /// (e.g. if the iterator got disconnected).
+    #[cfg(feature = "std")]
+    fn wait_for_until(&mut self, count: usize, deadline: Instant) -> bool {
+        let limit = WaitLimit::until(Some(deadline));
+
+        self.wait_until(|it| it.available() >= count, &limit)
+    }
+
+    /// Same as [`Self::wait_for`], but gives up as soon as `token` is cancelled.
+    ///
+    /// Returns `true` if at least `count` items became available, `false` otherwise
+    /// (e.g. if the iterator got disconnected).
+    fn wait_for_cancellable(&mut self, count: usize, token: &CancelToken) -> bool {
+        let limit = WaitLimit::cancellable(token);
+
+        self.wait_until(|it| it.available() >= count, &limit)
     }
 
     /// Returns the index of the iterator.
@@ -61,6 +103,59 @@ pub trait ORBIterator: PrivateORBIterator<_Buffer = Self::Buffer> {
         self.buffer().iters().alive_iters()
     }
 
+    /// Returns `true` if the producer is alive.
+    /// For multi-producer buffers, returns `true` if at least one producer is alive.
+    #[inline]
+    fn prod_alive(&self) -> bool {
+        self.buffer().liveness().prod_alive()
+    }
+    /// Returns `true` if the worker is alive.
+    /// For pipeline buffers, this is the same as `Self::stage_alive(0)`.
+    #[inline]
+    fn work_alive(&self) -> bool {
+        self.stage_alive(0)
+    }
+    /// Returns `true` if the worker at `stage` is alive.
+    /// Returns `false` if the buffer has no such stage (e.g. non-mutable buffers have none).
+    #[inline]
+    fn stage_alive(&self, stage: usize) -> bool {
+        stage < self.buffer().iters().stages() && self.buffer().liveness().stage_alive(stage)
+    }
+    /// Returns `true` if the consumer is alive.
+    /// For broadcast buffers, this is the same as `Self::reader_alive(0)`.
+    #[inline]
+    fn cons_alive(&self) -> bool {
+        self.reader_alive(0)
+    }
+    /// Returns `true` if the consumer `reader` is alive.
+    /// Returns `false` if the buffer has no such reader (only broadcast buffers have more than one).
+    #[inline]
+    fn reader_alive(&self, reader: usize) -> bool {
+        reader < self.buffer().iters().readers() && self.buffer().liveness().reader_alive(reader)
+    }
+
+    /// Returns `true` if this iterator has been disconnected from its peers, so that it will never
+    /// be able to move again.
+    ///
+    /// This happens:
+    /// * to a producer, as soon as any worker is dropped, or once every consumer is;
+    /// * to a worker or a consumer, once the iterators preceding it have been dropped and it has
+    ///   processed all the items they released. Until then, this method returns `false`, telling
+    ///   an iterator which is only "empty for now" from one which will never receive items again.
+    ///
+    /// Blocking methods, such as [`Self::wait_for`], give up as soon as the iterator is disconnected.
+    #[inline]
+    fn is_disconnected(&self) -> bool {
+        self._is_disconnected()
+    }
+
+    /// Returns the runtime statistics of the buffer, if it records any.
+    #[cfg(feature = "stats")]
+    #[inline]
+    fn stats(&self) -> Option<&Stats> {
+        self.buffer().iters().stats()
+    }
+
     /// Returns the index of the producer.
     #[inline(always)]
     fn prod_index(&self) -> usize {
@@ -71,10 +166,27 @@ pub trait ORBIterator: PrivateORBIterator<_Buffer = Self::Buffer> {
     fn work_index(&self) -> usize {
         self.buffer().iters().work_index()
     }
+    /// Returns the index of the worker at `stage`, or `None` if the buffer has no such stage.
+    /// For mutable non-pipeline buffers, `stage_index(0)` is the same as [`Self::work_index`].
+    #[inline(always)]
+    fn stage_index(&self, stage: usize) -> Option<usize> {
+        let iters = self.buffer().iters();
+
+        (stage < iters.stages()).then(|| iters.stage_index(stage))
+    }
     /// Returns the index of the consumer.
+    /// For broadcast buffers, this is the index of the slowest consumer still alive.
     #[inline(always)]
     fn cons_index(&self) -> usize {
-        self.buffer().iters().cons_index()
+        self.buffer().liveness().cons_index(self.buffer().iters())
+    }
+    /// Returns the index of the consumer `reader`, or `None` if the buffer has no such reader.
+    /// For non-broadcast buffers, `reader_index(0)` is the same as [`Self::cons_index`].
+    #[inline(always)]
+    fn reader_index(&self, reader: usize) -> Option<usize> {
+        let iters = self.buffer().iters();
+
+        (reader < iters.readers()).then(|| iters.reader_index(reader))
     }
 
     /// Returns a mutable references to the current value.
@@ -85,7 +197,10 @@ pub trait ORBIterator: PrivateORBIterator<_Buffer = Self::Buffer> {
     /// in order to move the iterator.
     /// </div>
     #[inline]
-    fn get_mut<'a>(&mut self) -> Option<&'a mut Self::Item> {
+    fn get_mut<'a>(&mut self) -> Option<&'a mut Self::Item>
+    where
+        Self: ExclusiveIter,
+    {
         self.next_ref_mut()
     }
 
@@ -95,16 +210,56 @@ pub trait ORBIterator: PrivateORBIterator<_Buffer = Self::Buffer> {
     /// Being these references, [`Self::advance()`] has to be called when done with the mutation
     /// in order to move the iterator.
     /// </div>
+    ///
+    /// Returns:
+    /// * `Err(SliceError::Insufficient)`, if fewer than `count` items are available;
+    /// * `Err(SliceError::Disconnected)`, if `count` items will never be available;
+    /// * `Ok(slices)`, otherwise.
     #[inline]
     fn get_mut_slice_exact<'a>(
         &mut self,
         count: usize,
-    ) -> Option<<<Self::Buffer as OneRB>::Storage as StorageComponent>::SliceOutputMut<'a>> {
-        self.check(count).then(|| {
-            self.buffer()
+    ) -> Result<
+        <<Self::Buffer as OneRB>::Storage as StorageComponent>::SliceOutputMut<'a>,
+        SliceError,
+    >
+    where
+        Self: ExclusiveIter,
+    {
+        match self.check(count) {
+            true => Ok(self
+                .buffer()
                 .storage_mut()
-                .next_chunk_mut(self._index(), count)
-        })
+                .next_chunk_mut(self._index(), count)),
+            false => Err(self.slice_failure(count)),
+        }
+    }
+
+    /// Returns a mutable reference to the next `N` items, as an array, if they are available and
+    /// contiguous in memory.
+    ///
+    /// Except with `VmemStorage`, blocks crossing the end of the buffer are never returned.
+    /// If the length of the buffer is a multiple of `N`, and the iterators always move by `N` items,
+    /// this never happens: such buffers can be built with `from_blocks` constructors
+    /// (e.g. `SharedHeapRB::from_blocks`).
+    /// <div class="warning">
+    ///
+    /// Being this a reference, [`Self::advance()`] has to be called when done with the mutation
+    /// in order to move the iterator.
+    /// </div>
+    #[inline]
+    fn get_block_mut<'a, const N: usize>(&mut self) -> Option<&'a mut [Self::Item; N]>
+    where
+        Self: ExclusiveIter,
+    {
+        if !self.check(N) {
+            return None;
+        }
+
+        self.buffer()
+            .storage()
+            .next_block(self._index(), N)
+            .map(|ptr| unsafe { &mut *(ptr as *mut [Self::Item; N]) })
     }
 
     /// Returns a tuple of mutable slice references, the sum of which with len equal to [`Self::available()`].
@@ -116,10 +271,13 @@ pub trait ORBIterator: PrivateORBIterator<_Buffer = Self::Buffer> {
     #[inline]
     fn get_mut_slice_avail<'a>(
         &mut self,
-    ) -> Option<<<Self::Buffer as OneRB>::Storage as StorageComponent>::SliceOutputMut<'a>> {
+    ) -> Option<<<Self::Buffer as OneRB>::Storage as StorageComponent>::SliceOutputMut<'a>>
+    where
+        Self: ExclusiveIter,
+    {
         match self.available() {
             0 => None,
-            avail => self.get_mut_slice_exact(avail),
+            avail => self.get_mut_slice_exact(avail).ok(),
         }
     }
 
@@ -134,18 +292,35 @@ pub trait ORBIterator: PrivateORBIterator<_Buffer = Self::Buffer> {
     fn get_mut_slice_multiple_of<'a>(
         &mut self,
         rhs: usize,
-    ) -> Option<<<Self::Buffer as OneRB>::Storage as StorageComponent>::SliceOutputMut<'a>> {
+    ) -> Option<<<Self::Buffer as OneRB>::Storage as StorageComponent>::SliceOutputMut<'a>>
+    where
+        Self: ExclusiveIter,
+    {
         let avail = self.available();
 
         unsafe {
             match avail.unchecked_sub(avail % rhs) {
                 0 => None,
-                avail => self.get_mut_slice_exact(avail),
+                avail => self.get_mut_slice_exact(avail).ok(),
             }
         }
     }
 }
 
+/// Trait implemented by iterators which are the only ones accessing the items they move over,
+/// and can therefore hand out mutable references to them.
+///
+/// Consumers of broadcast buffers do not implement it, since every reader goes over the same items:
+/// ```compile_fail
+/// use oneringbuf::{BroadcastStackRB, ORBIterator};
+///
+/// let mut buf = BroadcastStackRB::<u32, 8, 2>::from([0; 8]);
+/// let (_prod, [mut r0, _r1]) = buf.split_broadcast_array();
+///
+/// r0.get_mut();
+/// ```
+pub trait ExclusiveIter: ORBIterator {}
+
 pub(crate) trait PrivateORBIterator {
     type _Buffer: OneRB;
 
@@ -155,16 +330,51 @@ pub(crate) trait PrivateORBIterator {
     fn set_cached_avail(&mut self, avail: usize);
     fn _index(&self) -> usize;
     fn set_local_index(&mut self, index: usize);
+    /// Returns the global index of this iterator.
+    #[cfg(feature = "stats")]
+    fn atomic_index(&self) -> usize;
     /// Sets the global index of this iterator.
     fn set_atomic_index(&self, index: usize);
 
     /// Returns the global index of successor.
     fn succ_index(&self) -> usize;
 
+    /// Returns `true` if the successor will never move again.
+    fn succ_ended(&self) -> bool;
+    /// Returns `true` if the iterator will never be able to move again.
+    fn _is_disconnected(&self) -> bool;
+
+    /// Records, in the statistics of the buffer, an advance by `count` items starting from `from`.
+    /// Called before the new index is published.
+    #[cfg(feature = "stats")]
+    #[inline]
+    fn record_advance(&self, _from: usize, _count: usize) {}
+    /// Records, in the statistics of the buffer, a failed attempt to move the iterator.
+    #[cfg(feature = "stats")]
+    #[inline]
+    fn record_failure(&self) {}
+
     #[inline]
     unsafe fn _advance(&mut self, count: usize) {
         unsafe { self.advance_local(count) };
 
+        self.publish_index();
+    }
+
+    /// Publishes the local index, recording the items moved since the last publication.
+    #[inline]
+    fn publish_index(&self) {
+        #[cfg(feature = "stats")]
+        {
+            let (from, to) = (self.atomic_index(), self._index());
+            let count = match from <= to {
+                true => to - from,
+                false => self.buffer().len() - from + to,
+            };
+
+            self.record_advance(from, count);
+        }
+
         self.set_atomic_index(self._index());
     }
 
@@ -179,6 +389,78 @@ pub(crate) trait PrivateORBIterator {
         self.set_cached_avail(self.cached_avail().saturating_sub(count));
     }
 
+    /// Waits, according to the strategy of the buffer, until `cond` returns `true`,
+    /// `limit` expires or the successor ends.
+    ///
+    /// Returns the last value returned by `cond`.
+    #[inline]
+    fn wait_until(&mut self, mut cond: impl FnMut(&mut Self) -> bool, limit: &WaitLimit) -> bool {
+        let mut state = WaitState::default();
+
+        loop {
+            self.buffer().waiter().prepare(&mut state);
+
+            if cond(self) {
+                self.buffer().waiter().cancel(&mut state);
+                return true;
+            }
+
+            if limit.expired() || self.succ_ended() {
+                self.buffer().waiter().cancel(&mut state);
+                return false;
+            }
+
+            self.buffer().waiter().wait(&mut state, limit);
+        }
+    }
+
+    /// Tells why a single item could not be retrieved.
+    ///
+    /// Once the successor has ended, the available items are final, so the iterator is
+    /// disconnected only if none is left.
+    #[inline]
+    fn pop_error(&mut self) -> PopError {
+        match self.succ_ended() && self._available() == 0 {
+            true => PopError::Disconnected,
+            false => PopError::Empty,
+        }
+    }
+
+    /// Tells why `requested` items could not be retrieved.
+    #[inline]
+    fn slice_error(&mut self, requested: usize) -> SliceError {
+        let ended = self.succ_ended();
+        let available = self._available();
+
+        match ended && available < requested {
+            true => SliceError::Disconnected,
+            false => SliceError::Insufficient {
+                requested,
+                available,
+            },
+        }
+    }
+
+    /// Same as [`Self::pop_error`], but also records a failed attempt to move the iterator.
+    /// Only meant for methods which move items, so that peeking never counts as an xrun.
+    #[inline]
+    fn pop_failure(&mut self) -> PopError {
+        #[cfg(feature = "stats")]
+        self.record_failure();
+
+        self.pop_error()
+    }
+
+    /// Same as [`Self::slice_error`], but also records a failed attempt to move the iterator.
+    /// Only meant for methods which move items, so that peeking never counts as an xrun.
+    #[inline]
+    fn slice_failure(&mut self, requested: usize) -> SliceError {
+        #[cfg(feature = "stats")]
+        self.record_failure();
+
+        self.slice_error(requested)
+    }
+
     /// Checks whether the current index can be returned
     #[inline]
     fn check(&mut self, count: usize) -> bool {
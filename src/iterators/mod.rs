@@ -11,12 +11,25 @@ pub use async_iterators::{
 };
 
 pub use sync_iterators::{
-    cons_iter::ConsIter, detached::Detached, prod_iter::ProdIter, work_iter::WorkIter,
+    cons_iter::ConsIter,
+    detached::Detached,
+    framed::{Frame, FramedCons, FramedProd},
+    grant::{Grant, GrantSlices, ReadGrant, Reservation},
+    item_iters::{DrainCloned, Iter, IterMut, View},
+    lossy_cons_iter::{LossyConsIter, Overrun},
+    mpsc_prod_iter::MpscProdIter,
+    observer::Observer,
+    owned_iters::{OwnedConsIter, OwnedProdIter},
+    prod_iter::ProdIter,
+    uninit_prod_iter::UninitProdIter,
+    window_iter::WindowIter,
+    work_iter::WorkIter,
 };
 
 use core::ptr;
-pub use iterator_trait::ORBIterator;
+pub use iterator_trait::{ExclusiveIter, ORBIterator};
 
+pub(crate) use iterator_trait::PrivateORBIterator;
 pub(crate) use iterator_trait::iter_macros::*;
 
 #[inline]
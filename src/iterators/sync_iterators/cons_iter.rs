@@ -1,13 +1,28 @@
+use core::mem::ManuallyDrop;
+use core::ptr;
+
+#[cfg(doc)]
+use crate::WaitStrategy;
+use crate::errors::{PopError, SliceError};
 #[allow(unused_imports)]
 use crate::iterators::ProdIter;
-use crate::iterators::iterator_trait::{ORBIterator, PrivateORBIterator};
+use crate::iterators::iterator_trait::{ExclusiveIter, ORBIterator, PrivateORBIterator};
 use crate::iterators::sync_iterators::Iter;
+use crate::iterators::sync_iterators::framed::FramedCons;
+use crate::iterators::sync_iterators::grant::ReadGrant;
+use crate::iterators::sync_iterators::item_iters::{self, DrainCloned, View};
+use crate::iterators::sync_iterators::observer::Observer;
+use crate::iterators::sync_iterators::wake_peers;
+use crate::iterators::sync_iterators::window_iter::WindowIter;
 use crate::iterators::{copy_from_slice_unchecked, private_impl};
 use crate::ring_buffer::OneRB;
 use crate::ring_buffer::storage_components::PStorageComponent;
 use crate::ring_buffer::storage_components::StorageComponent;
 use crate::ring_buffer::wrappers::refs::IntoRef;
-use crate::ring_buffer::{SharedRB, iters_components::PIterComponent};
+use crate::ring_buffer::{
+    SharedRB,
+    iters_components::{PIterComponent, SingleConsIterComp},
+};
 #[cfg(feature = "async")]
 use crate::{
     iterators::{AsyncConsIter, async_iterators::AsyncIterator},
@@ -19,14 +34,25 @@ Iterator used to pop data from the buffer.
 
 When working with types which implement both [`Copy`] and [`Clone`] traits, `copy` methods should be
 preferred over `clone` methods.
+
+When obtained from a broadcast buffer, each consumer is bound to a reader and keeps its own index:
+every reader sees all the items pushed by the producer.
+Methods moving items out of the buffer (e.g. [`Self::pop_move`]) must not be used in this case.
 "##]
-#[repr(transparent)]
 pub struct ConsIter<B: IntoRef + OneRB> {
     inner: Iter<B>,
+    reader: usize,
 }
 
 unsafe impl<B: IntoRef + OneRB + SharedRB> Send for ConsIter<B> {}
 
+impl<B: IntoRef + OneRB> Drop for ConsIter<B> {
+    fn drop(&mut self) {
+        self.inner.buffer.liveness().drop_reader(self.reader);
+        wake_peers(&*self.inner.buffer);
+    }
+}
+
 impl<B: IntoRef + OneRB<Item = T>, T> PrivateORBIterator for ConsIter<B> {
     type _Buffer = B;
 
@@ -37,21 +63,47 @@ impl<B: IntoRef + OneRB<Item = T>, T> PrivateORBIterator for ConsIter<B> {
         unsafe {
             self.inner.cached_avail = match self.inner.index <= succ_idx {
                 true => succ_idx.unchecked_sub(self.inner.index),
-                false => self
-                    .buffer()
-                    .storage()
-                    .len()
-                    .unchecked_sub(self.inner.index)
-                    .unchecked_add(succ_idx),
+                // The producer wrapped around, possibly skipping the tail of the storage.
+                false => match self.buffer().storage().wrap_mark() {
+                    Some(mark) if mark == self.inner.index => {
+                        // The mark is cleared once an index past it is published.
+                        self.inner.index = 0;
+
+                        succ_idx
+                    }
+                    Some(mark) if mark > self.inner.index => mark.unchecked_sub(self.inner.index),
+                    _ => self
+                        .buffer()
+                        .storage()
+                        .len()
+                        .unchecked_sub(self.inner.index)
+                        .unchecked_add(succ_idx),
+                },
             };
         }
 
         self.inner.cached_avail
     }
 
+    #[cfg(feature = "stats")]
+    #[inline]
+    fn atomic_index(&self) -> usize {
+        self.inner.buffer.iters().reader_index(self.reader)
+    }
+
     #[inline]
     fn set_atomic_index(&self, index: usize) {
-        self.inner.buffer.iters().set_cons_index(index);
+        let iters = self.inner.buffer.iters();
+
+        // The consumer wrapped around, going past the tail skipped by the producer, if any.
+        // The mark is cleared before the index is published, so that the producer cannot set
+        // a new one in the meantime.
+        if index < iters.reader_index(self.reader) {
+            self.inner.buffer.storage().set_wrap_mark(None);
+        }
+
+        iters.set_reader_index(self.reader, index);
+        self.inner.buffer.waiter().notify();
     }
 
     #[inline]
@@ -59,6 +111,44 @@ impl<B: IntoRef + OneRB<Item = T>, T> PrivateORBIterator for ConsIter<B> {
         self.inner.buffer.iters().middle_iter_idx()
     }
 
+    #[inline]
+    fn succ_ended(&self) -> bool {
+        let iters = self.inner.buffer.iters();
+        self.inner
+            .buffer
+            .liveness()
+            .chain_end(iters, iters.stages())
+            .0
+    }
+
+    #[inline]
+    fn _is_disconnected(&self) -> bool {
+        let iters = self.inner.buffer.iters();
+        let (ended, index) = self
+            .inner
+            .buffer
+            .liveness()
+            .chain_end(iters, iters.stages());
+
+        ended && index == self.inner.index
+    }
+
+    #[cfg(feature = "stats")]
+    #[inline]
+    fn record_advance(&self, from: usize, count: usize) {
+        if let Some(stats) = self.inner.buffer.iters().stats() {
+            stats.record_pop(from, count);
+        }
+    }
+
+    #[cfg(feature = "stats")]
+    #[inline]
+    fn record_failure(&self) {
+        if let Some(stats) = self.inner.buffer.iters().stats() {
+            stats.record_underrun();
+        }
+    }
+
     private_impl!();
 }
 
@@ -67,6 +157,8 @@ impl<B: IntoRef + OneRB<Item = T>, T> ORBIterator for ConsIter<B> {
     type Buffer = B;
 }
 
+impl<B: IntoRef + OneRB<Iters: SingleConsIterComp>> ExclusiveIter for ConsIter<B> {}
+
 #[cfg(feature = "async")]
 impl<B: IntoRef + OneRB<Iters: AsyncIterComp>> ConsIter<B> {
     pub fn into_async(self) -> AsyncConsIter<B> {
@@ -76,17 +168,43 @@ impl<B: IntoRef + OneRB<Iters: AsyncIterComp>> ConsIter<B> {
 
 impl<B: IntoRef + OneRB<Item = T>, T> ConsIter<B> {
     pub(crate) fn new(value: B::TargetRef) -> Self {
+        Self::new_reader(value, 0)
+    }
+
+    pub(crate) fn new_reader(value: B::TargetRef, reader: usize) -> Self {
+        let index = value.iters().reader_index(reader);
+
         Self {
-            inner: Iter::new(value),
+            inner: Iter::new(value, index),
+            reader,
         }
     }
 
+    /// Consumes the iterator without running its destructor, returning its reference to the buffer.
+    pub(crate) fn into_buffer_ref(self) -> B::TargetRef {
+        let this = ManuallyDrop::new(self);
+
+        unsafe { ptr::read(&this.inner.buffer) }
+    }
+
+    /// Returns a new [`Observer`] of the buffer, which reports its fill level without moving any iterator.
+    #[inline]
+    pub fn observer(&self) -> Observer<B> {
+        Observer::new(self.inner.buffer.clone())
+    }
+
+    /// Returns the reader this consumer is bound to.
+    /// Consumers obtained from non-broadcast buffers always return `0`.
+    #[inline]
+    pub fn reader(&self) -> usize {
+        self.reader
+    }
+
     /// Resets the index of the iterator. I.e., moves the iterator to the location occupied by its successor.
     #[inline]
     pub fn reset_index(&mut self) {
-        let new_idx = self.succ_index();
-        self.inner.index = new_idx;
-        self.set_atomic_index(new_idx);
+        self.inner.index = self.succ_index();
+        self.publish_index();
     }
 
     /// Returns a reference to an element.
@@ -106,19 +224,196 @@ impl<B: IntoRef + OneRB<Item = T>, T> ConsIter<B> {
     /// Being these references, [`Self::advance()`] has to be called when done with the data
     /// in order to move the iterator.
     /// </div>
+    ///
+    /// Returns:
+    /// * `Err(SliceError::Insufficient)`, if fewer than `count` items are available;
+    /// * `Err(SliceError::Disconnected)`, if `count` items will never be available;
+    /// * `Ok(slices)`, otherwise.
     #[inline]
     pub fn peek_slice<'a>(
         &mut self,
         count: usize,
-    ) -> Option<<<B as OneRB>::Storage as StorageComponent>::SliceOutput<'a>> {
-        self.check(count).then(|| {
+    ) -> Result<<<B as OneRB>::Storage as StorageComponent>::SliceOutput<'a>, SliceError> {
+        match self.check(count) {
+            true => Ok(self
+                .inner
+                .buffer
+                .storage()
+                .next_chunk(self.inner.index, count)),
+            false => Err(self.slice_error(count)),
+        }
+    }
+
+    /// Returns a reference to the element at `offset` from the head, without moving the consumer.
+    /// `peek_at(0)` is the same as [`Self::peek_ref`].
+    /// <div class="warning">
+    ///
+    /// Being this a reference, [`Self::advance()`] has to be called when done with the data
+    /// in order to move the iterator.
+    /// </div>
+    #[inline]
+    pub fn peek_at<'a>(&mut self, offset: usize) -> Option<&'a T> {
+        self.check(offset.saturating_add(1)).then(|| unsafe {
             self.inner
                 .buffer
                 .storage()
-                .next_chunk(self.inner.index, count)
+                ._index(self.offset_index(offset))
+                .inner_ref()
         })
     }
 
+    /// Same as [`Self::peek_slice`], but the slices start at `offset` from the head.
+    /// <div class="warning">
+    ///
+    /// Being these references, [`Self::advance()`] has to be called when done with the data
+    /// in order to move the iterator.
+    /// </div>
+    ///
+    /// Returns:
+    /// * `Err(SliceError::Insufficient)`, if fewer than `offset + count` items are available;
+    /// * `Err(SliceError::Disconnected)`, if `offset + count` items will never be available;
+    /// * `Ok(slices)`, otherwise.
+    #[inline]
+    pub fn peek_slice_at<'a>(
+        &mut self,
+        offset: usize,
+        count: usize,
+    ) -> Result<<<B as OneRB>::Storage as StorageComponent>::SliceOutput<'a>, SliceError> {
+        let end = offset.saturating_add(count);
+
+        match self.check(end) {
+            true => Ok(self
+                .inner
+                .buffer
+                .storage()
+                .next_chunk(self.offset_index(offset), count)),
+            false => Err(self.slice_error(end)),
+        }
+    }
+
+    /// Returns the newest `count` items, without moving the consumer, as a single slice, when using
+    /// `VmemStorage`, or a tuple of two, split at the end of the buffer, otherwise.
+    ///
+    /// If the producer skipped the tail of the storage (see [`ProdIter::reserve`](crate::iterators::ProdIter::reserve)),
+    /// only the items written after it are returned, until the consumer goes past it.
+    ///
+    /// Returns:
+    /// * `Err(SliceError::Insufficient)`, if fewer than `count` items are available;
+    /// * `Err(SliceError::Disconnected)`, if `count` items will never be available;
+    /// * `Ok(slices)`, otherwise.
+    #[inline]
+    pub fn peek_latest<'a>(
+        &mut self,
+        count: usize,
+    ) -> Result<<<B as OneRB>::Storage as StorageComponent>::SliceOutput<'a>, SliceError> {
+        let (before, after) = self.split_available();
+
+        match after.checked_sub(count) {
+            Some(start) if before > 0 => Ok(self.inner.buffer.storage().next_chunk(start, count)),
+            Some(offset) => self.peek_slice_at(offset, count),
+            None if before > 0 => Err(SliceError::Insufficient {
+                requested: count,
+                available: after,
+            }),
+            None => Err(self.slice_error(count)),
+        }
+    }
+
+    /// Discards the available items but the newest `keep`, moving the consumer forward.
+    ///
+    /// Discarded items which are owned by the buffer (e.g. within [`OwnedHeapRB`](crate::OwnedHeapRB))
+    /// are dropped, while the others stay in place, until the producer overwrites them.
+    ///
+    /// Returns the number of discarded items.
+    pub fn skip_to_latest(&mut self, keep: usize) -> usize {
+        let (before, after) = self.split_available();
+        let count = (before + after).saturating_sub(keep);
+        let head = count.min(before);
+
+        unsafe { self.discard(head) };
+
+        if count > head {
+            // Moves the consumer past the skipped tail, if any.
+            self._available();
+
+            unsafe { self.discard(count - head) };
+        }
+
+        count
+    }
+
+    /// Returns the number of available items before the tail of the storage skipped by the producer,
+    /// along with the number of the ones written after it.
+    /// If no tail is pending, the former is `0`.
+    #[inline]
+    fn split_available(&mut self) -> (usize, usize) {
+        let avail = self._available();
+        let (index, succ_idx) = (self.inner.index, self.succ_index());
+
+        match self.buffer().storage().wrap_mark() {
+            Some(mark) if succ_idx < index && index < mark => (mark - index, succ_idx),
+            _ => (0, avail),
+        }
+    }
+
+    /// Drops the next `count` items, if owned by the buffer, and moves the consumer past them.
+    ///
+    /// # Safety
+    /// `count` items must be available.
+    unsafe fn discard(&mut self, count: usize) {
+        if count == 0 {
+            return;
+        }
+
+        if self.inner.buffer.iters().owns_items() {
+            let storage = self.inner.buffer.storage();
+
+            for offset in 0..count {
+                let idx = self.offset_index(offset);
+                unsafe { storage._index(idx).as_mut_ptr().drop_in_place() };
+            }
+        }
+
+        unsafe { self.advance(count) };
+    }
+
+    /// Returns a [`View`] over the items currently available, without moving the consumer.
+    #[inline]
+    pub fn view(&mut self) -> View<'_, B> {
+        let len = self._available();
+
+        View::new(&self.inner.buffer, self.inner.index, len)
+    }
+
+    /// Returns the index of the location at `offset` from the consumer.
+    #[inline]
+    fn offset_index(&self, offset: usize) -> usize {
+        let len = self.inner.buffer.len();
+
+        match self.inner.index + offset {
+            idx if idx >= len => idx - len,
+            idx => idx,
+        }
+    }
+
+    /// Same as [`Self::peek_slice`], but returns a [`ReadGrant`], which advances the consumer
+    /// by itself when dropped.
+    #[inline]
+    pub fn read_grant(&mut self, count: usize) -> Result<ReadGrant<'_, B>, SliceError> {
+        ReadGrant::new(self, count)
+    }
+
+    /// Returns a [`WindowIter`] over overlapping windows of `window` items, each starting
+    /// `hop` items after the previous one.
+    ///
+    /// # Panics
+    /// Panics if `hop` is zero or greater than `window`, or if `window` is longer than the items
+    /// the buffer can hold, minus those kept by [`Self::history`].
+    #[inline]
+    pub fn windows(&mut self, window: usize, hop: usize) -> WindowIter<'_, B> {
+        WindowIter::new(self, window, hop)
+    }
+
     /// Returns a tuple of slice references, the sum of which with len equal to available data.
     /// <div class="warning">
     ///
@@ -131,8 +426,86 @@ impl<B: IntoRef + OneRB<Item = T>, T> ConsIter<B> {
     ) -> Option<<<B as OneRB>::Storage as StorageComponent>::SliceOutput<'a>> {
         match self.available() {
             0 => None,
-            avail => self.peek_slice(avail),
+            avail => self.peek_slice(avail).ok(),
+        }
+    }
+
+    /// Returns the last `k` consumed items, followed by the available ones, as a single slice,
+    /// when using `VmemStorage`, or a tuple of two, split at the end of the buffer, otherwise.
+    ///
+    /// Consumed items are kept only if the buffer was built with
+    /// [`OneRingBuf::with_lookback`](crate::OneRingBuf::with_lookback).
+    ///
+    /// Returns `None` if `k` is greater than the number of items kept by the buffer.
+    #[inline]
+    pub fn history<'a>(
+        &mut self,
+        k: usize,
+    ) -> Option<<<B as OneRB>::Storage as StorageComponent>::SliceOutput<'a>> {
+        if k > self.inner.buffer.lookback() {
+            return None;
         }
+
+        let avail = self._available();
+        let len = self.inner.buffer.len();
+        let start = (self.inner.index + len - k) % len;
+
+        Some(self.inner.buffer.storage().next_chunk(start, k + avail))
+    }
+
+    /// Returns an iterator which clones the available items out of the buffer.
+    ///
+    /// The consumer is moved forward as items are yielded, and its index is published
+    /// when the returned iterator is dropped. The originals stay in the buffer until the
+    /// producer overwrites them; see [`DrainCloned`].
+    #[inline]
+    pub fn drain_cloned(&mut self) -> DrainCloned<'_, B>
+    where
+        T: Clone,
+    {
+        DrainCloned::new(self)
+    }
+
+    /// Returns an iterator over references to the items currently available, without moving the consumer.
+    #[inline]
+    pub fn iter(&mut self) -> item_iters::Iter<'_, B> {
+        let remaining = self._available();
+
+        item_iters::Iter::new(&self.inner.buffer, self.inner.index, remaining)
+    }
+
+    /// Returns the offset from the head of the first available item for which `pred` returns `true`,
+    /// without moving the consumer.
+    #[inline]
+    pub fn find(&mut self, pred: impl FnMut(&T) -> bool) -> Option<usize> {
+        self.iter().position(pred)
+    }
+
+    /// Returns the offset from the head of the first available item equal to `value`,
+    /// without moving the consumer.
+    #[inline]
+    pub fn position_of(&mut self, value: &T) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        self.find(|item| item == value)
+    }
+
+    /// Returns the offset from the head at which `needle` first appears among the available items,
+    /// without moving the consumer. An empty `needle` is found at offset `0`.
+    pub fn find_subslice(&mut self, needle: &[T]) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        let view = self.view();
+        let last = view.len().checked_sub(needle.len())?;
+
+        (0..=last).find(|&start| {
+            needle
+                .iter()
+                .enumerate()
+                .all(|(i, item)| view[start + i] == *item)
+        })
     }
 
     /// Tries to pop an element, moving it.
@@ -140,8 +513,8 @@ impl<B: IntoRef + OneRB<Item = T>, T> ConsIter<B> {
     /// This method moves items, so locations from which they are moved out are left uninitialised.
     /// These locations must be re-initialised used proper [`ProdIter`] methods (`*_init`) ones
     #[inline]
-    pub unsafe fn pop_move(&mut self) -> Option<T> {
-        self.next()
+    pub unsafe fn pop_move(&mut self) -> Result<T, PopError> {
+        self.next().ok_or_else(|| self.pop_failure())
     }
 
     /// Tries to pop an element, duplicating it.
@@ -153,23 +526,43 @@ impl<B: IntoRef + OneRB<Item = T>, T> ConsIter<B> {
     /// gets deallocated, dropping its copy results in a double-free.
     /// `Self::pop` and `Self::pop_clone` should be preferred over this method.
     #[inline]
-    pub unsafe fn pop_unsafe(&mut self) -> Option<T> {
-        self.next_duplicate()
+    pub unsafe fn pop_unsafe(&mut self) -> Result<T, PopError> {
+        self.next_duplicate().ok_or_else(|| self.pop_failure())
     }
 
     /// Tries to pop an element, copying it.
+    ///
+    /// Returns:
+    /// * `Err(PopError::Empty)`, if the buffer is empty;
+    /// * `Err(PopError::Disconnected)`, if the buffer is empty and the iterator is
+    ///   [disconnected](ORBIterator::is_disconnected);
+    /// * `Ok(item)`, otherwise.
     #[inline]
-    pub fn pop(&mut self) -> Option<T>
+    pub fn pop(&mut self) -> Result<T, PopError>
     where
         T: Copy,
     {
-        self.next_duplicate()
+        self.next_duplicate().ok_or_else(|| self.pop_failure())
+    }
+
+    /// Same as [`Self::pop`], but waits until an element is available,
+    /// according to the [`WaitStrategy`] of the buffer.
+    ///
+    /// Returns `Err(PopError::Disconnected)` only if the iterator got [disconnected](ORBIterator::is_disconnected).
+    #[inline]
+    pub fn pop_blocking(&mut self) -> Result<T, PopError>
+    where
+        T: Copy,
+    {
+        self.wait_for(1);
+
+        self.pop()
     }
 
     /// Tries to pop an element, cloning it.
     /// When possible, `Self::pop` should be preferred over this method.
     #[inline]
-    pub fn pop_clone(&mut self) -> Option<T>
+    pub fn pop_clone(&mut self) -> Result<T, PopError>
     where
         T: Clone,
     {
@@ -179,23 +572,23 @@ impl<B: IntoRef + OneRB<Item = T>, T> ConsIter<B> {
                 self.advance(1);
             }
         }
-        ret
+        ret.ok_or_else(|| self.pop_failure())
     }
 
     #[inline]
-    fn _extract_item(&mut self, dst: &mut T, f: fn(&T, &mut T)) -> Option<()> {
+    fn _extract_item(&mut self, dst: &mut T, f: fn(&T, &mut T)) -> Result<(), PopError> {
         if let Some(v) = self.next_ref() {
             f(v, dst);
 
             unsafe { self.advance(1) };
-            Some(())
+            Ok(())
         } else {
-            None
+            Err(self.pop_failure())
         }
     }
 
-    /// - Returns `Some(())`, copying next item into `dst`, if available.
-    /// - Returns `None` doing nothing, otherwise.
+    /// - Returns `Ok(())`, copying next item into `dst`, if available.
+    /// - Returns `Err(PopError)` doing nothing, otherwise.
     ///
     /// This method uses `copy` and should be preferred over `clone` version, if possible.
     /// <div class="warning">
@@ -203,7 +596,7 @@ impl<B: IntoRef + OneRB<Item = T>, T> ConsIter<B> {
     /// Unlike `peek*` methods, this one automatically advances the iterator.
     /// </div>
     #[inline]
-    pub fn copy_item(&mut self, dst: &mut T) -> Option<()>
+    pub fn copy_item(&mut self, dst: &mut T) -> Result<(), PopError>
     where
         T: Copy,
     {
@@ -220,7 +613,7 @@ impl<B: IntoRef + OneRB<Item = T>, T> ConsIter<B> {
     /// Unlike `peek*` methods, this one automatically advances the iterator.
     /// </div>
     #[inline]
-    pub fn clone_item(&mut self, dst: &mut T) -> Option<()>
+    pub fn clone_item(&mut self, dst: &mut T) -> Result<(), PopError>
     where
         T: Clone,
     {
@@ -231,20 +624,39 @@ impl<B: IntoRef + OneRB<Item = T>, T> ConsIter<B> {
     }
 
     #[inline]
-    fn _extract_slice(&mut self, dst: &mut [T], f: fn(&[T], &mut [T])) -> Option<()> {
+    fn _extract_slice(&mut self, dst: &mut [T], f: fn(&[T], &mut [T])) -> Result<(), SliceError> {
         let count = dst.len();
 
-        self.check(count).then(|| {
+        if !self.check(count) {
+            return Err(self.slice_failure(count));
+        }
+
+        self.inner
+            .buffer
+            .storage_mut()
+            ._extract_slice(self.inner.index, dst, f);
+        unsafe { self.advance(count) };
+
+        Ok(())
+    }
+
+    #[inline]
+    fn _extract_slice_partial(&mut self, dst: &mut [T], f: fn(&[T], &mut [T])) -> usize {
+        let count = dst.len().min(self._available());
+
+        if count > 0 {
             self.inner
                 .buffer
                 .storage_mut()
-                ._extract_slice(self.inner.index, dst, f);
+                ._extract_slice(self.inner.index, &mut dst[..count], f);
             unsafe { self.advance(count) };
-        })
+        }
+
+        count
     }
 
-    /// - Returns `Some(())`, filling `dst` slice with the next `dst.len()` values, if available.
-    /// - Returns `None` doing nothing, otherwise.
+    /// - Returns `Ok(())`, filling `dst` slice with the next `dst.len()` values, if available.
+    /// - Returns `Err(SliceError)` doing nothing, otherwise.
     ///
     /// This method fills the slice using `copy` and should be preferred over `clone` version, if possible.
     /// <div class="warning">
@@ -252,7 +664,7 @@ impl<B: IntoRef + OneRB<Item = T>, T> ConsIter<B> {
     /// Unlike `peek*` methods, this one automatically advances the iterator.
     /// </div>
     #[inline]
-    pub fn copy_slice(&mut self, dst: &mut [T]) -> Option<()>
+    pub fn copy_slice(&mut self, dst: &mut [T]) -> Result<(), SliceError>
     where
         T: Copy,
     {
@@ -263,13 +675,104 @@ impl<B: IntoRef + OneRB<Item = T>, T> ConsIter<B> {
         self._extract_slice(dst, f)
     }
 
+    /// Same as [`Self::copy_slice`], but returns the next `N` values as an array.
+    ///
+    /// Returns:
+    /// * `Err(SliceError::Insufficient)`, doing nothing, if fewer than `N` values are available;
+    /// * `Err(SliceError::Disconnected)`, doing nothing, if `N` values will never be available;
+    /// * `Ok(array)`, otherwise.
+    /// <div class="warning">
+    ///
+    /// Unlike `peek*` methods, this one automatically advances the iterator.
+    /// </div>
+    #[inline]
+    pub fn pop_array<const N: usize>(&mut self) -> Result<[T; N], SliceError>
+    where
+        T: Copy,
+    {
+        if !self.check(N) {
+            return Err(self.slice_failure(N));
+        }
+
+        let (index, len) = (self.inner.index, self.inner.buffer.len());
+        let storage = self.inner.buffer.storage();
+        let ret =
+            core::array::from_fn(|i| unsafe { *storage._index((index + i) % len).inner_ref() });
+
+        unsafe { self.advance(N) };
+
+        Ok(ret)
+    }
+
+    /// Same as [`Self::copy_slice`], but copies as many values as are available, instead of
+    /// failing when fewer than `dst.len()` are.
+    ///
+    /// Returns the number of values copied, which fill the beginning of `dst`.
+    /// <div class="warning">
+    ///
+    /// Unlike `peek*` methods, this one automatically advances the iterator.
+    /// </div>
+    #[inline]
+    pub fn copy_slice_partial(&mut self, dst: &mut [T]) -> usize
+    where
+        T: Copy,
+    {
+        fn f<T: Copy>(binding: &[T], dst: &mut [T]) {
+            copy_from_slice_unchecked(binding, dst);
+        }
+
+        self._extract_slice_partial(dst, f)
+    }
+
+    /// Same as [`Self::copy_slice`], but waits until `dst.len()` values are available,
+    /// according to the [`WaitStrategy`] of the buffer.
+    ///
+    /// Returns `Err(SliceError::Disconnected)`, doing nothing, only if the iterators preceding this one are gone
+    /// before `dst.len()` values became available.
+    ///
+    /// # Panics
+    /// Panics if `dst` is not shorter than the buffer, as it could never be filled.
+    #[inline]
+    pub fn copy_slice_blocking(&mut self, dst: &mut [T]) -> Result<(), SliceError>
+    where
+        T: Copy,
+    {
+        assert!(dst.len() < self.buf_len());
+
+        self.wait_for(dst.len());
+
+        self.copy_slice(dst)
+    }
+
+    /// Copies the available items into `dst`, up to and including the first one equal to `delim`,
+    /// then advances the iterator past them.
+    ///
+    /// Returns:
+    /// * `Some(count)`, with the number of items copied, delimiter included;
+    /// * `None`, doing nothing, if `delim` is not among the first `dst.len()` available items.
+    /// <div class="warning">
+    ///
+    /// Unlike `peek*` methods, this one automatically advances the iterator.
+    /// </div>
+    #[inline]
+    pub fn read_until(&mut self, delim: &T, dst: &mut [T]) -> Option<usize>
+    where
+        T: Copy + PartialEq,
+    {
+        let count = self.iter().take(dst.len()).position(|item| item == delim)? + 1;
+
+        self.copy_slice(&mut dst[..count]).ok()?;
+
+        Some(count)
+    }
+
     /// Same as [`Self::copy_slice`], but uses `clone`, instead.
     /// <div class="warning">
     ///
     /// Unlike `peek*` methods, this one automatically advances the iterator.
     /// </div>
     #[inline]
-    pub fn clone_slice(&mut self, dst: &mut [T]) -> Option<()>
+    pub fn clone_slice(&mut self, dst: &mut [T]) -> Result<(), SliceError>
     where
         T: Clone,
     {
@@ -279,6 +782,34 @@ impl<B: IntoRef + OneRB<Item = T>, T> ConsIter<B> {
 
         self._extract_slice(dst, f)
     }
+
+    /// Same as [`Self::copy_slice_partial`], but uses `clone`, instead.
+    /// <div class="warning">
+    ///
+    /// Unlike `peek*` methods, this one automatically advances the iterator.
+    /// </div>
+    #[inline]
+    pub fn clone_slice_partial(&mut self, dst: &mut [T]) -> usize
+    where
+        T: Clone,
+    {
+        fn f<T: Clone>(binding: &[T], dst: &mut [T]) {
+            dst.clone_from_slice(binding);
+        }
+
+        self._extract_slice_partial(dst, f)
+    }
+}
+
+impl<B: IntoRef + OneRB<Item = u8>> ConsIter<B> {
+    /// Wraps the consumer into a [`FramedCons`], reading frames up to `max_frame` bytes long.
+    ///
+    /// # Panics
+    /// Panics if a frame of `max_frame` bytes, along with its header, does not fit within the items
+    /// the buffer can hold, minus those kept by [`Self::history`].
+    pub fn framed(self, max_frame: usize) -> FramedCons<B> {
+        FramedCons::new(self, max_frame)
+    }
 }
 
 mod test {
@@ -1,15 +1,20 @@
 use crate::OneRB;
 use crate::StorageComponent;
+use crate::errors::SliceError;
 #[allow(unused_imports)]
 use crate::iterators::WorkIter;
-use crate::iterators::iterator_trait::ORBIterator;
+use crate::iterators::iterator_trait::{ExclusiveIter, ORBIterator};
 use crate::iterators::util_macros::delegate;
 use crate::iterators::util_macros::muncher;
+use crate::ring_buffer::storage_components::PStorageComponent;
+use crate::ring_buffer::wait::CancelToken;
 #[cfg(feature = "async")]
 use crate::{
     iterators::{AsyncDetached, async_iterators::AsyncIterator},
     iters_components::async_iters::AsyncIterComp,
 };
+#[cfg(feature = "std")]
+use std::time::{Duration, Instant};
 
 #[doc = r##"
 Detached iterator: does not update the atomic index when advancing.
@@ -72,6 +77,24 @@ impl<T, I: ORBIterator<Item = T>> Detached<I> {
 
     delegate!(ORBIterator (inline), pub fn available(&(mut) self) -> usize);
     delegate!(ORBIterator (inline), pub fn wait_for(&(mut) self, count: usize));
+
+    /// Same as [`ORBIterator::wait_for_timeout`].
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn wait_for_timeout(&mut self, count: usize, timeout: Duration) -> bool {
+        self.inner_mut().wait_for_timeout(count, timeout)
+    }
+    /// Same as [`ORBIterator::wait_for_until`].
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn wait_for_until(&mut self, count: usize, deadline: Instant) -> bool {
+        self.inner_mut().wait_for_until(count, deadline)
+    }
+    /// Same as [`ORBIterator::wait_for_cancellable`].
+    #[inline]
+    pub fn wait_for_cancellable(&mut self, count: usize, token: &CancelToken) -> bool {
+        self.inner_mut().wait_for_cancellable(count, token)
+    }
     delegate!(ORBIterator (inline), pub fn index(&self) -> usize);
     delegate!(ORBIterator (inline), pub fn buf_len(&self) -> usize);
 
@@ -104,13 +127,21 @@ impl<T, I: ORBIterator<Item = T>> Detached<I> {
 
     /// Goes back, wrapping if necessary.
     ///
+    /// Wrapping around, the tail of the storage skipped by the producer, if any
+    /// (see [`ProdIter::reserve`](crate::iterators::ProdIter::reserve)), is skipped as well.
+    ///
     /// # Safety
     /// Index must always be between consumer and producer.
     pub unsafe fn go_back(&mut self, count: usize) {
         let idx = self.inner.index();
 
         self.inner.set_local_index(match idx < count {
-            true => unsafe { self.inner.buf_len().unchecked_sub(count).unchecked_sub(idx) },
+            true => {
+                let end = self.inner.buffer().storage().wrap_mark();
+                let end = end.unwrap_or(self.inner.buf_len());
+
+                unsafe { end.unchecked_sub(count.unchecked_sub(idx)) }
+            }
             false => unsafe { idx.unchecked_sub(count) },
         });
 
@@ -121,17 +152,28 @@ impl<T, I: ORBIterator<Item = T>> Detached<I> {
 
     delegate!(ORBIterator (inline), pub fn prod_index(&self) -> usize);
     delegate!(ORBIterator (inline), pub fn work_index(&self) -> usize);
+    delegate!(ORBIterator (inline), pub fn stage_index(&self, stage: usize) -> Option<usize>);
     delegate!(ORBIterator (inline), pub fn cons_index(&self) -> usize);
+    delegate!(ORBIterator (inline), pub fn reader_index(&self, reader: usize) -> Option<usize>);
 
-    delegate!(ORBIterator (inline), pub fn get_mut(&(mut) self) -> Option<&'_ mut T>);
-    delegate!(ORBIterator (inline), pub fn get_mut_slice_exact(&(mut) self, count: usize) -> Option<<<I::Buffer as OneRB>::Storage as StorageComponent>::SliceOutputMut<'_>>);
-    delegate!(ORBIterator (inline), pub fn get_mut_slice_avail(&(mut) self) -> Option<<<I::Buffer as OneRB>::Storage as StorageComponent>::SliceOutputMut<'_>>);
-    delegate!(ORBIterator (inline), pub fn get_mut_slice_multiple_of(&(mut) self, rhs: usize) -> Option<<<I::Buffer as OneRB>::Storage as StorageComponent>::SliceOutputMut<'_>>);
+    delegate!(ORBIterator (inline), pub fn prod_alive(&self) -> bool);
+    delegate!(ORBIterator (inline), pub fn work_alive(&self) -> bool);
+    delegate!(ORBIterator (inline), pub fn stage_alive(&self, stage: usize) -> bool);
+    delegate!(ORBIterator (inline), pub fn cons_alive(&self) -> bool);
+    delegate!(ORBIterator (inline), pub fn reader_alive(&self, reader: usize) -> bool);
+    delegate!(ORBIterator (inline), pub fn is_disconnected(&self) -> bool);
 
     /// Synchronises the underlying atomic index with the local index. I.e. let the consumer iterator
     /// advance.
     #[inline]
     pub fn sync_index(&self) {
-        self.inner.set_atomic_index(self.inner.index());
+        self.inner.publish_index();
     }
 }
+
+impl<T, I: ExclusiveIter<Item = T>> Detached<I> {
+    delegate!(ORBIterator (inline), pub fn get_mut(&(mut) self) -> Option<&'_ mut T>);
+    delegate!(ORBIterator (inline), pub fn get_mut_slice_exact(&(mut) self, count: usize) -> Result<<<I::Buffer as OneRB>::Storage as StorageComponent>::SliceOutputMut<'_>, SliceError>);
+    delegate!(ORBIterator (inline), pub fn get_mut_slice_avail(&(mut) self) -> Option<<<I::Buffer as OneRB>::Storage as StorageComponent>::SliceOutputMut<'_>>);
+    delegate!(ORBIterator (inline), pub fn get_mut_slice_multiple_of(&(mut) self, rhs: usize) -> Option<<<I::Buffer as OneRB>::Storage as StorageComponent>::SliceOutputMut<'_>>);
+}
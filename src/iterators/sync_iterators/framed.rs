@@ -0,0 +1,344 @@
+use crate::errors::{FrameError, SliceError};
+use crate::iterators::copy_from_slice_unchecked;
+use crate::iterators::iterator_trait::{ORBIterator, PrivateORBIterator};
+use crate::iterators::sync_iterators::observer::Observer;
+use crate::iterators::util_macros::delegate;
+use crate::iterators::util_macros::muncher;
+use crate::iterators::{ConsIter, ProdIter};
+use crate::ring_buffer::OneRB;
+use crate::ring_buffer::storage_components::{PStorageComponent, StorageComponent};
+use crate::ring_buffer::wrappers::refs::IntoRef;
+
+type SliceOutput<'a, B> = <<B as OneRB>::Storage as StorageComponent>::SliceOutput<'a>;
+
+/// Maximum length of a header: a LEB128 varint holding a `u64`.
+const MAX_HEADER: usize = 10;
+
+/// Encodes `len` as a LEB128 varint into `out`, returning the number of bytes written.
+#[inline]
+fn encode_header(mut len: usize, out: &mut [u8; MAX_HEADER]) -> usize {
+    let mut i = 0;
+
+    loop {
+        let byte = (len & 0x7f) as u8;
+        len >>= 7;
+
+        if len == 0 {
+            out[i] = byte;
+            return i + 1;
+        }
+
+        out[i] = byte | 0x80;
+        i += 1;
+    }
+}
+
+/// Returns the number of bytes needed to encode `len` as a LEB128 varint.
+#[inline]
+fn header_len(len: usize) -> usize {
+    encode_header(len, &mut [0; MAX_HEADER])
+}
+
+/// Checks that frames up to `max_frame` bytes fit within the buffer of `iter`.
+fn check_max_frame(max_frame: usize, iter: &impl PrivateORBIterator) {
+    // Items kept for `ConsIter::history` are never available.
+    let capacity = (iter.buffer().len() - 1).saturating_sub(iter.buffer().lookback());
+
+    assert!(
+        max_frame + header_len(max_frame) <= capacity,
+        "frames of {max_frame} bytes do not fit within a buffer holding {capacity} bytes"
+    );
+}
+
+#[doc = r##"
+Producer writing length-prefixed frames into a byte buffer, returned by [`ProdIter::framed`].
+
+Every frame is made up of a LEB128 varint header, holding the length of the payload, followed by the
+payload itself. A frame is written only if it fits entirely, and it is published all at once, so that
+the consumer never sees a partial frame.
+"##]
+pub struct FramedProd<B: IntoRef + OneRB<Item = u8>> {
+    inner: ProdIter<B>,
+    max_frame: usize,
+}
+
+impl<B: IntoRef + OneRB<Item = u8>> FramedProd<B> {
+    pub(crate) fn new(inner: ProdIter<B>, max_frame: usize) -> Self {
+        check_max_frame(max_frame, &inner);
+
+        Self { inner, max_frame }
+    }
+
+    #[inline]
+    fn inner(&self) -> &ProdIter<B> {
+        &self.inner
+    }
+    #[inline]
+    fn inner_mut(&mut self) -> &mut ProdIter<B> {
+        &mut self.inner
+    }
+
+    delegate!(ORBIterator (inline), pub fn available(&(mut) self) -> usize);
+    delegate!(ORBIterator (inline), pub fn buf_len(&self) -> usize);
+    delegate!(ORBIterator (inline), pub fn alive_iters(&self) -> u8);
+    delegate!(ORBIterator (inline), pub fn is_disconnected(&self) -> bool);
+    delegate!(ProdIter (inline), pub fn observer(&self) -> Observer<B>);
+
+    /// Returns the maximum length of the payload of a frame.
+    #[inline]
+    pub fn max_frame(&self) -> usize {
+        self.max_frame
+    }
+
+    /// Returns the underlying producer.
+    #[inline]
+    pub fn into_inner(self) -> ProdIter<B> {
+        self.inner
+    }
+
+    /// Tries to push `payload` as a whole frame.
+    ///
+    /// Returns:
+    /// * `Err(FrameError::TooLarge)`, doing nothing, if `payload` is longer than [`Self::max_frame`];
+    /// * `Err(FrameError::Insufficient)`, doing nothing, if there is not enough room for the whole frame;
+    /// * `Err(FrameError::Disconnected)`, doing nothing, if there is not enough room for the whole frame
+    ///   and the iterator is [disconnected](ORBIterator::is_disconnected);
+    /// * `Ok(())`, otherwise.
+    pub fn push(&mut self, payload: &[u8]) -> Result<(), FrameError> {
+        if payload.len() > self.max_frame {
+            return Err(FrameError::TooLarge {
+                len: payload.len(),
+                max: self.max_frame,
+            });
+        }
+
+        let mut header = [0; MAX_HEADER];
+        let header_len = encode_header(payload.len(), &mut header);
+        let count = header_len + payload.len();
+
+        if !self.inner.check(count) {
+            return Err(self.inner.slice_failure(count).into());
+        }
+
+        fn f(binding: &mut [u8], slice: &[u8]) {
+            copy_from_slice_unchecked(slice, binding);
+        }
+
+        let buffer = self.inner.buffer();
+        let (index, len) = (self.inner._index(), buffer.len());
+        let storage = buffer.storage_mut();
+
+        storage._push_slice(index, &header[..header_len], f);
+        storage._push_slice((index + header_len) % len, payload, f);
+        unsafe { self.inner.advance(count) };
+
+        Ok(())
+    }
+
+    /// Same as [`Self::push`], but waits until there is room for the whole frame.
+    ///
+    /// Returns:
+    /// * `Err(FrameError::TooLarge)`, if `payload` is longer than [`Self::max_frame`];
+    /// * `Err(FrameError::Disconnected)`, if the consumer got dropped while waiting;
+    /// * `Ok(())`, otherwise.
+    pub fn push_blocking(&mut self, payload: &[u8]) -> Result<(), FrameError> {
+        if payload.len() <= self.max_frame {
+            self.inner
+                .wait_for(header_len(payload.len()) + payload.len());
+        }
+
+        self.push(payload)
+    }
+}
+
+#[doc = r##"
+Consumer reading length-prefixed frames from a byte buffer, returned by [`ConsIter::framed`].
+
+See [`FramedProd`] for the layout of a frame.
+"##]
+pub struct FramedCons<B: IntoRef + OneRB<Item = u8>> {
+    inner: ConsIter<B>,
+    max_frame: usize,
+}
+
+impl<B: IntoRef + OneRB<Item = u8>> FramedCons<B> {
+    pub(crate) fn new(inner: ConsIter<B>, max_frame: usize) -> Self {
+        check_max_frame(max_frame, &inner);
+
+        Self { inner, max_frame }
+    }
+
+    #[inline]
+    fn inner(&self) -> &ConsIter<B> {
+        &self.inner
+    }
+    #[inline]
+    fn inner_mut(&mut self) -> &mut ConsIter<B> {
+        &mut self.inner
+    }
+
+    delegate!(ORBIterator (inline), pub fn available(&(mut) self) -> usize);
+    delegate!(ORBIterator (inline), pub fn buf_len(&self) -> usize);
+    delegate!(ORBIterator (inline), pub fn alive_iters(&self) -> u8);
+    delegate!(ORBIterator (inline), pub fn is_disconnected(&self) -> bool);
+    delegate!(ConsIter (inline), pub fn observer(&self) -> Observer<B>);
+
+    /// Returns the maximum length of the payload of a frame.
+    #[inline]
+    pub fn max_frame(&self) -> usize {
+        self.max_frame
+    }
+
+    /// Returns the underlying consumer.
+    #[inline]
+    pub fn into_inner(self) -> ConsIter<B> {
+        self.inner
+    }
+
+    /// Reads the header of the next frame, returning the lengths of the header and of the payload.
+    ///
+    /// `error` tells why the whole header is not available yet.
+    fn next_header(
+        &mut self,
+        error: fn(&mut ConsIter<B>, usize) -> SliceError,
+    ) -> Result<(usize, usize), FrameError> {
+        let mut len = 0u64;
+
+        for i in 0..MAX_HEADER {
+            let Some(&byte) = self.inner.peek_at(i) else {
+                return Err(error(&mut self.inner, i + 1).into());
+            };
+
+            len |= ((byte & 0x7f) as u64) << (7 * i);
+
+            if byte & 0x80 == 0 {
+                return match usize::try_from(len) {
+                    Ok(len) if len <= self.max_frame => Ok((i + 1, len)),
+                    _ => Err(FrameError::TooLarge {
+                        len: len as usize,
+                        max: self.max_frame,
+                    }),
+                };
+            }
+        }
+
+        Err(FrameError::TooLarge {
+            len: usize::MAX,
+            max: self.max_frame,
+        })
+    }
+
+    /// Returns the payload of the next frame, as a single slice, when using `VmemStorage`,
+    /// or a tuple of two, split at the end of the buffer, otherwise.
+    ///
+    /// The consumer is moved past the frame when the returned [`Frame`] is dropped.
+    ///
+    /// Returns:
+    /// * `Err(FrameError::TooLarge)`, if the header announces more than [`Self::max_frame`] bytes,
+    ///   meaning that the data within the buffer is not made up of frames;
+    /// * `Err(FrameError::Insufficient)`, if the whole frame has not been written yet;
+    /// * `Err(FrameError::Disconnected)`, if the whole frame will never be available;
+    /// * `Ok(frame)`, otherwise.
+    pub fn next_frame(&mut self) -> Result<Frame<'_, B>, FrameError> {
+        let (header_len, len) = self.next_header(ConsIter::slice_error)?;
+        self.inner.peek_slice_at(header_len, len)?;
+
+        Ok(Frame {
+            index: (self.inner._index() + header_len) % self.inner.buf_len(),
+            iter: &mut self.inner,
+            len,
+            count: header_len + len,
+        })
+    }
+
+    /// Copies the payload of the next frame into `dst`, then moves the consumer past the frame.
+    ///
+    /// Returns:
+    /// * `Err(FrameError::TooLarge)`, doing nothing, if the payload is longer than `dst`,
+    ///   or if the header announces more than [`Self::max_frame`] bytes;
+    /// * `Err(FrameError::Insufficient)`, doing nothing, if the whole frame has not been written yet;
+    /// * `Err(FrameError::Disconnected)`, doing nothing, if the whole frame will never be available;
+    /// * `Ok(len)`, with the length of the payload, otherwise.
+    pub fn pop_frame(&mut self, dst: &mut [u8]) -> Result<usize, FrameError> {
+        let (header_len, len) = self.next_header(ConsIter::slice_failure)?;
+
+        if len > dst.len() {
+            return Err(FrameError::TooLarge {
+                len,
+                max: dst.len(),
+            });
+        }
+        if !self.inner.check(header_len + len) {
+            return Err(self.inner.slice_failure(header_len + len).into());
+        }
+
+        fn f(binding: &[u8], dst: &mut [u8]) {
+            copy_from_slice_unchecked(binding, dst);
+        }
+
+        let buffer = self.inner.buffer();
+        let index = (self.inner._index() + header_len) % buffer.len();
+
+        buffer
+            .storage_mut()
+            ._extract_slice(index, &mut dst[..len], f);
+        unsafe { self.inner.advance(header_len + len) };
+
+        Ok(len)
+    }
+}
+
+#[doc = r##"
+Payload of a frame, returned by [`FramedCons::next_frame`].
+
+The payload is exposed by [`Self::slices`] as the same slices returned by [`ConsIter::peek_slice`],
+borrowed from the frame, so that they cannot be used once it has been dropped:
+```compile_fail
+use oneringbuf::SharedStackRB;
+
+let mut buf = SharedStackRB::<u8, 8>::from([0; 8]);
+let (prod, cons) = buf.split();
+let (mut prod, mut cons) = (prod.framed(4), cons.framed(4));
+prod.push(&[1, 2, 3]).unwrap();
+
+let (head, _) = cons.next_frame().unwrap().slices();
+assert_eq!(head, [1, 2, 3]);
+```
+
+When dropped, the consumer is moved past the whole frame.
+"##]
+pub struct Frame<'a, B: IntoRef + OneRB<Item = u8>> {
+    iter: &'a mut ConsIter<B>,
+    index: usize,
+    len: usize,
+    count: usize,
+}
+
+impl<B: IntoRef + OneRB<Item = u8>> Frame<'_, B> {
+    /// Returns the payload.
+    #[inline]
+    pub fn slices(&self) -> SliceOutput<'_, B> {
+        self.iter
+            .buffer()
+            .storage()
+            .next_chunk(self.index, self.len)
+    }
+
+    /// Returns the length of the payload.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the payload is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<B: IntoRef + OneRB<Item = u8>> Drop for Frame<'_, B> {
+    fn drop(&mut self) {
+        unsafe { self.iter.advance(self.count) };
+    }
+}
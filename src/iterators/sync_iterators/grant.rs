@@ -0,0 +1,470 @@
+use core::iter::Chain;
+use core::ops::{Deref, DerefMut, Index, IndexMut};
+use core::slice;
+
+use crate::errors::SliceError;
+use crate::iterators::ConsIter;
+use crate::iterators::ProdIter;
+#[allow(unused_imports)]
+use crate::iterators::WorkIter;
+use crate::iterators::iterator_trait::{ExclusiveIter, ORBIterator, PrivateORBIterator};
+use crate::ring_buffer::OneRB;
+use crate::ring_buffer::storage_components::{PStorageComponent, StorageComponent};
+use crate::ring_buffer::wrappers::refs::IntoRef;
+
+type SliceOutputMut<'a, I> =
+    <<<I as ORBIterator>::Buffer as OneRB>::Storage as StorageComponent>::SliceOutputMut<'a>;
+type SliceOutput<'a, B> = <<B as OneRB>::Storage as StorageComponent>::SliceOutput<'a>;
+
+/// Pointer to the first item of a part of a grant, along with the number of items in it.
+struct RawPart<T> {
+    ptr: *mut T,
+    len: usize,
+}
+
+unsafe impl<T: Send> Send for RawPart<T> {}
+unsafe impl<T: Sync> Sync for RawPart<T> {}
+
+/// Returns the parts made up of the `count` items which start from `index`: the head, running
+/// up to the end of the storage at most, and the tail, wrapping around to its start.
+#[inline]
+fn raw_parts<S: PStorageComponent>(
+    storage: &S,
+    index: usize,
+    count: usize,
+) -> [RawPart<S::Item>; 2] {
+    let head = match storage.next_block(index, count) {
+        Some(_) => count,
+        None => storage.len() - index,
+    };
+
+    // Neither part crosses the end of the storage.
+    unsafe {
+        [
+            RawPart {
+                ptr: storage.next_block(index, head).unwrap_unchecked(),
+                len: head,
+            },
+            RawPart {
+                ptr: storage.next_block(0, count - head).unwrap_unchecked(),
+                len: count - head,
+            },
+        ]
+    }
+}
+
+#[doc = r##"
+Items of a [`Grant`] or of a [`ReadGrant`], reached through [`Deref`] (and [`DerefMut`], for a [`Grant`]).
+
+They are made up of a head, followed by a tail, holding the items which wrap around to the start
+of the buffer, if any. When using `VmemStorage`, the tail is always empty.
+
+Being unsized, they can only be reached through a reference borrowed from the grant, so that they
+cannot be used once it has been dropped:
+```compile_fail
+use oneringbuf::SharedStackRB;
+
+let mut buf = SharedStackRB::<u32, 8>::from([0; 8]);
+let (mut prod, _cons) = buf.split();
+
+let (head, _) = prod.grant(4).unwrap().as_mut_slices();
+head[0] = 1;
+```
+"##]
+#[repr(transparent)]
+pub struct GrantSlices<T>([RawPart<T>]);
+
+impl<T> GrantSlices<T> {
+    #[inline]
+    fn new(parts: &[RawPart<T>; 2]) -> &Self {
+        unsafe { &*(parts.as_slice() as *const [RawPart<T>] as *const Self) }
+    }
+
+    #[inline]
+    fn new_mut(parts: &mut [RawPart<T>; 2]) -> &mut Self {
+        unsafe { &mut *(parts.as_mut_slice() as *mut [RawPart<T>] as *mut Self) }
+    }
+
+    /// Returns the head and the tail.
+    #[inline]
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let [head, tail] = &self.0 else {
+            unreachable!()
+        };
+
+        unsafe {
+            (
+                slice::from_raw_parts(head.ptr, head.len),
+                slice::from_raw_parts(tail.ptr, tail.len),
+            )
+        }
+    }
+
+    /// Returns the head and the tail, as mutable slices.
+    #[inline]
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let [head, tail] = &self.0 else {
+            unreachable!()
+        };
+
+        unsafe {
+            (
+                slice::from_raw_parts_mut(head.ptr, head.len),
+                slice::from_raw_parts_mut(tail.ptr, tail.len),
+            )
+        }
+    }
+
+    /// Returns an iterator over the items.
+    #[inline]
+    pub fn iter(&self) -> Chain<slice::Iter<'_, T>, slice::Iter<'_, T>> {
+        let (head, tail) = self.as_slices();
+
+        head.iter().chain(tail)
+    }
+
+    /// Returns an iterator over mutable references to the items.
+    #[inline]
+    pub fn iter_mut(&mut self) -> Chain<slice::IterMut<'_, T>, slice::IterMut<'_, T>> {
+        let (head, tail) = self.as_mut_slices();
+
+        head.iter_mut().chain(tail)
+    }
+}
+
+impl<T> Index<usize> for GrantSlices<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        let (head, tail) = self.as_slices();
+
+        match index < head.len() {
+            true => &head[index],
+            false => &tail[index - head.len()],
+        }
+    }
+}
+
+impl<T> IndexMut<usize> for GrantSlices<T> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let (head, tail) = self.as_mut_slices();
+
+        match index < head.len() {
+            true => &mut head[index],
+            false => &mut tail[index - head.len()],
+        }
+    }
+}
+
+#[doc = r##"
+Mutable grant over a fixed number of items, returned by [`ProdIter::grant`] and [`WorkIter::work_grant`].
+
+The items are exposed through [`DerefMut`], as [`GrantSlices`], and by [`Self::slices_mut`] as
+the same slices returned by [`ORBIterator::get_mut_slice_exact`], borrowed from the grant,
+so that they cannot be used once it has been dropped:
+```compile_fail
+use oneringbuf::SharedStackRB;
+
+let mut buf = SharedStackRB::<u32, 8>::from([0; 8]);
+let (mut prod, _cons) = buf.split();
+
+let (head, _) = prod.grant(4).unwrap().slices_mut();
+head[0] = 1;
+```
+
+When dropped, the grant advances the iterator exactly once: by all the granted items, by default,
+by fewer of them, if [`Self::commit`] was called, or not at all, if [`Self::abort`] was called.
+"##]
+pub struct Grant<'a, I: ExclusiveIter<Item: 'a>> {
+    iter: &'a mut I,
+    index: usize,
+    len: usize,
+    commit: usize,
+    parts: [RawPart<I::Item>; 2],
+}
+
+impl<'a, I: ExclusiveIter<Item: 'a>> Grant<'a, I> {
+    pub(crate) fn new(iter: &'a mut I, count: usize) -> Result<Self, SliceError> {
+        if !iter.check(count) {
+            return Err(iter.slice_error(count));
+        }
+
+        let index = iter._index();
+
+        Ok(Self {
+            parts: raw_parts(iter.buffer().storage(), index, count),
+            index,
+            iter,
+            len: count,
+            commit: count,
+        })
+    }
+
+    /// Returns the granted items.
+    #[inline]
+    pub fn slices(&self) -> SliceOutput<'_, I::Buffer> {
+        self.iter
+            .buffer()
+            .storage()
+            .next_chunk(self.index, self.len)
+    }
+
+    /// Returns the granted items, as mutable slices.
+    #[inline]
+    pub fn slices_mut(&mut self) -> SliceOutputMut<'_, I> {
+        self.iter
+            .buffer()
+            .storage_mut()
+            .next_chunk_mut(self.index, self.len)
+    }
+
+    /// Returns the number of granted items.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no items were granted.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Releases the grant, advancing the iterator by the first `count` items only.
+    ///
+    /// # Panics
+    /// Panics if `count` is greater than the number of granted items.
+    #[inline]
+    pub fn commit(mut self, count: usize) {
+        // Nothing is published if the assertion fails.
+        self.commit = 0;
+        assert!(count <= self.len, "committing more items than granted");
+        self.commit = count;
+    }
+
+    /// Releases the grant without advancing the iterator.
+    #[inline]
+    pub fn abort(mut self) {
+        self.commit = 0;
+    }
+}
+
+impl<'a, I: ExclusiveIter<Item: 'a>> Deref for Grant<'a, I> {
+    type Target = GrantSlices<I::Item>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        GrantSlices::new(&self.parts)
+    }
+}
+
+impl<'a, I: ExclusiveIter<Item: 'a>> DerefMut for Grant<'a, I> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        GrantSlices::new_mut(&mut self.parts)
+    }
+}
+
+impl<'a, I: ExclusiveIter<Item: 'a>> Drop for Grant<'a, I> {
+    fn drop(&mut self) {
+        if self.commit > 0 {
+            unsafe { self.iter.advance(self.commit) };
+        }
+    }
+}
+
+#[doc = r##"
+Read-only grant over a fixed number of items, returned by [`ConsIter::read_grant`].
+
+The items are exposed through [`Deref`], as [`GrantSlices`], and by [`Self::slices`] as the same slices
+returned by [`ConsIter::peek_slice`], borrowed from the grant, so that they cannot be used once it has been dropped:
+```compile_fail
+use oneringbuf::SharedStackRB;
+
+let mut buf = SharedStackRB::<u32, 8>::from([0; 8]);
+let (mut prod, mut cons) = buf.split();
+prod.push_slice(&[1, 2, 3, 4]).unwrap();
+
+let (head, _) = cons.read_grant(4).unwrap().slices();
+assert_eq!(head[0], 1);
+```
+
+When dropped, the grant advances the consumer exactly once: by all the granted items, by default,
+by fewer of them, if [`Self::commit`] was called, or not at all, if [`Self::abort`] was called.
+"##]
+pub struct ReadGrant<'a, B: IntoRef + OneRB<Item: 'a>> {
+    iter: &'a mut ConsIter<B>,
+    index: usize,
+    len: usize,
+    commit: usize,
+    parts: [RawPart<B::Item>; 2],
+}
+
+impl<'a, B: IntoRef + OneRB<Item: 'a>> ReadGrant<'a, B> {
+    pub(crate) fn new(iter: &'a mut ConsIter<B>, count: usize) -> Result<Self, SliceError> {
+        iter.peek_slice(count)?;
+        let index = iter.index();
+
+        Ok(Self {
+            parts: raw_parts(iter.buffer().storage(), index, count),
+            index,
+            iter,
+            len: count,
+            commit: count,
+        })
+    }
+
+    /// Returns the granted items.
+    #[inline]
+    pub fn slices(&self) -> SliceOutput<'_, B> {
+        self.iter
+            .buffer()
+            .storage()
+            .next_chunk(self.index, self.len)
+    }
+
+    /// Returns the number of granted items.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no items were granted.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Releases the grant, advancing the consumer by the first `count` items only.
+    ///
+    /// # Panics
+    /// Panics if `count` is greater than the number of granted items.
+    #[inline]
+    pub fn commit(mut self, count: usize) {
+        // Nothing is published if the assertion fails.
+        self.commit = 0;
+        assert!(count <= self.len, "committing more items than granted");
+        self.commit = count;
+    }
+
+    /// Releases the grant without advancing the consumer.
+    #[inline]
+    pub fn abort(mut self) {
+        self.commit = 0;
+    }
+}
+
+impl<'a, B: IntoRef + OneRB<Item: 'a>> Deref for ReadGrant<'a, B> {
+    type Target = GrantSlices<B::Item>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        GrantSlices::new(&self.parts)
+    }
+}
+
+impl<'a, B: IntoRef + OneRB<Item: 'a>> Drop for ReadGrant<'a, B> {
+    fn drop(&mut self) {
+        if self.commit > 0 {
+            unsafe { self.iter.advance(self.commit) };
+        }
+    }
+}
+
+#[doc = r##"
+Contiguous reservation over a fixed number of items, returned by [`ProdIter::reserve`].
+
+The items are exposed, through [`DerefMut`], as a single mutable slice. If they would have crossed
+the end of the buffer, the tail of the storage is skipped, and the consumer skips it as well.
+
+When dropped, the reservation advances the producer exactly once: by all the reserved items, by default,
+by fewer of them, if [`Self::commit`] was called, or not at all, if [`Self::abort`] was called.
+The skipped tail is released only along with at least one item.
+"##]
+pub struct Reservation<'a, B: IntoRef + OneRB<Item: 'a>> {
+    iter: &'a mut ProdIter<B>,
+    slice: &'a mut [B::Item],
+    padding: usize,
+    commit: usize,
+}
+
+impl<'a, B: IntoRef + OneRB<Item: 'a>> Reservation<'a, B> {
+    pub(crate) fn new(iter: &'a mut ProdIter<B>, slice: &'a mut [B::Item], padding: usize) -> Self {
+        let commit = slice.len();
+
+        Self {
+            iter,
+            slice,
+            padding,
+            commit,
+        }
+    }
+
+    /// Returns the number of reserved items.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.slice.len()
+    }
+
+    /// Returns `true` if no items were reserved.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.slice.is_empty()
+    }
+
+    /// Returns the number of items skipped at the end of the storage to keep the reservation contiguous.
+    #[inline]
+    pub fn padding(&self) -> usize {
+        self.padding
+    }
+
+    /// Releases the reservation, advancing the producer by the first `count` items only.
+    ///
+    /// # Panics
+    /// Panics if `count` is greater than the number of reserved items.
+    #[inline]
+    pub fn commit(mut self, count: usize) {
+        // Nothing is published if the assertion fails.
+        self.commit = 0;
+        assert!(count <= self.len(), "committing more items than reserved");
+        self.commit = count;
+    }
+
+    /// Releases the reservation without advancing the producer.
+    #[inline]
+    pub fn abort(mut self) {
+        self.commit = 0;
+    }
+}
+
+impl<'a, B: IntoRef + OneRB<Item: 'a>> Deref for Reservation<'a, B> {
+    type Target = [B::Item];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.slice
+    }
+}
+
+impl<'a, B: IntoRef + OneRB<Item: 'a>> DerefMut for Reservation<'a, B> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.slice
+    }
+}
+
+impl<'a, B: IntoRef + OneRB<Item: 'a>> Drop for Reservation<'a, B> {
+    fn drop(&mut self) {
+        if self.commit > 0 {
+            if self.padding > 0 {
+                // Published along with the index of the producer.
+                let buffer = self.iter.buffer();
+                buffer.storage().set_wrap_mark(Some(self.iter._index()));
+            }
+
+            unsafe { self.iter.advance(self.padding + self.commit) };
+        }
+    }
+}
@@ -0,0 +1,237 @@
+use core::iter::FusedIterator;
+use core::ops::Index;
+
+use crate::iterators::iterator_trait::PrivateORBIterator;
+use crate::iterators::{ConsIter, WorkIter};
+use crate::ring_buffer::OneRB;
+use crate::ring_buffer::storage_components::{PStorageComponent, StorageComponent};
+use crate::ring_buffer::wrappers::refs::IntoRef;
+
+#[doc = r##"
+Iterator returned by [`ConsIter::drain_cloned`].
+
+Items are cloned out of the buffer, moving the consumer forward locally as they are yielded.
+The new index is published, making room for the producer, when this iterator is dropped.
+
+The originals are left in their locations, and only dropped once the producer overwrites them.
+Moving them out instead would leave those locations uninitialised, as [`ConsIter::pop_move`] does,
+which cannot be done safely for every buffer.
+"##]
+pub struct DrainCloned<'a, B: IntoRef + OneRB> {
+    iter: &'a mut ConsIter<B>,
+}
+
+impl<'a, B: IntoRef + OneRB> DrainCloned<'a, B> {
+    pub(crate) fn new(iter: &'a mut ConsIter<B>) -> Self {
+        Self { iter }
+    }
+}
+
+impl<B: IntoRef + OneRB<Item: Clone>> Iterator for DrainCloned<'_, B> {
+    type Item = B::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let ret = self.iter.next_ref()?.clone();
+
+        unsafe { self.iter.advance_local(1) };
+
+        Some(ret)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.iter.cached_avail(), None)
+    }
+}
+
+impl<B: IntoRef + OneRB> Drop for DrainCloned<'_, B> {
+    fn drop(&mut self) {
+        self.iter.publish_index();
+    }
+}
+
+#[doc = r##"
+Iterator returned by [`ConsIter::iter`].
+
+Walks the items available when it was created, across the end of the buffer,
+without moving the consumer.
+"##]
+pub struct Iter<'a, B: OneRB> {
+    buffer: &'a B,
+    index: usize,
+    remaining: usize,
+}
+
+impl<'a, B: OneRB> Iter<'a, B> {
+    pub(crate) fn new(buffer: &'a B, index: usize, remaining: usize) -> Self {
+        Self {
+            buffer,
+            index,
+            remaining,
+        }
+    }
+}
+
+impl<'a, B: OneRB> Iterator for Iter<'a, B> {
+    type Item = &'a B::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let ret = unsafe { self.buffer.storage()._index(self.index).inner_ref() };
+
+        self.index += 1;
+        if self.index == self.buffer.len() {
+            self.index = 0;
+        }
+        self.remaining -= 1;
+
+        Some(ret)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<B: OneRB> ExactSizeIterator for Iter<'_, B> {}
+impl<B: OneRB> FusedIterator for Iter<'_, B> {}
+
+#[doc = r##"
+View returned by [`ConsIter::view`].
+
+Gives random access to the items available when it was created, with index `0` being the head,
+across the end of the buffer. The consumer cannot move while the view is alive.
+"##]
+pub struct View<'a, B: OneRB> {
+    buffer: &'a B,
+    index: usize,
+    len: usize,
+}
+
+impl<'a, B: OneRB> View<'a, B> {
+    pub(crate) fn new(buffer: &'a B, index: usize, len: usize) -> Self {
+        Self { buffer, index, len }
+    }
+
+    /// Returns the number of items within the view.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the view holds no items.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns a reference to the item at `offset` from the head, or `None` if out of bounds.
+    #[inline]
+    pub fn get(&self, offset: usize) -> Option<&'a B::Item> {
+        if offset >= self.len {
+            return None;
+        }
+
+        let mut idx = self.index + offset;
+        if idx >= self.buffer.len() {
+            idx -= self.buffer.len();
+        }
+
+        Some(unsafe { self.buffer.storage()._index(idx).inner_ref() })
+    }
+
+    /// Returns the items within the view as slices: a single one, when using `VmemStorage`,
+    /// a tuple of two, split at the end of the buffer, otherwise.
+    #[inline]
+    pub fn as_slices(&self) -> <B::Storage as StorageComponent>::SliceOutput<'a> {
+        self.buffer.storage().next_chunk(self.index, self.len)
+    }
+
+    /// Returns an iterator over the items within the view.
+    #[inline]
+    pub fn iter(&self) -> Iter<'a, B> {
+        Iter::new(self.buffer, self.index, self.len)
+    }
+}
+
+impl<B: OneRB> Index<usize> for View<'_, B> {
+    type Output = B::Item;
+
+    /// # Panics
+    /// Panics if `offset` is not lower than [`View::len`].
+    #[inline]
+    fn index(&self, offset: usize) -> &Self::Output {
+        match self.get(offset) {
+            Some(item) => item,
+            None => panic!(
+                "offset {offset} out of bounds for a view of {} items",
+                self.len
+            ),
+        }
+    }
+}
+
+impl<'a, B: OneRB> IntoIterator for &View<'a, B> {
+    type Item = &'a B::Item;
+    type IntoIter = Iter<'a, B>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[doc = r##"
+Iterator returned by [`WorkIter::iter_mut`].
+
+Yields mutable references to the available items, moving the worker forward locally as they are yielded.
+
+Unlike [`DrainCloned`], this iterator does not publish the index of the worker when dropped, since yielded
+references may outlive it, and the items they point to must not be released to the next iterator
+while they can still be mutated. The index is published by the next method of the worker doing so,
+e.g. [`WorkIter::sync_index`], which cannot be called as long as any yielded reference is alive:
+```compile_fail
+use oneringbuf::SharedStackRBMut;
+
+let mut buf = SharedStackRBMut::<u32, 8>::from([0; 8]);
+let (mut prod, mut work, _cons) = buf.split_mut();
+prod.push(1).unwrap();
+
+let x = work.iter_mut().next().unwrap();
+work.sync_index();
+*x = 2;
+```
+"##]
+pub struct IterMut<'a, B: IntoRef + OneRB> {
+    iter: &'a mut WorkIter<B>,
+}
+
+impl<'a, B: IntoRef + OneRB> IterMut<'a, B> {
+    pub(crate) fn new(iter: &'a mut WorkIter<B>) -> Self {
+        Self { iter }
+    }
+}
+
+impl<'a, B: IntoRef + OneRB> Iterator for IterMut<'a, B> {
+    type Item = &'a mut B::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let ret = self.iter.next_ref_mut()?;
+
+        unsafe { self.iter.advance_local(1) };
+
+        Some(ret)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.iter.cached_avail(), None)
+    }
+}
@@ -0,0 +1,198 @@
+use crate::iterators::copy_from_slice_unchecked;
+use crate::iterators::sync_iterators::observer::Observer;
+use crate::iterators::sync_iterators::wake_peers;
+#[allow(unused_imports)]
+use crate::iterators::{ConsIter, ProdIter};
+use crate::iters_components::OverwriteComp;
+use crate::ring_buffer::iters_components::PIterComponent;
+use crate::ring_buffer::storage_components::PStorageComponent;
+use crate::ring_buffer::wrappers::refs::IntoRef;
+use crate::ring_buffer::{OneRB, SharedRB};
+
+/// Returned by [`LossyConsIter`] when the producer has overwritten items which were not read yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overrun {
+    /// Number of items lost since the last read.
+    pub lost: usize,
+}
+
+/// Releases the lock on the consumer index when dropped, storing `index` into it.
+struct Unlock<'a> {
+    iters: &'a OverwriteComp,
+    index: usize,
+}
+
+impl Drop for Unlock<'_> {
+    fn drop(&mut self) {
+        self.iters.unlock_cons(self.index);
+    }
+}
+
+#[doc = r##"
+Iterator used to pop data from an overwriting buffer.
+
+Since the producer may overwrite items at any time (see [`ProdIter::push_overwrite`]), this iterator
+never hands out references to the data within the buffer: items are always copied or cloned out.
+
+Before returning any other item, every method reports whether the consumer was lapped with
+an [`Overrun`], holding the number of items lost. Subsequent calls carry on from the oldest
+item which has not been overwritten.
+"##]
+pub struct LossyConsIter<B: IntoRef + OneRB<Iters = OverwriteComp>> {
+    buffer: B::TargetRef,
+}
+
+unsafe impl<B: IntoRef + OneRB<Iters = OverwriteComp> + SharedRB> Send for LossyConsIter<B> {}
+
+impl<B: IntoRef + OneRB<Iters = OverwriteComp>> Drop for LossyConsIter<B> {
+    fn drop(&mut self) {
+        self.buffer.liveness().drop_reader(0);
+        wake_peers(&*self.buffer);
+    }
+}
+
+impl<B: IntoRef + OneRB<Item = T, Iters = OverwriteComp>, T> LossyConsIter<B> {
+    pub(crate) fn new(value: B::TargetRef) -> Self {
+        Self { buffer: value }
+    }
+
+    /// Returns a new [`Observer`] of the buffer, which reports its fill level without moving any iterator.
+    #[inline]
+    pub fn observer(&self) -> Observer<B> {
+        Observer::new(self.buffer.clone())
+    }
+
+    /// Locks the consumer index and, if at least `count` items are available,
+    /// calls `f` with the current index, then moves the iterator forward.
+    #[inline]
+    fn _read<R>(
+        &mut self,
+        count: usize,
+        f: impl FnOnce(&B, usize) -> R,
+    ) -> Result<Option<R>, Overrun> {
+        let iters = self.buffer.iters();
+        let len = self.buffer.len();
+
+        let cons = iters.lock_cons();
+
+        let lost = iters.take_lost();
+        if lost > 0 {
+            iters.unlock_cons(cons);
+            return Err(Overrun { lost });
+        }
+
+        let prod = iters.prod_index();
+        let avail = match cons <= prod {
+            true => prod - cons,
+            false => len - cons + prod,
+        };
+
+        if avail < count {
+            iters.unlock_cons(cons);
+            return Ok(None);
+        }
+
+        // If `f` panics, the lock is released without moving the consumer.
+        let mut unlock = Unlock { iters, index: cons };
+        let ret = f(&*self.buffer, cons);
+
+        let next = cons + count;
+        unlock.index = if next >= len { next - len } else { next };
+        drop(unlock);
+        self.buffer.waiter().notify();
+
+        Ok(Some(ret))
+    }
+
+    /// Returns the number of items available for reading.
+    #[inline]
+    pub fn available(&self) -> usize {
+        let iters = self.buffer.iters();
+        let (cons, prod) = (iters.cons_index(), iters.prod_index());
+
+        match cons <= prod {
+            true => prod - cons,
+            false => self.buffer.len() - cons + prod,
+        }
+    }
+
+    /// Returns the length of the buffer.
+    #[inline]
+    pub fn buf_len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Returns `true` if the producer has been dropped and all the items it pushed have been read.
+    #[inline]
+    pub fn is_disconnected(&self) -> bool {
+        !self.buffer.liveness().prod_alive() && self.available() == 0
+    }
+
+    /// Returns the number of iterators still alive.
+    #[inline]
+    pub fn alive_iters(&self) -> u8 {
+        self.buffer.iters().alive_iters()
+    }
+
+    /// Tries to pop an element, copying it.
+    ///
+    /// Returns:
+    /// * `Err(Overrun)`, if items were overwritten since the last call;
+    /// * `Ok(None)`, if the buffer is empty;
+    /// * `Ok(Some(item))`, otherwise.
+    #[inline]
+    pub fn pop(&mut self) -> Result<Option<T>, Overrun>
+    where
+        T: Copy,
+    {
+        self._read(1, |buf, idx| unsafe {
+            buf.storage()._index(idx).inner_duplicate()
+        })
+    }
+
+    /// Same as [`Self::pop`], but uses `clone`, instead.
+    #[inline]
+    pub fn pop_clone(&mut self) -> Result<Option<T>, Overrun>
+    where
+        T: Clone,
+    {
+        self._read(1, |buf, idx| unsafe {
+            buf.storage()._index(idx).inner_ref().clone()
+        })
+    }
+
+    /// Fills `dst` slice with the next `dst.len()` values, copying them.
+    ///
+    /// Returns:
+    /// * `Err(Overrun)`, if items were overwritten since the last call;
+    /// * `Ok(None)`, doing nothing, if fewer than `dst.len()` items are available;
+    /// * `Ok(Some(()))`, otherwise.
+    #[inline]
+    pub fn copy_slice(&mut self, dst: &mut [T]) -> Result<Option<()>, Overrun>
+    where
+        T: Copy,
+    {
+        fn f<T: Copy>(binding: &[T], dst: &mut [T]) {
+            copy_from_slice_unchecked(binding, dst);
+        }
+
+        self._read(dst.len(), |buf, idx| {
+            buf.storage_mut()._extract_slice(idx, dst, f)
+        })
+    }
+
+    /// Same as [`Self::copy_slice`], but uses `clone`, instead.
+    #[inline]
+    pub fn clone_slice(&mut self, dst: &mut [T]) -> Result<Option<()>, Overrun>
+    where
+        T: Clone,
+    {
+        fn f<T: Clone>(binding: &[T], dst: &mut [T]) {
+            dst.clone_from_slice(binding);
+        }
+
+        self._read(dst.len(), |buf, idx| {
+            buf.storage_mut()._extract_slice(idx, dst, f)
+        })
+    }
+}
@@ -1,10 +1,21 @@
 //! Sync iterators.
 
-use crate::ring_buffer::{OneRB, SharedRB, wrappers::refs::IntoRef};
+use crate::ring_buffer::{
+    OneRB, SharedRB, iters_components::PIterComponent, wrappers::refs::IntoRef,
+};
 
 pub(crate) mod cons_iter;
 pub(crate) mod detached;
+pub(crate) mod framed;
+pub(crate) mod grant;
+pub(crate) mod item_iters;
+pub(crate) mod lossy_cons_iter;
+pub(crate) mod mpsc_prod_iter;
+pub(crate) mod observer;
+pub(crate) mod owned_iters;
 pub(crate) mod prod_iter;
+pub(crate) mod uninit_prod_iter;
+pub(crate) mod window_iter;
 pub(crate) mod work_iter;
 
 pub(crate) struct Iter<B: IntoRef + OneRB> {
@@ -16,11 +27,18 @@ pub(crate) struct Iter<B: IntoRef + OneRB> {
 unsafe impl<B: IntoRef + OneRB + SharedRB> Send for Iter<B> {}
 
 impl<B: IntoRef + OneRB> Iter<B> {
-    pub(crate) fn new(value: B::TargetRef) -> Self {
+    pub(crate) fn new(value: B::TargetRef, index: usize) -> Self {
         Self {
-            index: 0,
+            index,
             buffer: value,
             cached_avail: 0,
         }
     }
 }
+
+/// Wakes up the peers of a dropped iterator, so that they can notice it.
+#[inline]
+pub(crate) fn wake_peers<B: OneRB>(buffer: &B) {
+    buffer.waiter().notify();
+    buffer.iters().wake_all();
+}
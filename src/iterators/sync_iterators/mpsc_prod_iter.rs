@@ -0,0 +1,206 @@
+#[cfg(doc)]
+use crate::iterators::ProdIter;
+
+use crate::errors::{PushError, SliceError};
+use crate::iterators::copy_from_slice_unchecked;
+use crate::iterators::sync_iterators::observer::Observer;
+use crate::iterators::sync_iterators::wake_peers;
+use crate::iters_components::MpscComp;
+use crate::ring_buffer::iters_components::PIterComponent;
+use crate::ring_buffer::storage_components::PStorageComponent;
+use crate::ring_buffer::wrappers::refs::IntoRef;
+use crate::ring_buffer::{OneRB, SharedRB};
+
+#[doc = r##"
+Iterator used to push data into a multi-producer buffer.
+
+Unlike [`ProdIter`], this iterator can be cloned, and every clone can push data concurrently.
+Slots are claimed in a lock-free way and then published in the same order they were claimed,
+so a producer may briefly spin while waiting for a slower producer, which claimed slots before it,
+to publish them.
+
+<div class="warning">
+
+If that producer gets preempted between claiming and publishing its slots, every producer which
+claimed slots after it waits until it is resumed. With `std` feature, waiting producers yield their
+thread after a while; without it they busy-wait, so producers must never preempt each other without
+letting the preempted one resume (e.g. one pushing from an interrupt handler, the other from the
+interrupted code), or they deadlock.
+
+</div>
+"##]
+pub struct MpscProdIter<B: IntoRef + OneRB<Iters = MpscComp>> {
+    buffer: B::TargetRef,
+    /// Period of the counters held by [`MpscComp`]: the greatest multiple of the buffer length.
+    wrap: usize,
+}
+
+unsafe impl<B: IntoRef + OneRB<Iters = MpscComp> + SharedRB> Send for MpscProdIter<B> {}
+
+impl<B: IntoRef + OneRB<Iters = MpscComp>> Drop for MpscProdIter<B> {
+    fn drop(&mut self) {
+        self.buffer.liveness().drop_prod();
+        wake_peers(&*self.buffer);
+    }
+}
+
+/// Publishes the slots claimed by a producer when dropped, even if writing them panicked,
+/// so that the producers which claimed slots after them are not blocked forever.
+struct Commit<'a, B: OneRB<Iters = MpscComp>> {
+    buffer: &'a B,
+    start: usize,
+    count: usize,
+    wrap: usize,
+}
+
+impl<B: OneRB<Iters = MpscComp>> Drop for Commit<'_, B> {
+    fn drop(&mut self) {
+        let len = self.buffer.len();
+
+        self.buffer
+            .iters()
+            .commit(self.start, self.count, len, self.wrap);
+        self.buffer.waiter().notify();
+    }
+}
+
+impl<B: IntoRef + OneRB<Iters = MpscComp>> Clone for MpscProdIter<B> {
+    fn clone(&self) -> Self {
+        self.buffer.iters().add_iter();
+        self.buffer.liveness().add_prod();
+
+        Self {
+            buffer: self.buffer.clone(),
+            wrap: self.wrap,
+        }
+    }
+}
+
+impl<B: IntoRef + OneRB<Item = T, Iters = MpscComp>, T> MpscProdIter<B> {
+    pub(crate) fn new(value: B::TargetRef) -> Self {
+        let len = value.len();
+
+        Self {
+            buffer: value,
+            wrap: usize::MAX / len * len,
+        }
+    }
+
+    /// Returns a new [`Observer`] of the buffer, which reports its fill level without moving any iterator.
+    #[inline]
+    pub fn observer(&self) -> Observer<B> {
+        Observer::new(self.buffer.clone())
+    }
+
+    #[inline]
+    fn _push_with(&mut self, count: usize, f: impl FnOnce(&B, usize)) -> Result<(), SliceError> {
+        let len = self.buffer.len();
+
+        match self.buffer.iters().reserve(count, len, self.wrap) {
+            Ok(start) => {
+                let commit = Commit {
+                    buffer: &*self.buffer,
+                    start,
+                    count,
+                    wrap: self.wrap,
+                };
+
+                f(commit.buffer, start % len);
+                drop(commit);
+                Ok(())
+            }
+            Err(_) if self.is_disconnected() => Err(SliceError::Disconnected),
+            Err(available) => Err(SliceError::Insufficient {
+                requested: count,
+                available,
+            }),
+        }
+    }
+
+    /// Tries to push a new item by moving or copying it.
+    ///
+    /// The same considerations made for [`ProdIter::push`] about uninitialised memory apply here.
+    ///
+    /// Returns:
+    /// * `Err(PushError::Full(value))`, if the buffer is full;
+    /// * `Err(PushError::Disconnected(value))`, if the buffer is full and the consumer has been dropped;
+    /// * `Ok(())`, otherwise.
+    #[inline]
+    pub fn push(&mut self, value: T) -> Result<(), PushError<T>> {
+        let mut value = Some(value);
+
+        match self._push_with(1, |buf, idx| unsafe {
+            *buf.storage()._index(idx).as_mut_ptr() = value.take().unwrap();
+        }) {
+            Ok(()) => Ok(()),
+            Err(SliceError::Disconnected) => Err(PushError::Disconnected(value.unwrap())),
+            Err(SliceError::Insufficient { .. }) => Err(PushError::Full(value.unwrap())),
+        }
+    }
+
+    /// Tries to push a slice of items by copying the elements.
+    /// The elements must implement [`Copy`] trait.
+    ///
+    /// The slice is published atomically: the consumer sees either none or all of it,
+    /// contiguous and never interleaved with items pushed by other producers.
+    ///
+    /// Returns:
+    /// * `Err(SliceError::Insufficient)`, doing nothing, if there is not enough room for `slice`;
+    /// * `Err(SliceError::Disconnected)`, doing nothing, if there is not enough room for `slice` and
+    ///   the consumer has been dropped;
+    /// * `Ok(())`, otherwise.
+    #[inline]
+    pub fn push_slice(&mut self, slice: &[T]) -> Result<(), SliceError>
+    where
+        T: Copy,
+    {
+        #[inline]
+        fn f<T: Copy>(binding: &mut [T], slice: &[T]) {
+            copy_from_slice_unchecked(slice, binding);
+        }
+
+        self._push_with(slice.len(), |buf, idx| {
+            buf.storage_mut()._push_slice(idx, slice, f)
+        })
+    }
+
+    /// Same as [`Self::push_slice`], but the elements are cloned.
+    /// The elements must implement [`Clone`] trait.
+    ///
+    /// If cloning an element panics, the claimed slots are published anyway, so that other
+    /// producers are not blocked: each of them holds either its clone or the item it held before.
+    ///
+    /// Returns the same as [`Self::push_slice`].
+    #[inline]
+    pub fn push_slice_clone(&mut self, slice: &[T]) -> Result<(), SliceError>
+    where
+        T: Clone,
+    {
+        #[inline]
+        fn f<T: Clone>(binding: &mut [T], slice: &[T]) {
+            binding.clone_from_slice(slice);
+        }
+
+        self._push_with(slice.len(), |buf, idx| {
+            buf.storage_mut()._push_slice(idx, slice, f)
+        })
+    }
+
+    /// Returns the number of iterators (producers and consumer) still alive.
+    #[inline]
+    pub fn alive_iters(&self) -> u8 {
+        self.buffer.iters().alive_iters()
+    }
+
+    /// Returns the length of the buffer.
+    #[inline]
+    pub fn buf_len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Returns `true` if the consumer has been dropped.
+    #[inline]
+    pub fn is_disconnected(&self) -> bool {
+        self.buffer.liveness().downstream_ended(self.buffer.iters())
+    }
+}
@@ -0,0 +1,179 @@
+#[cfg(doc)]
+use crate::iterators::{ConsIter, ProdIter, WorkIter};
+
+use crate::ring_buffer::iters_components::PIterComponent;
+#[cfg(feature = "stats")]
+use crate::ring_buffer::stats::Stats;
+use crate::ring_buffer::wrappers::refs::IntoRef;
+use crate::ring_buffer::{OneRB, SharedRB};
+
+#[doc = r##"
+Read-only handle used to monitor a buffer, obtained from any of its iterators (e.g. [`ProdIter::observer`]).
+
+An observer never moves any iterator: it only reports the fill level of the buffer, the backlog of
+each stage and which iterators are still alive. Unlike iterators, it can be cloned, and it can be
+sent to other threads along with iterators of concurrent buffers.
+
+Every observer keeps the buffer alive, just like an iterator, so it is counted by
+[`ORBIterator::alive_iters`](crate::ORBIterator::alive_iters). Values are read one at a time,
+so they may be stale as soon as they are returned.
+"##]
+pub struct Observer<B: IntoRef + OneRB> {
+    buffer: B::TargetRef,
+}
+
+unsafe impl<B: IntoRef + OneRB + SharedRB> Send for Observer<B> {}
+unsafe impl<B: IntoRef + OneRB + SharedRB> Sync for Observer<B> {}
+
+impl<B: IntoRef + OneRB> Clone for Observer<B> {
+    fn clone(&self) -> Self {
+        Self::new(self.buffer.clone())
+    }
+}
+
+impl<B: IntoRef + OneRB> Observer<B> {
+    /// Creates a new observer upon a clone of the reference held by an iterator.
+    pub(crate) fn new(buffer: B::TargetRef) -> Self {
+        buffer.iters().add_iter();
+
+        Self { buffer }
+    }
+
+    /// Returns the number of items from `from` to `to`, moving forward.
+    #[inline]
+    fn distance(&self, from: usize, to: usize) -> usize {
+        match from <= to {
+            true => to - from,
+            false => self.buffer.len() - from + to,
+        }
+    }
+
+    /// Returns the length of the buffer.
+    #[inline]
+    pub fn buf_len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Returns the number of items between the producer and the consumer.
+    /// For broadcast buffers, the slowest consumer still alive is taken into account.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.distance(self.cons_index(), self.prod_index())
+    }
+
+    /// Returns the number of items that can be pushed before the buffer becomes full.
+    /// Items kept for [`ConsIter::history`] are not counted as free.
+    #[inline]
+    pub fn free(&self) -> usize {
+        (self.buf_len() - 1 - self.len()).saturating_sub(self.buffer.lookback())
+    }
+
+    /// Returns `true` if the buffer holds no items.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if no more items can be pushed.
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.free() == 0
+    }
+
+    /// Returns the number of worker stages.
+    #[inline]
+    pub fn stages(&self) -> usize {
+        self.buffer.iters().stages()
+    }
+
+    /// Returns the number of items released to the worker at `stage`, but not processed yet.
+    ///
+    /// # Panics
+    /// Panics if `stage` is not lower than [`Self::stages`].
+    pub fn stage_backlog(&self, stage: usize) -> usize {
+        assert!(stage < self.stages(), "stage out of bounds");
+
+        let iters = self.buffer.iters();
+        let prev = match stage {
+            0 => iters.prod_index(),
+            _ => iters.stage_index(stage - 1),
+        };
+
+        self.distance(iters.stage_index(stage), prev)
+    }
+
+    /// Returns the number of items released to the consumer, but not consumed yet.
+    /// For broadcast buffers, the slowest consumer still alive is taken into account.
+    pub fn cons_backlog(&self) -> usize {
+        let iters = self.buffer.iters();
+        let prev = match self.stages() {
+            0 => iters.prod_index(),
+            k => iters.stage_index(k - 1),
+        };
+
+        self.distance(self.cons_index(), prev)
+    }
+
+    /// Returns the index of the producer.
+    #[inline]
+    pub fn prod_index(&self) -> usize {
+        self.buffer.iters().prod_index()
+    }
+
+    /// Returns the index of the worker at `stage`, or `None` if the buffer has no such stage.
+    #[inline]
+    pub fn stage_index(&self, stage: usize) -> Option<usize> {
+        let iters = self.buffer.iters();
+
+        (stage < iters.stages()).then(|| iters.stage_index(stage))
+    }
+
+    /// Returns the index of the consumer.
+    /// For broadcast buffers, this is the index of the slowest consumer still alive.
+    #[inline]
+    pub fn cons_index(&self) -> usize {
+        self.buffer.liveness().cons_index(self.buffer.iters())
+    }
+
+    /// Returns the index of the consumer `reader`, or `None` if the buffer has no such reader.
+    #[inline]
+    pub fn reader_index(&self, reader: usize) -> Option<usize> {
+        let iters = self.buffer.iters();
+
+        (reader < iters.readers()).then(|| iters.reader_index(reader))
+    }
+
+    /// Returns the runtime statistics of the buffer, if it records any.
+    #[cfg(feature = "stats")]
+    #[inline]
+    pub fn stats(&self) -> Option<&Stats> {
+        self.buffer.iters().stats()
+    }
+
+    /// Returns how many iterators and observers are still alive.
+    #[inline]
+    pub fn alive_iters(&self) -> u8 {
+        self.buffer.iters().alive_iters()
+    }
+
+    /// Returns `true` if the producer is alive.
+    /// For multi-producer buffers, returns `true` if at least one producer is alive.
+    #[inline]
+    pub fn prod_alive(&self) -> bool {
+        self.buffer.liveness().prod_alive()
+    }
+
+    /// Returns `true` if the worker at `stage` is alive.
+    /// Returns `false` if the buffer has no such stage.
+    #[inline]
+    pub fn stage_alive(&self, stage: usize) -> bool {
+        stage < self.stages() && self.buffer.liveness().stage_alive(stage)
+    }
+
+    /// Returns `true` if the consumer `reader` is alive.
+    /// Returns `false` if the buffer has no such reader.
+    #[inline]
+    pub fn reader_alive(&self, reader: usize) -> bool {
+        reader < self.buffer.iters().readers() && self.buffer.liveness().reader_alive(reader)
+    }
+}
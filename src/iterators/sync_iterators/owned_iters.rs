@@ -0,0 +1,150 @@
+use crate::errors::{PopError, PushError, SliceError};
+use crate::iterators::iterator_trait::{ORBIterator, PrivateORBIterator};
+use crate::iterators::sync_iterators::observer::Observer;
+use crate::iterators::util_macros::delegate;
+use crate::iterators::util_macros::muncher;
+use crate::iterators::{ConsIter, ProdIter};
+use crate::iters_components::OwnedComp;
+use crate::ring_buffer::OneRB;
+use crate::ring_buffer::storage_components::StorageComponent;
+use crate::ring_buffer::wrappers::refs::IntoRef;
+#[cfg(doc)]
+use crate::{OwnedHeapRB, WaitStrategy};
+
+#[doc = r##"
+Iterator used to move items into an owned buffer (e.g. [`OwnedHeapRB`]).
+
+Every location the producer writes to is uninitialised, so items are always moved in,
+without reading or dropping anything. There is no need for `*_init` methods.
+"##]
+#[repr(transparent)]
+pub struct OwnedProdIter<B: IntoRef + OneRB<Iters = OwnedComp>> {
+    inner: ProdIter<B>,
+}
+
+impl<B: IntoRef + OneRB<Item = T, Iters = OwnedComp>, T> OwnedProdIter<B> {
+    pub(crate) fn new(value: B::TargetRef) -> Self {
+        Self {
+            inner: ProdIter::new(value),
+        }
+    }
+
+    #[inline]
+    fn inner(&self) -> &ProdIter<B> {
+        &self.inner
+    }
+    #[inline]
+    fn inner_mut(&mut self) -> &mut ProdIter<B> {
+        &mut self.inner
+    }
+
+    /// Tries to push a new item by moving it.
+    ///
+    /// Returns:
+    /// * `Err(PushError::Full(value))`, if the buffer is full;
+    /// * `Err(PushError::Disconnected(value))`, if the buffer is full and the consumer has been dropped;
+    /// * `Ok(())`, otherwise.
+    #[inline]
+    pub fn push(&mut self, value: T) -> Result<(), PushError<T>> {
+        fn f<T>(binding: *mut T, value: T) {
+            unsafe { binding.write(value) };
+        }
+
+        self.inner._push(value, f)
+    }
+
+    /// Same as [`Self::push`], but waits until there is room for the item,
+    /// according to the [`WaitStrategy`] of the buffer.
+    ///
+    /// Returns:
+    /// * `Err(PushError::Disconnected(value))`, if the consumer got dropped while waiting;
+    /// * `Ok(())`, otherwise.
+    #[inline]
+    pub fn push_blocking(&mut self, value: T) -> Result<(), PushError<T>> {
+        self.inner.wait_for(1);
+
+        self.push(value)
+    }
+
+    delegate!(ORBIterator (inline), pub fn available(&(mut) self) -> usize);
+    delegate!(ORBIterator (inline), pub fn buf_len(&self) -> usize);
+    delegate!(ORBIterator (inline), pub fn alive_iters(&self) -> u8);
+    delegate!(ORBIterator (inline), pub fn is_disconnected(&self) -> bool);
+    delegate!(ProdIter (inline), pub fn observer(&self) -> Observer<B>);
+}
+
+#[doc = r##"
+Iterator used to move items out of an owned buffer (e.g. [`OwnedHeapRB`]).
+
+Every item is moved out when popped, leaving its location uninitialised for the producer.
+Items which are still in the buffer when it is dropped are dropped along with it.
+"##]
+#[repr(transparent)]
+pub struct OwnedConsIter<B: IntoRef + OneRB<Iters = OwnedComp>> {
+    inner: ConsIter<B>,
+}
+
+impl<B: IntoRef + OneRB<Item = T, Iters = OwnedComp>, T> OwnedConsIter<B> {
+    pub(crate) fn new(value: B::TargetRef) -> Self {
+        Self {
+            inner: ConsIter::new(value),
+        }
+    }
+
+    #[inline]
+    fn inner(&self) -> &ConsIter<B> {
+        &self.inner
+    }
+    #[inline]
+    fn inner_mut(&mut self) -> &mut ConsIter<B> {
+        &mut self.inner
+    }
+
+    /// Tries to pop an element, moving it.
+    ///
+    /// Returns:
+    /// * `Err(PopError::Empty)`, if the buffer is empty;
+    /// * `Err(PopError::Disconnected)`, if the buffer is empty and the producer has been dropped;
+    /// * `Ok(item)`, otherwise.
+    #[inline]
+    pub fn pop(&mut self) -> Result<T, PopError> {
+        // The location is considered uninitialised from now on, so the bitwise copy left behind
+        // is never read or dropped again.
+        unsafe { self.inner.pop_unsafe() }
+    }
+
+    /// Same as [`Self::pop`], but waits until an element is available,
+    /// according to the [`WaitStrategy`] of the buffer.
+    ///
+    /// Returns `Err(PopError::Disconnected)` only if the producer got dropped.
+    #[inline]
+    pub fn pop_blocking(&mut self) -> Result<T, PopError> {
+        self.inner.wait_for(1);
+
+        self.pop()
+    }
+
+    /// Returns a reference to the next element, if any, without moving it out.
+    #[inline]
+    pub fn peek_ref(&mut self) -> Option<&T> {
+        self.inner.next_ref()
+    }
+
+    /// Returns references to the newest `count` elements, without moving them out.
+    ///
+    /// Returns the same as [`ConsIter::peek_latest`].
+    #[inline]
+    pub fn peek_latest(
+        &mut self,
+        count: usize,
+    ) -> Result<<B::Storage as StorageComponent>::SliceOutput<'_>, SliceError> {
+        self.inner.peek_latest(count)
+    }
+
+    delegate!(ORBIterator (inline), pub fn available(&(mut) self) -> usize);
+    delegate!(ORBIterator (inline), pub fn buf_len(&self) -> usize);
+    delegate!(ORBIterator (inline), pub fn alive_iters(&self) -> u8);
+    delegate!(ORBIterator (inline), pub fn is_disconnected(&self) -> bool);
+    delegate!(ConsIter, pub fn skip_to_latest(&(mut) self, keep: usize) -> usize);
+    delegate!(ConsIter (inline), pub fn observer(&self) -> Observer<B>);
+}
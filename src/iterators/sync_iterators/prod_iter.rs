@@ -1,19 +1,38 @@
 #[cfg(doc)]
-use {crate::iterators::ConsIter, crate::iterators::Detached, core::mem::MaybeUninit};
+use {
+    crate::UninitRB, crate::WaitStrategy, crate::iterators::ConsIter, crate::iterators::Detached,
+    crate::iterators::LossyConsIter, crate::iterators::OwnedProdIter,
+    crate::iterators::UninitProdIter, core::mem::MaybeUninit,
+};
+
+use core::mem::ManuallyDrop;
+use core::ptr;
 
-use crate::iterators::iterator_trait::{ORBIterator, PrivateORBIterator};
+use crate::OneRingBuf;
+use crate::errors::{PopError, PushError, SliceError};
+use crate::iterators::iterator_trait::{ExclusiveIter, ORBIterator, PrivateORBIterator};
 use crate::iterators::sync_iterators::Iter;
+use crate::iterators::sync_iterators::framed::FramedProd;
+use crate::iterators::sync_iterators::grant::{Grant, Reservation};
+use crate::iterators::sync_iterators::observer::Observer;
+use crate::iterators::sync_iterators::wake_peers;
 use crate::iterators::{copy_from_slice_unchecked, private_impl};
+use crate::iters_components::{NonMutIterComp, OverwriteComp};
 use crate::ring_buffer::iters_components::PIterComponent;
 use crate::ring_buffer::storage_components::{PStorageComponent, StorageComponent};
 use crate::ring_buffer::wrappers::refs::IntoRef;
 use crate::ring_buffer::wrappers::unsafe_sync_cell::UnsafeSyncCell;
 use crate::ring_buffer::{OneRB, SharedRB};
+#[cfg(feature = "alloc")]
+use crate::storage_components::HeapStorage;
+use crate::storage_components::StackStorage;
 #[cfg(feature = "async")]
 use crate::{
     iterators::{AsyncProdIter, async_iterators::AsyncIterator},
     iters_components::async_iters::AsyncIterComp,
 };
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 
 #[doc = r##"
 Iterator used to push data into the buffer.
@@ -24,14 +43,16 @@ When working with types which implement both
 preferred over `clone` methods.
 
 # TL;DR about uninitialised memory
-If you created the buffer with either `default` or `from` methods and you are *not* going to use [`ConsIter::pop`],
+If you are *not* going to move items out of the buffer (e.g. using [`ConsIter::pop_move`]),
 then it is safe to use normal methods from this struct.
 
-If you either created the buffer with `new_zeroed` or are going to move items out of the buffer (e.g. using [`ConsIter::pop`]),
-then you must pay attention to what you do and ensure that all the locations cleared by `pop` will be re-filled with `*_init` methods.
+If you are going to move items out of the buffer, then you must pay attention to what you do and ensure that all the locations cleared by `pop` will be re-filled with `*_init` methods.
 After that you can use normal methods again.
 Read below to know why and how.
 
+If items have to be moved in and out of the buffer all the time, an owned buffer (see [`OwnedProdIter`])
+should be preferred, as it keeps track of initialised locations by itself.
+
 It would be a good idea to do a check with [miri](https://github.com/rust-lang/miri), which is able to
 tell if and when something bad has happened.
 
@@ -43,7 +64,11 @@ When one creates a buffer from this crate, they may choose to build it either wi
 or with `new_zeroed` ones.
 
 When using the former, an initialised buffer is created, so there are no problems concerning uninitialised memory.
-With the latter methods, a zeroed buffer is rather created.
+With the latter methods, a zeroed buffer is rather created, wrapped in an [`UninitRB`]: it can either be filled
+and converted, or split into an [`UninitProdIter`], which only exposes initialising writes, until it has
+lapped the whole storage and [`UninitProdIter::into_init`] returns a normal producer.
+
+Items moved out of the buffer leave their locations uninitialised, though.
 To write data into an uninitialised (or zeroed) block of memory, one has to use [`write`](https://doc.rust-lang.org/std/primitive.pointer.html#method.write)
 method, which overwrites a memory location, without reading or dropping the old value.
 
@@ -65,6 +90,13 @@ pub struct ProdIter<B: IntoRef + OneRB> {
 
 unsafe impl<B: IntoRef + OneRB + SharedRB> Send for ProdIter<B> {}
 
+impl<B: IntoRef + OneRB> Drop for ProdIter<B> {
+    fn drop(&mut self) {
+        self.inner.buffer.liveness().drop_prod();
+        wake_peers(&*self.inner.buffer);
+    }
+}
+
 impl<B: IntoRef + OneRB<Item = T>, T> PrivateORBIterator for ProdIter<B> {
     type _Buffer = B;
 
@@ -83,20 +115,66 @@ impl<B: IntoRef + OneRB<Item = T>, T> PrivateORBIterator for ProdIter<B> {
                     .unchecked_sub(self.inner.index)
                     .unchecked_add(succ_idx)
                     .unchecked_sub(1),
-            };
+            }
+            .saturating_sub(self.inner.buffer.lookback());
         }
 
         self.inner.cached_avail
     }
 
+    #[cfg(feature = "stats")]
+    #[inline]
+    fn atomic_index(&self) -> usize {
+        self.inner.buffer.iters().prod_index()
+    }
+
     #[inline]
     fn set_atomic_index(&self, index: usize) {
         self.inner.buffer.iters().set_prod_index(index);
+        self.inner.buffer.waiter().notify();
     }
 
     #[inline]
     fn succ_index(&self) -> usize {
-        self.inner.buffer.iters().cons_index()
+        self.inner
+            .buffer
+            .liveness()
+            .cons_index(self.inner.buffer.iters())
+    }
+
+    #[inline]
+    fn succ_ended(&self) -> bool {
+        self.inner
+            .buffer
+            .liveness()
+            .downstream_ended(self.inner.buffer.iters())
+    }
+
+    #[inline]
+    fn _is_disconnected(&self) -> bool {
+        self.succ_ended()
+    }
+
+    #[cfg(feature = "stats")]
+    #[inline]
+    fn record_advance(&self, from: usize, count: usize) {
+        if let Some(stats) = self.inner.buffer.iters().stats() {
+            let (cons, len) = (self.succ_index(), self.inner.buffer.len());
+            let fill = match cons <= self.inner.index {
+                true => self.inner.index - cons,
+                false => len - cons + self.inner.index,
+            };
+
+            stats.record_push(from, count, fill);
+        }
+    }
+
+    #[cfg(feature = "stats")]
+    #[inline]
+    fn record_failure(&self) {
+        if let Some(stats) = self.inner.buffer.iters().stats() {
+            stats.record_overrun();
+        }
     }
 
     private_impl!();
@@ -107,6 +185,33 @@ impl<B: IntoRef + OneRB<Item = T>, T> ORBIterator for ProdIter<B> {
     type Buffer = B;
 }
 
+impl<B: IntoRef + OneRB> ExclusiveIter for ProdIter<B> {}
+
+/// Pushes every item, waiting for room, according to the [`WaitStrategy`] of the buffer,
+/// whenever the buffer is full. Items are published in batches, as many as there is room for,
+/// as in [`ProdIter::push_iter`], which also deals with possibly uninitialised locations.
+///
+/// Only implemented for buffers which can be shared between threads, since no one else could
+/// make room in a local one.
+///
+/// If the iterator gets [disconnected](ORBIterator::is_disconnected) while waiting, it stops
+/// and the remaining items are dropped, as they could never be consumed.
+impl<B: IntoRef + OneRB<Item = T> + SharedRB, T> Extend<T> for ProdIter<B> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut iter = iter.into_iter().peekable();
+
+        while iter.peek().is_some() {
+            if self.push_iter(&mut iter) == 0 {
+                self.wait_for(1);
+
+                if self.available() == 0 {
+                    return;
+                }
+            }
+        }
+    }
+}
+
 #[cfg(feature = "async")]
 impl<B: IntoRef + OneRB<Iters: AsyncIterComp>> ProdIter<B> {
     pub fn into_async(self) -> AsyncProdIter<B> {
@@ -116,19 +221,37 @@ impl<B: IntoRef + OneRB<Iters: AsyncIterComp>> ProdIter<B> {
 
 impl<B: IntoRef + OneRB<Item = T>, T> ProdIter<B> {
     pub(crate) fn new(value: B::TargetRef) -> Self {
+        let index = value.iters().prod_index();
+
         Self {
-            inner: Iter::new(value),
+            inner: Iter::new(value, index),
         }
     }
 
+    /// Consumes the iterator without running its destructor, returning its reference to the buffer.
+    pub(crate) fn into_buffer_ref(self) -> B::TargetRef {
+        let this = ManuallyDrop::new(self);
+
+        unsafe { ptr::read(&this.inner.buffer) }
+    }
+
+    /// Returns a new [`Observer`] of the buffer, which reports its fill level without moving any iterator.
+    #[inline]
+    pub fn observer(&self) -> Observer<B> {
+        Observer::new(self.inner.buffer.clone())
+    }
+
     #[inline]
-    fn _push(&mut self, value: T, f: fn(*mut T, T)) -> Result<(), T> {
+    pub(crate) fn _push(&mut self, value: T, f: fn(*mut T, T)) -> Result<(), PushError<T>> {
         if let Some(binding) = self.next_ref_mut_init() {
             f(binding, value);
             unsafe { self.advance(1) };
             Ok(())
         } else {
-            Err(value)
+            Err(match self.pop_failure() {
+                PopError::Empty => PushError::Full(value),
+                PopError::Disconnected => PushError::Disconnected(value),
+            })
         }
     }
 
@@ -139,10 +262,12 @@ impl<B: IntoRef + OneRB<Item = T>, T> ProdIter<B> {
     /// For more info, refer to the main documentation above.
     ///
     /// Returns:
-    /// * `Err(value)`, if the buffer is full;
+    /// * `Err(PushError::Full(value))`, if the buffer is full;
+    /// * `Err(PushError::Disconnected(value))`, if the buffer is full and the iterator is
+    ///   [disconnected](ORBIterator::is_disconnected);
     /// * `Ok(())`, otherwise.
     #[inline]
-    pub fn push(&mut self, value: T) -> Result<(), T> {
+    pub fn push(&mut self, value: T) -> Result<(), PushError<T>> {
         fn f<T>(binding: *mut T, value: T) {
             unsafe {
                 *binding = value;
@@ -152,14 +277,25 @@ impl<B: IntoRef + OneRB<Item = T>, T> ProdIter<B> {
         self._push(value, f)
     }
 
-    /// Same as [`Self::push_slice`], but can be used when dealing with possibly uninitialised
-    /// locations within the buffer, e.g. after a [`ConsIter::pop`].
+    /// Same as [`Self::push`], but waits until there is room for the item,
+    /// according to the [`WaitStrategy`] of the buffer.
     ///
     /// Returns:
-    /// * `Err(value)`, if the buffer is full;
+    /// * `Err(PushError::Disconnected(value))`, if the iterator got [disconnected](ORBIterator::is_disconnected) while waiting;
     /// * `Ok(())`, otherwise.
     #[inline]
-    pub fn push_init(&mut self, value: T) -> Result<(), T> {
+    pub fn push_blocking(&mut self, value: T) -> Result<(), PushError<T>> {
+        self.wait_for(1);
+
+        self.push(value)
+    }
+
+    /// Same as [`Self::push_slice`], but can be used when dealing with possibly uninitialised
+    /// locations within the buffer, e.g. after a [`ConsIter::pop`].
+    ///
+    /// Returns the same as [`Self::push`].
+    #[inline]
+    pub fn push_init(&mut self, value: T) -> Result<(), PushError<T>> {
         fn f<T>(binding: *mut T, value: T) {
             unsafe {
                 if UnsafeSyncCell::check_zeroed(binding) {
@@ -174,16 +310,35 @@ impl<B: IntoRef + OneRB<Item = T>, T> ProdIter<B> {
     }
 
     #[inline]
-    fn _push_slice(&mut self, slice: &[T], f: fn(&mut [T], &[T])) -> Option<()> {
+    fn _push_slice(&mut self, slice: &[T], f: fn(&mut [T], &[T])) -> Result<(), SliceError> {
         let count = slice.len();
 
-        self.check(count).then(|| {
+        if !self.check(count) {
+            return Err(self.slice_failure(count));
+        }
+
+        self.inner
+            .buffer
+            .storage_mut()
+            ._push_slice(self.inner.index, slice, f);
+        unsafe { self.advance(count) };
+
+        Ok(())
+    }
+
+    #[inline]
+    fn _push_slice_partial(&mut self, slice: &[T], f: fn(&mut [T], &[T])) -> usize {
+        let count = slice.len().min(self._available());
+
+        if count > 0 {
             self.inner
                 .buffer
                 .storage_mut()
-                ._push_slice(self.inner.index, slice, f);
+                ._push_slice(self.inner.index, &slice[..count], f);
             unsafe { self.advance(count) };
-        })
+        }
+
+        count
     }
 
     /// Tries to push a slice of items by copying the elements.
@@ -194,10 +349,12 @@ impl<B: IntoRef + OneRB<Item = T>, T> ProdIter<B> {
     /// For more info, refer to the main documentation above.
     ///
     /// Returns:
-    /// * `None`, if the buffer is full;
-    /// * `Some(())`, otherwise.
+    /// * `Err(SliceError::Insufficient)`, doing nothing, if there is not enough room for `slice`;
+    /// * `Err(SliceError::Disconnected)`, doing nothing, if there is not enough room for `slice` and
+    ///   the iterator is [disconnected](ORBIterator::is_disconnected);
+    /// * `Ok(())`, otherwise.
     #[inline]
-    pub fn push_slice(&mut self, slice: &[T]) -> Option<()>
+    pub fn push_slice(&mut self, slice: &[T]) -> Result<(), SliceError>
     where
         T: Copy,
     {
@@ -209,14 +366,48 @@ impl<B: IntoRef + OneRB<Item = T>, T> ProdIter<B> {
         self._push_slice(slice, f)
     }
 
+    /// Same as [`Self::push_slice`], but pushes a fixed-size array, which is given back on failure.
+    ///
+    /// Returns:
+    /// * `Err(PushError::Full(array))`, doing nothing, if there is not enough room for `array`;
+    /// * `Err(PushError::Disconnected(array))`, doing nothing, if there is not enough room for `array`
+    ///   and the iterator is [disconnected](ORBIterator::is_disconnected);
+    /// * `Ok(())`, otherwise.
+    #[inline]
+    pub fn push_array<const N: usize>(&mut self, array: [T; N]) -> Result<(), PushError<[T; N]>>
+    where
+        T: Copy,
+    {
+        match self.push_slice(&array) {
+            Ok(()) => Ok(()),
+            Err(SliceError::Insufficient { .. }) => Err(PushError::Full(array)),
+            Err(SliceError::Disconnected) => Err(PushError::Disconnected(array)),
+        }
+    }
+
+    /// Same as [`Self::push_slice`], but pushes as many items as there is room for, instead of
+    /// failing when `slice` does not fit entirely.
+    ///
+    /// Returns the number of items pushed, which are the first ones of `slice`.
+    #[inline]
+    pub fn push_slice_partial(&mut self, slice: &[T]) -> usize
+    where
+        T: Copy,
+    {
+        #[inline]
+        fn f<T: Copy>(binding: &mut [T], slice: &[T]) {
+            copy_from_slice_unchecked(slice, binding);
+        }
+
+        self._push_slice_partial(slice, f)
+    }
+
     /// Same as [`Self::push_slice`], but can be used when dealing with possibly uninitialised
     /// locations within the buffer, e.g. after a [`ConsIter::pop`].
     ///
-    /// Returns:
-    /// * `None`, if the buffer is full;
-    /// * `Some(())`, otherwise.
+    /// Returns the same as [`Self::push_slice`].
     #[inline]
-    pub fn push_slice_init(&mut self, slice: &[T]) -> Option<()>
+    pub fn push_slice_init(&mut self, slice: &[T]) -> Result<(), SliceError>
     where
         T: Copy,
     {
@@ -243,11 +434,9 @@ impl<B: IntoRef + OneRB<Item = T>, T> ProdIter<B> {
     /// In this case, [`Self::push_slice_clone_init`] has to be used, instead.
     /// For more info, refer to the main documentation above.
     ///
-    /// Returns:
-    /// * `None`, if the buffer is full;
-    /// * `Some(())`, otherwise.
+    /// Returns the same as [`Self::push_slice`].
     #[inline]
-    pub fn push_slice_clone(&mut self, slice: &[T]) -> Option<()>
+    pub fn push_slice_clone(&mut self, slice: &[T]) -> Result<(), SliceError>
     where
         T: Clone,
     {
@@ -259,14 +448,28 @@ impl<B: IntoRef + OneRB<Item = T>, T> ProdIter<B> {
         self._push_slice(slice, f)
     }
 
+    /// Same as [`Self::push_slice_partial`], but the elements are cloned.
+    ///
+    /// Returns the number of items pushed, which are the first ones of `slice`.
+    #[inline]
+    pub fn push_slice_clone_partial(&mut self, slice: &[T]) -> usize
+    where
+        T: Clone,
+    {
+        #[inline]
+        fn f<T: Clone>(binding_h: &mut [T], slice: &[T]) {
+            binding_h.clone_from_slice(slice);
+        }
+
+        self._push_slice_partial(slice, f)
+    }
+
     /// Same as [`Self::push_slice_clone`], but can be used when dealing with possibly uninitialised
     /// locations within the buffer, e.g. after a [`ConsIter::pop`].
     ///
-    /// Returns:
-    /// * `None`, if the buffer is full;
-    /// * `Some(())`, otherwise.
+    /// Returns the same as [`Self::push_slice`].
     #[inline]
-    pub fn push_slice_clone_init(&mut self, slice: &[T]) -> Option<()>
+    pub fn push_slice_clone_init(&mut self, slice: &[T]) -> Result<(), SliceError>
     where
         T: Clone,
     {
@@ -286,6 +489,91 @@ impl<B: IntoRef + OneRB<Item = T>, T> ProdIter<B> {
         self._push_slice(slice, f)
     }
 
+    /// Pushes items taken from `iter`, until either the iterator or the free space runs out.
+    /// Items are published all at once, after the last one has been written.
+    ///
+    /// Items which do not fit are not consumed from the iterator.
+    /// Like `*_init` methods, this one can be used when dealing with possibly uninitialised
+    /// locations within the buffer, e.g. after a [`ConsIter::pop`].
+    ///
+    /// Returns the number of items pushed.
+    pub fn push_iter<I: IntoIterator<Item = T>>(&mut self, iter: I) -> usize {
+        let len = self.inner.buffer.len();
+        let mut idx = self.inner.index;
+        let mut count = 0;
+
+        for value in iter.into_iter().take(self._available()) {
+            let binding = self.inner.buffer.storage()._index(idx).as_mut_ptr();
+
+            unsafe {
+                if UnsafeSyncCell::check_zeroed(binding) {
+                    binding.write(value);
+                } else {
+                    *binding = value;
+                }
+            }
+
+            idx += 1;
+            if idx == len {
+                idx = 0;
+            }
+            count += 1;
+        }
+
+        if count > 0 {
+            unsafe { self.advance(count) };
+        }
+
+        count
+    }
+
+    /// Moves as many items as there is room for from the front of `vec` into the buffer,
+    /// removing them from `vec`. Items do not need to implement [`Clone`].
+    ///
+    /// Like `*_init` methods, this one can be used when dealing with possibly uninitialised
+    /// locations within the buffer, e.g. after a [`ConsIter::pop`].
+    ///
+    /// Returns the number of items moved.
+    #[cfg(feature = "alloc")]
+    pub fn push_vec_drain(&mut self, vec: &mut Vec<T>) -> usize {
+        let count = vec.len().min(self._available());
+
+        self.push_iter(vec.drain(..count))
+    }
+
+    /// Returns a [`Grant`] over the next `count` items, which advances the producer by itself
+    /// when dropped.
+    ///
+    /// Like [`ORBIterator::get_mut_slice_exact`], the granted items must be *initialised*:
+    /// this method must *not* be used after a [`ConsIter::pop_move`].
+    #[inline]
+    pub fn grant(&mut self, count: usize) -> Result<Grant<'_, Self>, SliceError> {
+        Grant::new(self, count)
+    }
+
+    /// Checks that `count` contiguous items can be reserved, returning the index of the first one,
+    /// along with the number of items to skip at the end of the storage.
+    #[inline]
+    fn reserve_contiguous(&mut self, count: usize) -> Result<(usize, usize), SliceError> {
+        // Skipped items would end up among the ones kept for `ConsIter::history`.
+        assert_eq!(
+            self.inner.buffer.lookback(),
+            0,
+            "reservations cannot be used along with lookback"
+        );
+
+        let (index, len) = (self.inner.index, self.inner.buffer.len());
+        let padding = match index + count > len {
+            true => len - index,
+            false => 0,
+        };
+
+        match self.check(padding + count) {
+            true => Ok(((index + padding) % len, padding)),
+            false => Err(self.slice_error(padding + count)),
+        }
+    }
+
     /// If available, returns a mutable reference to the next item.
     /// This reference can be used to write data into an *initialised* item.
     ///
@@ -365,6 +653,130 @@ impl<B: IntoRef + OneRB<Item = T>, T> ProdIter<B> {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl<T, I: NonMutIterComp> ProdIter<OneRingBuf<HeapStorage<T>, I>> {
+    /// Returns a [`Reservation`] over the next `count` items, as a single contiguous slice,
+    /// which advances the producer by itself when dropped.
+    ///
+    /// If the items would cross the end of the buffer, the remaining items at the end of the
+    /// storage are skipped, and the reservation starts from the beginning instead. The consumer
+    /// skips them transparently, so they count as pushed until the consumer goes past them.
+    ///
+    /// Like [`Self::grant`], the reserved items must be *initialised*.
+    ///
+    /// Returns:
+    /// * `Err(SliceError::Insufficient)`, if there is no room for `count` items, plus the skipped ones;
+    /// * `Err(SliceError::Disconnected)`, if there is no room and the consumer has been dropped;
+    /// * `Ok(reservation)`, otherwise.
+    /// <div class="warning">Available only for non-mutable buffers.</div>
+    ///
+    /// # Panics
+    /// Panics if the buffer was built with [`OneRingBuf::with_lookback`].
+    pub fn reserve(
+        &mut self,
+        count: usize,
+    ) -> Result<Reservation<'_, OneRingBuf<HeapStorage<T>, I>>, SliceError> {
+        let (index, padding) = self.reserve_contiguous(count)?;
+        let (slice, _) = self.inner.buffer.storage_mut().next_chunk_mut(index, count);
+
+        Ok(Reservation::new(self, slice, padding))
+    }
+}
+
+impl<'buf, T, const N: usize, I: NonMutIterComp> ProdIter<OneRingBuf<StackStorage<'buf, T, N>, I>> {
+    /// Returns a [`Reservation`] over the next `count` items, as a single contiguous slice,
+    /// which advances the producer by itself when dropped.
+    ///
+    /// If the items would cross the end of the buffer, the remaining items at the end of the
+    /// storage are skipped, and the reservation starts from the beginning instead. The consumer
+    /// skips them transparently, so they count as pushed until the consumer goes past them.
+    ///
+    /// Like [`Self::grant`], the reserved items must be *initialised*.
+    ///
+    /// Returns:
+    /// * `Err(SliceError::Insufficient)`, if there is no room for `count` items, plus the skipped ones;
+    /// * `Err(SliceError::Disconnected)`, if there is no room and the consumer has been dropped;
+    /// * `Ok(reservation)`, otherwise.
+    /// <div class="warning">Available only for non-mutable buffers.</div>
+    ///
+    /// # Panics
+    /// Panics if the buffer was built with [`OneRingBuf::with_lookback`].
+    pub fn reserve(
+        &mut self,
+        count: usize,
+    ) -> Result<Reservation<'_, OneRingBuf<StackStorage<'buf, T, N>, I>>, SliceError> {
+        let (index, padding) = self.reserve_contiguous(count)?;
+        let (slice, _) = self.inner.buffer.storage_mut().next_chunk_mut(index, count);
+
+        Ok(Reservation::new(self, slice, padding))
+    }
+}
+
+impl<B: IntoRef + OneRB<Item = u8>> ProdIter<B> {
+    /// Wraps the producer into a [`FramedProd`], writing frames up to `max_frame` bytes long.
+    ///
+    /// # Panics
+    /// Panics if a frame of `max_frame` bytes, along with its header, does not fit within the items
+    /// the buffer can hold, minus those kept by [`ConsIter::history`].
+    pub fn framed(self, max_frame: usize) -> FramedProd<B> {
+        FramedProd::new(self, max_frame)
+    }
+}
+
+impl<B: IntoRef + OneRB<Item = T, Iters = OverwriteComp>, T> ProdIter<B> {
+    /// Makes room for `count` items, overwriting the oldest ones, if needed.
+    #[inline]
+    fn make_room(&mut self, count: usize) {
+        if !self.check(count) {
+            self.inner
+                .buffer
+                .iters()
+                .make_room(self.inner.index, count, self.inner.buffer.len());
+        }
+    }
+
+    /// Pushes a new item by moving or copying it.
+    /// If the buffer is full, the oldest item is overwritten.
+    ///
+    /// This method never fails: it waits only if the consumer is copying out the oldest item,
+    /// which is a bounded operation.
+    /// Overwritten items are reported to the consumer by [`LossyConsIter`].
+    #[inline]
+    pub fn push_overwrite(&mut self, value: T) {
+        self.make_room(1);
+
+        let ret = self.push(value);
+        debug_assert!(ret.is_ok());
+    }
+
+    /// Pushes a slice of items by copying the elements.
+    /// If the buffer is full, the oldest items are overwritten.
+    ///
+    /// If `slice` is longer than the capacity of the buffer (i.e. its length minus one),
+    /// only its last items are pushed and the first ones are reported as lost.
+    #[inline]
+    pub fn push_slice_overwrite(&mut self, slice: &[T])
+    where
+        T: Copy,
+    {
+        let cap = self.inner.buffer.len() - 1;
+
+        let slice = match slice.len() > cap {
+            true => {
+                let skip = slice.len() - cap;
+                self.inner.buffer.iters().add_lost(skip);
+                &slice[skip..]
+            }
+            false => slice,
+        };
+
+        self.make_room(slice.len());
+
+        let ret = self.push_slice(slice);
+        debug_assert!(ret.is_ok());
+    }
+}
+
 pub mod test {
     #[test]
     fn cached_avail() {
@@ -0,0 +1,163 @@
+use crate::errors::{PopError, PushError, SliceError};
+use crate::iterators::ProdIter;
+use crate::iterators::iterator_trait::{ORBIterator, PrivateORBIterator};
+use crate::iterators::sync_iterators::observer::Observer;
+use crate::iterators::util_macros::delegate;
+use crate::iterators::util_macros::muncher;
+use crate::ring_buffer::OneRB;
+use crate::ring_buffer::storage_components::PStorageComponent;
+use crate::ring_buffer::wrappers::refs::IntoRef;
+#[cfg(doc)]
+use crate::{UninitRB, WaitStrategy, iterators::ConsIter};
+
+#[doc = r##"
+Iterator used to push data into a buffer whose items are not all initialised yet, returned by
+[`UninitRB::split`].
+
+Only initialising writes are exposed: each of them behaves like the `*_init` counterpart of [`ProdIter`]
+(e.g. [`Self::push`] like [`ProdIter::push_init`]), so that no uninitialised item is ever read or dropped.
+Unlike those, initialised items are told by their position rather than by checking whether they are zeroed,
+so that items whose value is made up of zero bits are dropped as well when replaced.
+
+Once the producer has lapped the whole storage, every item is initialised, and [`Self::into_init`]
+returns a normal [`ProdIter`], while the [`ConsIter`] returned along with this keeps working.
+"##]
+pub struct UninitProdIter<B: IntoRef + OneRB> {
+    inner: ProdIter<B>,
+    /// Number of initialised items, starting from the beginning of the storage.
+    filled: usize,
+}
+
+impl<B: IntoRef + OneRB<Item = T>, T> UninitProdIter<B> {
+    pub(crate) fn new(value: B::TargetRef, filled: usize) -> Self {
+        Self {
+            inner: ProdIter::new(value),
+            filled,
+        }
+    }
+
+    #[inline]
+    fn inner(&self) -> &ProdIter<B> {
+        &self.inner
+    }
+    #[inline]
+    fn inner_mut(&mut self) -> &mut ProdIter<B> {
+        &mut self.inner
+    }
+
+    delegate!(ORBIterator (inline), pub fn available(&(mut) self) -> usize);
+    delegate!(ORBIterator (inline), pub fn buf_len(&self) -> usize);
+    delegate!(ORBIterator (inline), pub fn alive_iters(&self) -> u8);
+    delegate!(ORBIterator (inline), pub fn is_disconnected(&self) -> bool);
+    delegate!(ProdIter (inline), pub fn observer(&self) -> Observer<B>);
+
+    /// Returns the number of initialised items.
+    #[inline]
+    pub fn filled(&self) -> usize {
+        self.filled
+    }
+
+    /// Returns `true` if every item has been initialised.
+    #[inline]
+    pub fn is_init(&self) -> bool {
+        self.filled == self.inner.buf_len()
+    }
+
+    /// Converts this into a normal producer, once every item has been initialised.
+    ///
+    /// Returns `Err(self)` if the producer has not lapped the whole storage yet.
+    pub fn into_init(self) -> Result<ProdIter<B>, Self> {
+        match self.is_init() {
+            true => Ok(self.inner),
+            false => Err(self),
+        }
+    }
+
+    /// Writes `value` at `index`, dropping the item it replaces only if that was initialised.
+    ///
+    /// The producer writes the storage in order from its beginning, so, during the first lap,
+    /// it never lies past the initialised items: these are exactly the ones before `filled`.
+    #[inline]
+    fn write(&mut self, index: usize, value: T) {
+        let binding = self.inner.buffer().storage()._index(index).as_mut_ptr();
+
+        unsafe {
+            match index < self.filled {
+                true => *binding = value,
+                false => binding.write(value),
+            }
+        }
+
+        self.filled = self.filled.max(index + 1);
+    }
+
+    /// Same as [`ProdIter::push_init`], but tells initialised items by their position,
+    /// instead of checking whether they are zeroed.
+    #[inline]
+    pub fn push(&mut self, value: T) -> Result<(), PushError<T>> {
+        if !self.inner.check(1) {
+            return Err(match self.inner.pop_failure() {
+                PopError::Empty => PushError::Full(value),
+                PopError::Disconnected => PushError::Disconnected(value),
+            });
+        }
+
+        self.write(self.inner.index(), value);
+        unsafe { self.inner.advance(1) };
+
+        Ok(())
+    }
+
+    /// Same as [`Self::push`], but waits until there is room for the item,
+    /// according to the [`WaitStrategy`] of the buffer.
+    ///
+    /// Returns:
+    /// * `Err(PushError::Disconnected(value))`, if the iterator got [disconnected](ORBIterator::is_disconnected) while waiting;
+    /// * `Ok(())`, otherwise.
+    #[inline]
+    pub fn push_blocking(&mut self, value: T) -> Result<(), PushError<T>> {
+        self.inner.wait_for(1);
+
+        self.push(value)
+    }
+
+    /// Same as [`ProdIter::push_slice_init`].
+    ///
+    /// Since `Copy` items need no drop, they are copied whether the locations they replace
+    /// are initialised or not.
+    #[inline]
+    pub fn push_slice(&mut self, slice: &[T]) -> Result<(), SliceError>
+    where
+        T: Copy,
+    {
+        let index = self.inner.index();
+        self.inner.push_slice(slice)?;
+
+        let end = (index + slice.len()).min(self.inner.buf_len());
+        self.filled = self.filled.max(end);
+
+        Ok(())
+    }
+
+    /// Same as [`ProdIter::push_slice_clone_init`], but tells initialised items by their position,
+    /// instead of checking whether they are zeroed.
+    #[inline]
+    pub fn push_slice_clone(&mut self, slice: &[T]) -> Result<(), SliceError>
+    where
+        T: Clone,
+    {
+        let count = slice.len();
+
+        if !self.inner.check(count) {
+            return Err(self.inner.slice_failure(count));
+        }
+
+        let (index, len) = (self.inner.index(), self.inner.buf_len());
+        for (i, value) in slice.iter().enumerate() {
+            self.write((index + i) % len, value.clone());
+        }
+        unsafe { self.inner.advance(count) };
+
+        Ok(())
+    }
+}
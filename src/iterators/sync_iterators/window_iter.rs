@@ -0,0 +1,101 @@
+#[cfg(doc)]
+use crate::WaitStrategy;
+use crate::iterators::ConsIter;
+use crate::iterators::iterator_trait::{ORBIterator, PrivateORBIterator};
+use crate::ring_buffer::OneRB;
+use crate::ring_buffer::storage_components::StorageComponent;
+use crate::ring_buffer::wrappers::refs::IntoRef;
+
+type SliceOutput<'a, B> = <<B as OneRB>::Storage as StorageComponent>::SliceOutput<'a>;
+
+#[doc = r##"
+Iterator over overlapping windows of the available items, returned by [`ConsIter::windows`].
+
+Every window is long `window` items, and starts `hop` items after the previous one. Windows are
+returned as the same slices returned by [`ConsIter::peek_slice`]: a single one, when using `VmemStorage`,
+or a tuple of two, split at the end of the buffer, otherwise.
+
+The consumer is moved forward by `hop` items right before the next window is returned, or when
+this iterator is dropped, so that the items shared with the next window stay in place.
+Since each window borrows the iterator, this is not an [`Iterator`]: windows are obtained with
+[`Self::next_window`] or [`Self::next_window_blocking`].
+"##]
+pub struct WindowIter<'a, B: IntoRef + OneRB> {
+    iter: &'a mut ConsIter<B>,
+    window: usize,
+    hop: usize,
+    pending: bool,
+}
+
+impl<'a, B: IntoRef + OneRB> WindowIter<'a, B> {
+    pub(crate) fn new(iter: &'a mut ConsIter<B>, window: usize, hop: usize) -> Self {
+        assert!(
+            hop > 0 && hop <= window,
+            "hop must be greater than zero and not greater than the window"
+        );
+        // Items kept for `ConsIter::history` are never available.
+        let capacity = (iter.buf_len() - 1).saturating_sub(iter.buffer().lookback());
+        assert!(
+            window <= capacity,
+            "window must not be longer than the {capacity} items the buffer can hold"
+        );
+
+        Self {
+            iter,
+            window,
+            hop,
+            pending: false,
+        }
+    }
+
+    /// Returns the length of each window.
+    #[inline]
+    pub fn window(&self) -> usize {
+        self.window
+    }
+
+    /// Returns the number of items between the start of a window and the start of the next one.
+    #[inline]
+    pub fn hop(&self) -> usize {
+        self.hop
+    }
+
+    /// Moves the consumer past the start of the last returned window, if any.
+    #[inline]
+    fn release(&mut self) {
+        if self.pending {
+            unsafe { self.iter.advance(self.hop) };
+            self.pending = false;
+        }
+    }
+
+    /// Returns the next window, if `window` items are available, or `None` otherwise.
+    ///
+    /// If `None` is returned, the same window is tried again on the next call.
+    pub fn next_window(&mut self) -> Option<SliceOutput<'_, B>> {
+        self.release();
+
+        let ret = self.iter.peek_slice(self.window).ok()?;
+        self.pending = true;
+
+        Some(ret)
+    }
+
+    /// Same as [`Self::next_window`], but waits until `window` items are available,
+    /// according to the [`WaitStrategy`] of the buffer.
+    ///
+    /// Returns `None` only if the iterator got [disconnected](ORBIterator::is_disconnected)
+    /// before a whole window became available.
+    pub fn next_window_blocking(&mut self) -> Option<SliceOutput<'_, B>> {
+        self.release();
+        self.iter.wait_for(self.window);
+
+        self.next_window()
+    }
+}
+
+impl<B: IntoRef + OneRB> Drop for WindowIter<'_, B> {
+    fn drop(&mut self) {
+        self.release();
+    }
+}
@@ -1,8 +1,16 @@
-use crate::iterators::iterator_trait::{ORBIterator, PrivateORBIterator};
+use core::mem::ManuallyDrop;
+use core::ptr;
+
+use crate::errors::SliceError;
+use crate::iterators::iterator_trait::{ExclusiveIter, ORBIterator, PrivateORBIterator};
 use crate::iterators::private_impl;
 use crate::iterators::sync_iterators::Iter;
 #[allow(unused_imports)]
 use crate::iterators::sync_iterators::detached::Detached;
+use crate::iterators::sync_iterators::grant::Grant;
+use crate::iterators::sync_iterators::item_iters::IterMut;
+use crate::iterators::sync_iterators::observer::Observer;
+use crate::iterators::sync_iterators::wake_peers;
 use crate::ring_buffer::OneRB;
 use crate::ring_buffer::wrappers::refs::IntoRef;
 use crate::ring_buffer::{SharedRB, iters_components::PIterComponent};
@@ -25,14 +33,24 @@ in order to move the iterator.
 
 [`Self::advance`] updates a global iterator, which is read by the consumer to decide if it can move on.
 To avoid this [`Detached`] can be obtained by calling [`Self::detach`].
+
+When obtained from a pipeline buffer, each worker is bound to a stage: the worker at stage `s`
+can only process elements already released by the worker at stage `s - 1` (or by the producer, for stage `0`).
 "##]
-#[repr(transparent)]
 pub struct WorkIter<B: IntoRef + OneRB> {
     pub(crate) inner: Iter<B>,
+    stage: usize,
 }
 
 unsafe impl<B: IntoRef + OneRB + SharedRB> Send for WorkIter<B> {}
 
+impl<B: IntoRef + OneRB> Drop for WorkIter<B> {
+    fn drop(&mut self) {
+        self.inner.buffer.liveness().drop_stage(self.stage);
+        wake_peers(&*self.inner.buffer);
+    }
+}
+
 impl<B: IntoRef + OneRB<Item = T>, T> PrivateORBIterator for WorkIter<B> {
     type _Buffer = B;
 
@@ -55,14 +73,52 @@ impl<B: IntoRef + OneRB<Item = T>, T> PrivateORBIterator for WorkIter<B> {
         self.inner.cached_avail
     }
 
+    #[cfg(feature = "stats")]
+    #[inline]
+    fn atomic_index(&self) -> usize {
+        self.inner.buffer.iters().stage_index(self.stage)
+    }
+
     #[inline]
     fn set_atomic_index(&self, index: usize) {
-        self.inner.buffer.iters().set_work_index(index);
+        self.inner.buffer.iters().set_stage_index(self.stage, index);
+        self.inner.buffer.waiter().notify();
     }
 
     #[inline]
     fn succ_index(&self) -> usize {
-        self.inner.buffer.iters().prod_index()
+        match self.stage {
+            0 => self.inner.buffer.iters().prod_index(),
+            s => self.inner.buffer.iters().stage_index(s - 1),
+        }
+    }
+
+    #[inline]
+    fn succ_ended(&self) -> bool {
+        self.inner
+            .buffer
+            .liveness()
+            .chain_end(self.inner.buffer.iters(), self.stage)
+            .0
+    }
+
+    #[inline]
+    fn _is_disconnected(&self) -> bool {
+        let (ended, index) = self
+            .inner
+            .buffer
+            .liveness()
+            .chain_end(self.inner.buffer.iters(), self.stage);
+
+        ended && index == self.inner.index
+    }
+
+    #[cfg(feature = "stats")]
+    #[inline]
+    fn record_advance(&self, _from: usize, count: usize) {
+        if let Some(stats) = self.inner.buffer.iters().stats() {
+            stats.record_work(self.stage, count);
+        }
     }
 
     private_impl!();
@@ -73,6 +129,8 @@ impl<B: IntoRef + OneRB<Item = T>, T> ORBIterator for WorkIter<B> {
     type Buffer = B;
 }
 
+impl<B: IntoRef + OneRB> ExclusiveIter for WorkIter<B> {}
+
 #[cfg(feature = "async")]
 impl<B: IntoRef + OneRB<Iters: AsyncIterComp>> WorkIter<B> {
     pub fn into_async(self) -> AsyncWorkIter<B> {
@@ -82,16 +140,99 @@ impl<B: IntoRef + OneRB<Iters: AsyncIterComp>> WorkIter<B> {
 
 impl<B: IntoRef + OneRB<Item = T>, T> WorkIter<B> {
     pub(crate) fn new(value: B::TargetRef) -> Self {
+        Self::new_stage(value, 0)
+    }
+
+    pub(crate) fn new_stage(value: B::TargetRef, stage: usize) -> Self {
+        let index = value.iters().stage_index(stage);
+
         Self {
-            inner: Iter::new(value),
+            inner: Iter::new(value, index),
+            stage,
         }
     }
 
+    /// Consumes the iterator without running its destructor, returning its reference to the buffer.
+    pub(crate) fn into_buffer_ref(self) -> B::TargetRef {
+        let this = ManuallyDrop::new(self);
+
+        unsafe { ptr::read(&this.inner.buffer) }
+    }
+
+    /// Returns a new [`Observer`] of the buffer, which reports its fill level without moving any iterator.
+    #[inline]
+    pub fn observer(&self) -> Observer<B> {
+        Observer::new(self.inner.buffer.clone())
+    }
+
+    /// Returns the stage this worker is bound to.
+    /// Workers obtained from non-pipeline buffers always return `0`.
+    #[inline]
+    pub fn stage(&self) -> usize {
+        self.stage
+    }
+
+    /// Same as [`ORBIterator::get_mut_slice_exact`], but returns a [`Grant`], which advances
+    /// the worker by itself when dropped.
+    #[inline]
+    pub fn work_grant(&mut self, count: usize) -> Result<Grant<'_, Self>, SliceError> {
+        Grant::new(self, count)
+    }
+
+    /// Calls `f` with a mutable reference to each of the available items, in order,
+    /// then moves the worker past them, publishing its index once.
+    ///
+    /// References cannot escape `f`, so items are never released to the next iterator while
+    /// they are still being mutated:
+    /// ```compile_fail
+    /// use oneringbuf::SharedStackRBMut;
+    ///
+    /// let mut buf = SharedStackRBMut::<u32, 8>::from([0; 8]);
+    /// let (_prod, mut work, _cons) = buf.split_mut();
+    ///
+    /// let mut refs = Vec::new();
+    /// work.for_each_mut(|x| refs.push(x));
+    /// ```
+    ///
+    /// Returns the number of items processed.
+    pub fn for_each_mut(&mut self, mut f: impl FnMut(&mut T)) -> usize {
+        let count = self._available();
+
+        for _ in 0..count {
+            if let Some(item) = self.next_ref_mut() {
+                f(item);
+            }
+            unsafe { self.advance_local(1) };
+        }
+
+        if count > 0 {
+            self.publish_index();
+        }
+
+        count
+    }
+
+    /// Returns an iterator over mutable references to the available items.
+    ///
+    /// The worker is moved forward as items are yielded, but its index is not published until
+    /// [`Self::sync_index`], or any other method publishing it (e.g. [`Self::advance`]), is called,
+    /// which can only happen once every yielded reference has been dropped. See [`IterMut`].
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, B> {
+        IterMut::new(self)
+    }
+
+    /// Publishes the index of the worker, releasing the items it has moved past, e.g. with
+    /// [`Self::iter_mut`], to the next iterator.
+    #[inline]
+    pub fn sync_index(&mut self) {
+        self.publish_index();
+    }
+
     /// Resets the index of the iterator. I.e., moves the iterator to the location occupied by its successor.
     #[inline]
     pub fn reset_index(&mut self) {
-        let new_idx = self.succ_index();
-        self.inner.index = new_idx;
-        self.set_atomic_index(new_idx);
+        self.inner.index = self.succ_index();
+        self.publish_index();
     }
 }
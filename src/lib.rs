@@ -5,43 +5,59 @@
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 #[doc(inline)]
-pub use iterators::ORBIterator;
+pub use iterators::{ExclusiveIter, ORBIterator};
+
+pub use crate::errors::{FrameError, PopError, PushError, SliceError};
 
 pub use crate::ring_buffer::iters_components::IterComponent;
+#[cfg(feature = "stats")]
+pub use crate::ring_buffer::stats::Stats;
 pub use crate::ring_buffer::storage_components::StorageComponent;
+pub use crate::ring_buffer::uninit::UninitRB;
+pub use crate::ring_buffer::wait::{CancelToken, WaitStrategy};
 pub use crate::ring_buffer::{OneRB, OneRingBuf};
 pub use ring_buffer::wrappers::refs::IntoRef;
 
 // AsyncStackRB
 #[cfg(any(feature = "async", doc))]
-pub use crate::ring_buffer::types::{AsyncStackRB, AsyncStackRBMut};
+pub use crate::ring_buffer::types::{AsyncStackRB, AsyncStackRBMut, AsyncStackRBPipeline};
 
 // AsyncHeapRB
 #[cfg(all(feature = "alloc", feature = "async"))]
-pub use crate::ring_buffer::types::{AsyncHeapRB, AsyncHeapRBMut};
+pub use crate::ring_buffer::types::{AsyncHeapRB, AsyncHeapRBMut, AsyncHeapRBPipeline};
 
 // AsyncHeapRB
 #[cfg(all(feature = "alloc", feature = "async", feature = "vmem", unix))]
-pub use crate::ring_buffer::types::{AsyncVmemRB, AsyncVmemRBMut};
+pub use crate::ring_buffer::types::{AsyncVmemRB, AsyncVmemRBMut, AsyncVmemRBPipeline};
 
 // Heap
 #[cfg(feature = "alloc")]
-pub use crate::ring_buffer::types::{LocalHeapRB, LocalHeapRBMut, SharedHeapRB, SharedHeapRBMut};
+pub use crate::ring_buffer::types::{
+    BroadcastHeapRB, LocalHeapRB, LocalHeapRBMut, LocalHeapRBPipeline, MpscHeapRB, OverwriteHeapRB,
+    OwnedHeapRB, SharedHeapRB, SharedHeapRBMut, SharedHeapRBPipeline,
+};
 
 // Vmem
 #[cfg(any(doc, all(feature = "alloc", feature = "vmem", unix)))]
-pub use crate::ring_buffer::types::{LocalVmemRB, LocalVmemRBMut, SharedVmemRB, SharedVmemRBMut};
+pub use crate::ring_buffer::types::{
+    BroadcastVmemRB, LocalVmemRB, LocalVmemRBMut, LocalVmemRBPipeline, MpscVmemRB, OverwriteVmemRB,
+    OwnedVmemRB, SharedVmemRB, SharedVmemRBMut, SharedVmemRBPipeline,
+};
 
 // Stack
 pub use crate::ring_buffer::types::{
-    LocalStackRB, LocalStackRBMut, SharedStackRB, SharedStackRBMut,
+    BroadcastStackRB, LocalStackRB, LocalStackRBMut, LocalStackRBPipeline, MpscStackRB,
+    OverwriteStackRB, OwnedStackRB, SharedStackRB, SharedStackRBMut, SharedStackRBPipeline,
 };
 
 pub use ring_buffer::iters_components;
 pub use ring_buffer::storage_components;
 
+mod errors;
 pub mod iterators;
 mod ring_buffer;
 
@@ -1,15 +1,25 @@
+use core::{mem, ptr};
+
 #[cfg(feature = "alloc")]
 use crate::{
-    ring_buffer::wrappers::refs::droppable::DroppableRef, storage_components::HeapStorage,
+    OneRB,
+    ring_buffer::{
+        iters_components::{PIterComponent, UnsplitIterComp},
+        storage_components::PStorageComponent,
+        wrappers::refs::droppable::DroppableRef,
+    },
+    storage_components::HeapStorage,
 };
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 
 #[cfg(all(feature = "alloc", feature = "vmem", unix))]
 use crate::storage_components::VmemStorage;
 
 use crate::{
-    OneRingBuf,
-    iterators::{ConsIter, ProdIter, WorkIter},
-    iters_components::{MutIterComp, NonMutIterComp},
+    ORBIterator, OneRingBuf,
+    iterators::{ConsIter, PrivateORBIterator, ProdIter, WorkIter},
+    iters_components::{MutIterComp, NonMutIterComp, PipelineIterComp},
     ring_buffer::{
         iters_components::IterComponent,
         wrappers::refs::{IntoRef, non_droppable::NonDroppableRef},
@@ -39,6 +49,33 @@ impl<'buf, T, const N: usize, I: NonMutIterComp> OneRingBuf<StackStorage<'buf, T
         let r = NonDroppableRef::from(self);
         (ProdIter::new(r.clone()), ConsIter::new(r))
     }
+
+    /// Takes back the buffer split with [`Self::split`], consuming its iterators.
+    /// Items left within the buffer are kept, and a new split resumes from them.
+    /// To discard them, use [`Self::reset`].
+    ///
+    /// Returns the iterators back, if they do not belong to the same buffer, or if an
+    /// [`Observer`](crate::iterators::Observer) of the buffer is still alive.
+    #[allow(clippy::type_complexity)]
+    pub fn unsplit(
+        prod: ProdIter<OneRingBuf<StackStorage<'buf, T, N>, I>>,
+        cons: ConsIter<OneRingBuf<StackStorage<'buf, T, N>, I>>,
+    ) -> Result<
+        &'buf mut Self,
+        (
+            ProdIter<OneRingBuf<StackStorage<'buf, T, N>, I>>,
+            ConsIter<OneRingBuf<StackStorage<'buf, T, N>, I>>,
+        ),
+    > {
+        if !ptr::eq(prod.buffer(), cons.buffer()) || prod.alive_iters() != 2 {
+            return Err((prod, cons));
+        }
+
+        let r = prod.into_buffer_ref();
+        mem::forget(cons.into_buffer_ref());
+
+        Ok(unsafe { r.into_mut() })
+    }
 }
 impl<'buf, T, const N: usize, I: MutIterComp> OneRingBuf<StackStorage<'buf, T, N>, I> {
     /// Returns three iterators: a Producer, a Worker and a Consumer.
@@ -57,6 +94,95 @@ impl<'buf, T, const N: usize, I: MutIterComp> OneRingBuf<StackStorage<'buf, T, N
             ConsIter::new(r),
         )
     }
+
+    /// Takes back the buffer split with [`Self::split_mut`], consuming its iterators.
+    /// Items left within the buffer are kept, and a new split resumes from them.
+    /// To discard them, use [`Self::reset`].
+    ///
+    /// Returns the iterators back, if they do not belong to the same buffer, or if an
+    /// [`Observer`](crate::iterators::Observer) of the buffer is still alive.
+    #[allow(clippy::type_complexity)]
+    pub fn unsplit_mut(
+        prod: ProdIter<OneRingBuf<StackStorage<'buf, T, N>, I>>,
+        work: WorkIter<OneRingBuf<StackStorage<'buf, T, N>, I>>,
+        cons: ConsIter<OneRingBuf<StackStorage<'buf, T, N>, I>>,
+    ) -> Result<
+        &'buf mut Self,
+        (
+            ProdIter<OneRingBuf<StackStorage<'buf, T, N>, I>>,
+            WorkIter<OneRingBuf<StackStorage<'buf, T, N>, I>>,
+            ConsIter<OneRingBuf<StackStorage<'buf, T, N>, I>>,
+        ),
+    > {
+        if !ptr::eq(prod.buffer(), work.buffer())
+            || !ptr::eq(prod.buffer(), cons.buffer())
+            || prod.alive_iters() != 3
+        {
+            return Err((prod, work, cons));
+        }
+
+        let r = prod.into_buffer_ref();
+        mem::forget(work.into_buffer_ref());
+        mem::forget(cons.into_buffer_ref());
+
+        Ok(unsafe { r.into_mut() })
+    }
+}
+
+impl<'buf, T, const N: usize, I: IterComponent> OneRingBuf<StackStorage<'buf, T, N>, I> {
+    /// Returns a Producer, `K` Workers (one per stage, ordered) and a Consumer.
+    ///
+    /// The worker at stage `s` follows the worker at stage `s - 1`, while the consumer
+    /// follows the last worker.
+    /// <div class="warning">Available only for pipeline buffers.</div>
+    #[allow(clippy::type_complexity)]
+    pub fn split_pipeline<const K: usize>(
+        &'buf mut self,
+    ) -> (
+        ProdIter<OneRingBuf<StackStorage<'buf, T, N>, I>>,
+        [WorkIter<OneRingBuf<StackStorage<'buf, T, N>, I>>; K],
+        ConsIter<OneRingBuf<StackStorage<'buf, T, N>, I>>,
+    )
+    where
+        I: PipelineIterComp<K>,
+    {
+        let r = NonDroppableRef::from(self);
+        (
+            ProdIter::new(r.clone()),
+            core::array::from_fn(|s| WorkIter::new_stage(r.clone(), s)),
+            ConsIter::new(r),
+        )
+    }
+}
+
+/// Moves the items between the consumer and the producer out of `buffer`, leaving it empty.
+#[cfg(feature = "alloc")]
+fn take_items<B: OneRB>(buffer: &B) -> Vec<B::Item> {
+    let len = buffer.len();
+    let (mut idx, prod) = (buffer.iters().cons_index(), buffer.iters().prod_index());
+    let mut ret = Vec::with_capacity(if idx <= prod {
+        prod - idx
+    } else {
+        len - idx + prod
+    });
+
+    while idx != prod {
+        if buffer.storage().wrap_mark() == Some(idx) {
+            buffer.storage().set_wrap_mark(None);
+            idx = 0;
+            continue;
+        }
+
+        ret.push(unsafe { buffer.storage()._index(idx).take_inner() });
+
+        idx += 1;
+        if idx == len {
+            idx = 0;
+        }
+    }
+    buffer.iters().set_cons_index(prod);
+
+    ret
 }
 
 #[cfg(feature = "alloc")]
@@ -81,6 +207,33 @@ impl<T, I: NonMutIterComp> OneRingBuf<HeapStorage<T>, I> {
         let r = DroppableRef::from(self);
         (ProdIter::new(r.clone()), ConsIter::new(r))
     }
+
+    /// Takes back the buffer split with [`Self::split`], consuming its iterators.
+    /// Items left within the buffer are kept, and a new split resumes from them.
+    /// To discard them, use [`Self::reset`].
+    ///
+    /// Returns the iterators back, if they do not belong to the same buffer, or if an
+    /// [`Observer`](crate::iterators::Observer) of the buffer is still alive.
+    #[allow(clippy::type_complexity)]
+    pub fn unsplit(
+        prod: ProdIter<OneRingBuf<HeapStorage<T>, I>>,
+        cons: ConsIter<OneRingBuf<HeapStorage<T>, I>>,
+    ) -> Result<
+        Self,
+        (
+            ProdIter<OneRingBuf<HeapStorage<T>, I>>,
+            ConsIter<OneRingBuf<HeapStorage<T>, I>>,
+        ),
+    > {
+        if !ptr::eq(prod.buffer(), cons.buffer()) || prod.alive_iters() != 2 {
+            return Err((prod, cons));
+        }
+
+        let r = prod.into_buffer_ref();
+        mem::forget(cons.into_buffer_ref());
+
+        Ok(unsafe { r.into_inner() })
+    }
 }
 #[cfg(feature = "alloc")]
 impl<T, I: MutIterComp> OneRingBuf<HeapStorage<T>, I> {
@@ -100,6 +253,77 @@ impl<T, I: MutIterComp> OneRingBuf<HeapStorage<T>, I> {
             ConsIter::new(r),
         )
     }
+
+    /// Takes back the buffer split with [`Self::split_mut`], consuming its iterators.
+    /// Items left within the buffer are kept, and a new split resumes from them.
+    /// To discard them, use [`Self::reset`].
+    ///
+    /// Returns the iterators back, if they do not belong to the same buffer, or if an
+    /// [`Observer`](crate::iterators::Observer) of the buffer is still alive.
+    #[allow(clippy::type_complexity)]
+    pub fn unsplit_mut(
+        prod: ProdIter<OneRingBuf<HeapStorage<T>, I>>,
+        work: WorkIter<OneRingBuf<HeapStorage<T>, I>>,
+        cons: ConsIter<OneRingBuf<HeapStorage<T>, I>>,
+    ) -> Result<
+        Self,
+        (
+            ProdIter<OneRingBuf<HeapStorage<T>, I>>,
+            WorkIter<OneRingBuf<HeapStorage<T>, I>>,
+            ConsIter<OneRingBuf<HeapStorage<T>, I>>,
+        ),
+    > {
+        if !ptr::eq(prod.buffer(), work.buffer())
+            || !ptr::eq(prod.buffer(), cons.buffer())
+            || prod.alive_iters() != 3
+        {
+            return Err((prod, work, cons));
+        }
+
+        let r = prod.into_buffer_ref();
+        mem::forget(work.into_buffer_ref());
+        mem::forget(cons.into_buffer_ref());
+
+        Ok(unsafe { r.into_inner() })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, I: UnsplitIterComp> OneRingBuf<HeapStorage<T>, I> {
+    /// Moves the items left between the consumer and the producer out of the buffer, in order,
+    /// and returns them, consuming the buffer.
+    ///
+    /// A split buffer has to be taken back with `unsplit` or `unsplit_mut` first.
+    pub fn into_vec(self) -> Vec<T> {
+        take_items(&self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, I: IterComponent> OneRingBuf<HeapStorage<T>, I> {
+    /// Returns a Producer, `K` Workers (one per stage, ordered) and a Consumer.
+    ///
+    /// The worker at stage `s` follows the worker at stage `s - 1`, while the consumer
+    /// follows the last worker.
+    /// <div class="warning">Available only for pipeline buffers.</div>
+    #[allow(clippy::type_complexity)]
+    pub fn split_pipeline<const K: usize>(
+        self,
+    ) -> (
+        ProdIter<OneRingBuf<HeapStorage<T>, I>>,
+        [WorkIter<OneRingBuf<HeapStorage<T>, I>>; K],
+        ConsIter<OneRingBuf<HeapStorage<T>, I>>,
+    )
+    where
+        I: PipelineIterComp<K>,
+    {
+        let r = DroppableRef::from(self);
+        (
+            ProdIter::new(r.clone()),
+            core::array::from_fn(|s| WorkIter::new_stage(r.clone(), s)),
+            ConsIter::new(r),
+        )
+    }
 }
 
 #[cfg(all(feature = "alloc", feature = "vmem", unix))]
@@ -124,6 +348,33 @@ impl<T, I: NonMutIterComp> OneRingBuf<VmemStorage<T>, I> {
         let r = DroppableRef::from(self);
         (ProdIter::new(r.clone()), ConsIter::new(r))
     }
+
+    /// Takes back the buffer split with [`Self::split`], consuming its iterators.
+    /// Items left within the buffer are kept, and a new split resumes from them.
+    /// To discard them, use [`Self::reset`].
+    ///
+    /// Returns the iterators back, if they do not belong to the same buffer, or if an
+    /// [`Observer`](crate::iterators::Observer) of the buffer is still alive.
+    #[allow(clippy::type_complexity)]
+    pub fn unsplit(
+        prod: ProdIter<OneRingBuf<VmemStorage<T>, I>>,
+        cons: ConsIter<OneRingBuf<VmemStorage<T>, I>>,
+    ) -> Result<
+        Self,
+        (
+            ProdIter<OneRingBuf<VmemStorage<T>, I>>,
+            ConsIter<OneRingBuf<VmemStorage<T>, I>>,
+        ),
+    > {
+        if !ptr::eq(prod.buffer(), cons.buffer()) || prod.alive_iters() != 2 {
+            return Err((prod, cons));
+        }
+
+        let r = prod.into_buffer_ref();
+        mem::forget(cons.into_buffer_ref());
+
+        Ok(unsafe { r.into_inner() })
+    }
 }
 #[cfg(all(feature = "alloc", feature = "vmem", unix))]
 impl<T, I: MutIterComp> OneRingBuf<VmemStorage<T>, I> {
@@ -143,4 +394,75 @@ impl<T, I: MutIterComp> OneRingBuf<VmemStorage<T>, I> {
             ConsIter::new(r),
         )
     }
+
+    /// Takes back the buffer split with [`Self::split_mut`], consuming its iterators.
+    /// Items left within the buffer are kept, and a new split resumes from them.
+    /// To discard them, use [`Self::reset`].
+    ///
+    /// Returns the iterators back, if they do not belong to the same buffer, or if an
+    /// [`Observer`](crate::iterators::Observer) of the buffer is still alive.
+    #[allow(clippy::type_complexity)]
+    pub fn unsplit_mut(
+        prod: ProdIter<OneRingBuf<VmemStorage<T>, I>>,
+        work: WorkIter<OneRingBuf<VmemStorage<T>, I>>,
+        cons: ConsIter<OneRingBuf<VmemStorage<T>, I>>,
+    ) -> Result<
+        Self,
+        (
+            ProdIter<OneRingBuf<VmemStorage<T>, I>>,
+            WorkIter<OneRingBuf<VmemStorage<T>, I>>,
+            ConsIter<OneRingBuf<VmemStorage<T>, I>>,
+        ),
+    > {
+        if !ptr::eq(prod.buffer(), work.buffer())
+            || !ptr::eq(prod.buffer(), cons.buffer())
+            || prod.alive_iters() != 3
+        {
+            return Err((prod, work, cons));
+        }
+
+        let r = prod.into_buffer_ref();
+        mem::forget(work.into_buffer_ref());
+        mem::forget(cons.into_buffer_ref());
+
+        Ok(unsafe { r.into_inner() })
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "vmem", unix))]
+impl<T, I: UnsplitIterComp> OneRingBuf<VmemStorage<T>, I> {
+    /// Moves the items left between the consumer and the producer out of the buffer, in order,
+    /// and returns them, consuming the buffer.
+    ///
+    /// A split buffer has to be taken back with `unsplit` or `unsplit_mut` first.
+    pub fn into_vec(self) -> Vec<T> {
+        take_items(&self)
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "vmem", unix))]
+impl<T, I: IterComponent> OneRingBuf<VmemStorage<T>, I> {
+    /// Returns a Producer, `K` Workers (one per stage, ordered) and a Consumer.
+    ///
+    /// The worker at stage `s` follows the worker at stage `s - 1`, while the consumer
+    /// follows the last worker.
+    /// <div class="warning">Available only for pipeline buffers.</div>
+    #[allow(clippy::type_complexity)]
+    pub fn split_pipeline<const K: usize>(
+        self,
+    ) -> (
+        ProdIter<OneRingBuf<VmemStorage<T>, I>>,
+        [WorkIter<OneRingBuf<VmemStorage<T>, I>>; K],
+        ConsIter<OneRingBuf<VmemStorage<T>, I>>,
+    )
+    where
+        I: PipelineIterComp<K>,
+    {
+        let r = DroppableRef::from(self);
+        (
+            ProdIter::new(r.clone()),
+            core::array::from_fn(|s| WorkIter::new_stage(r.clone(), s)),
+            ConsIter::new(r),
+        )
+    }
 }
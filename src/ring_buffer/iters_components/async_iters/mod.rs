@@ -2,17 +2,18 @@
 
 use core::task::Waker;
 
-use crate::IterComponent;
+use crate::iters_components::SingleConsIterComp;
 
 pub mod mutable;
 pub mod non_mutable;
+pub mod pipeline;
 
 /// Trait implemented by async iterator components.
 ///
 /// This trait is not meant to be implemented outside of this crate, nor
 /// are its methods meant to be called directly.
 /// Instead, this trait should be used only as parameter/bound.
-pub trait AsyncIterComp: IterComponent {
+pub trait AsyncIterComp: SingleConsIterComp {
     fn wake_middle_iter(&self);
 
     fn register_prod_waker(&self, waker: &Waker);
@@ -23,6 +24,17 @@ pub trait AsyncIterComp: IterComponent {
     fn take_work_waker(&self) -> Option<Waker>;
     fn wake_work(&self);
 
+    fn register_stage_waker(&self, _stage: usize, waker: &Waker) {
+        self.register_work_waker(waker);
+    }
+    fn take_stage_waker(&self, _stage: usize) -> Option<Waker> {
+        self.take_work_waker()
+    }
+    /// Wakes the iterator which follows the worker at `stage`.
+    fn wake_next_stage(&self, _stage: usize) {
+        self.wake_cons();
+    }
+
     fn register_cons_waker(&self, waker: &Waker);
     fn take_cons_waker(&self) -> Option<Waker>;
     fn wake_cons(&self);
@@ -1,6 +1,9 @@
 use crossbeam_utils::CachePadded;
 use futures_util::task::AtomicWaker;
 
+#[cfg(feature = "stats")]
+use crate::ring_buffer::stats::Stats;
+
 use core::{
     sync::atomic::Ordering::{Acquire, Release},
     task::Waker,
@@ -15,10 +18,11 @@ use crate::{
         async_iterators::AsyncIterator,
     },
     iters_components::{
-        MutIterComp, async_iters::AsyncIterComp, shared_iters::mutable::SharedCompMut,
+        MutIterComp, UnsplitIterComp, async_iters::AsyncIterComp,
+        shared_iters::mutable::SharedCompMut,
     },
     ring_buffer::{
-        iters_components::{IterComponent, PIterComponent},
+        iters_components::{IterComponent, PIterComponent, SingleConsIterComp},
         wrappers::refs::non_droppable::NonDroppableRef,
     },
     storage_components::StackStorage,
@@ -38,6 +42,7 @@ pub struct AsyncCompMut {
 }
 
 impl MutIterComp for AsyncCompMut {}
+impl UnsplitIterComp for AsyncCompMut {}
 
 impl AsyncCompMut {
     pub const fn default() -> Self {
@@ -104,6 +109,11 @@ impl PIterComponent for AsyncCompMut {
         self.inner.alive_iters.fetch_sub(1, Release)
     }
 
+    #[inline]
+    fn add_iter(&self) {
+        self.inner.add_iter();
+    }
+
     #[inline(always)]
     fn acquire_fence(&self) {
         #[cfg(not(feature = "thread_sanitiser"))]
@@ -148,9 +158,27 @@ impl PIterComponent for AsyncCompMut {
     fn alive_iters(&self) -> u8 {
         self.inner.alive_iters.load(Acquire)
     }
+
+    #[cfg(feature = "stats")]
+    #[inline]
+    fn stats(&self) -> Option<&Stats> {
+        self.inner.stats()
+    }
+
+    #[inline(always)]
+    fn stages(&self) -> usize {
+        1
+    }
+
+    fn wake_all(&self) {
+        self.wake_prod();
+        self.wake_work();
+        self.wake_cons();
+    }
 }
 
 impl IterComponent for AsyncCompMut {}
+impl SingleConsIterComp for AsyncCompMut {}
 
 impl<'buf, T, const N: usize> OneRingBuf<StackStorage<'buf, T, N>, AsyncCompMut> {
     /// Returns three iterators: a Producer, a Worker and a Consumer.
@@ -1,6 +1,9 @@
 use crossbeam_utils::CachePadded;
 use futures_util::task::AtomicWaker;
 
+#[cfg(feature = "stats")]
+use crate::ring_buffer::stats::Stats;
+
 use core::{
     sync::atomic::Ordering::{Acquire, Release},
     task::Waker,
@@ -12,10 +15,11 @@ use crate::{
     OneRingBuf,
     iterators::{AsyncConsIter, AsyncProdIter, ConsIter, ProdIter, async_iterators::AsyncIterator},
     iters_components::{
-        NonMutIterComp, async_iters::AsyncIterComp, shared_iters::non_mutable::SharedComp,
+        NonMutIterComp, UnsplitIterComp, async_iters::AsyncIterComp,
+        shared_iters::non_mutable::SharedComp,
     },
     ring_buffer::{
-        iters_components::{IterComponent, PIterComponent},
+        iters_components::{IterComponent, PIterComponent, SingleConsIterComp},
         wrappers::refs::non_droppable::NonDroppableRef,
     },
     storage_components::StackStorage,
@@ -34,6 +38,7 @@ pub struct AsyncComp {
 }
 
 impl NonMutIterComp for AsyncComp {}
+impl UnsplitIterComp for AsyncComp {}
 
 impl AsyncComp {
     pub const fn default() -> Self {
@@ -95,6 +100,11 @@ impl PIterComponent for AsyncComp {
         self.inner.alive_iters.fetch_sub(1, Release)
     }
 
+    #[inline]
+    fn add_iter(&self) {
+        self.inner.add_iter();
+    }
+
     #[inline(always)]
     fn acquire_fence(&self) {
         #[cfg(not(feature = "thread_sanitiser"))]
@@ -137,9 +147,21 @@ impl PIterComponent for AsyncComp {
     fn alive_iters(&self) -> u8 {
         self.inner.alive_iters.load(Acquire)
     }
+
+    #[cfg(feature = "stats")]
+    #[inline]
+    fn stats(&self) -> Option<&Stats> {
+        self.inner.stats()
+    }
+
+    fn wake_all(&self) {
+        self.wake_prod();
+        self.wake_cons();
+    }
 }
 
 impl IterComponent for AsyncComp {}
+impl SingleConsIterComp for AsyncComp {}
 
 impl<'buf, T, const N: usize> OneRingBuf<StackStorage<'buf, T, N>, AsyncComp> {
     /// Returns two iterators: a Producer and a Consumer.
@@ -0,0 +1,261 @@
+use crossbeam_utils::CachePadded;
+use futures_util::task::AtomicWaker;
+
+use core::task::Waker;
+
+#[cfg(feature = "stats")]
+use crate::ring_buffer::stats::Stats;
+
+#[cfg(all(feature = "alloc", feature = "vmem", unix))]
+use crate::storage_components::VmemStorage;
+use crate::{
+    OneRingBuf,
+    iterators::{
+        AsyncConsIter, AsyncProdIter, AsyncWorkIter, ConsIter, ProdIter, WorkIter,
+        async_iterators::AsyncIterator,
+    },
+    iters_components::{
+        PipelineIterComp, async_iters::AsyncIterComp, shared_iters::pipeline::SharedCompPipeline,
+    },
+    ring_buffer::{
+        iters_components::{IterComponent, PIterComponent, SingleConsIterComp},
+        wrappers::refs::non_droppable::NonDroppableRef,
+    },
+    storage_components::StackStorage,
+};
+#[cfg(feature = "alloc")]
+use crate::{
+    ring_buffer::wrappers::refs::droppable::DroppableRef, storage_components::HeapStorage,
+};
+
+/// Pipeline async iterators component usable in concurrent environments.
+///
+/// Holds `K` worker stages, each one following the previous one.
+pub struct AsyncCompPipeline<const K: usize> {
+    inner: SharedCompPipeline<K>,
+
+    pub(crate) prod_waker: CachePadded<AtomicWaker>,
+    pub(crate) work_wakers: [CachePadded<AtomicWaker>; K],
+    pub(crate) cons_waker: CachePadded<AtomicWaker>,
+}
+
+impl<const K: usize> PipelineIterComp<K> for AsyncCompPipeline<K> {}
+
+impl<const K: usize> AsyncCompPipeline<K> {
+    pub const fn default() -> Self {
+        Self {
+            inner: SharedCompPipeline::default(),
+
+            prod_waker: CachePadded::new(AtomicWaker::new()),
+            work_wakers: [const { CachePadded::new(AtomicWaker::new()) }; K],
+            cons_waker: CachePadded::new(AtomicWaker::new()),
+        }
+    }
+}
+
+impl<const K: usize> AsyncIterComp for AsyncCompPipeline<K> {
+    fn wake_middle_iter(&self) {
+        self.work_wakers[0].wake();
+    }
+
+    fn register_prod_waker(&self, waker: &Waker) {
+        self.prod_waker.register(waker);
+    }
+
+    fn take_prod_waker(&self) -> Option<Waker> {
+        self.prod_waker.take()
+    }
+
+    fn wake_prod(&self) {
+        self.prod_waker.wake();
+    }
+
+    fn register_work_waker(&self, waker: &Waker) {
+        self.register_stage_waker(0, waker);
+    }
+
+    fn take_work_waker(&self) -> Option<Waker> {
+        self.take_stage_waker(0)
+    }
+
+    fn wake_work(&self) {
+        self.work_wakers[0].wake();
+    }
+
+    fn register_stage_waker(&self, stage: usize, waker: &Waker) {
+        self.work_wakers[stage].register(waker);
+    }
+
+    fn take_stage_waker(&self, stage: usize) -> Option<Waker> {
+        self.work_wakers[stage].take()
+    }
+
+    fn wake_next_stage(&self, stage: usize) {
+        match self.work_wakers.get(stage + 1) {
+            Some(w) => w.wake(),
+            None => self.cons_waker.wake(),
+        }
+    }
+
+    fn register_cons_waker(&self, waker: &Waker) {
+        self.cons_waker.register(waker);
+    }
+
+    fn take_cons_waker(&self) -> Option<Waker> {
+        self.cons_waker.take()
+    }
+
+    fn wake_cons(&self) {
+        self.cons_waker.wake();
+    }
+}
+
+impl<const K: usize> PIterComponent for AsyncCompPipeline<K> {
+    #[inline(always)]
+    fn middle_iter_idx(&self) -> usize {
+        self.inner.middle_iter_idx()
+    }
+
+    #[inline(always)]
+    fn drop_iter(&self) -> u8 {
+        self.inner.drop_iter()
+    }
+
+    #[inline]
+    fn add_iter(&self) {
+        self.inner.add_iter();
+    }
+
+    #[inline(always)]
+    fn acquire_fence(&self) {
+        self.inner.acquire_fence();
+    }
+
+    #[inline]
+    fn prod_index(&self) -> usize {
+        self.inner.prod_index()
+    }
+
+    #[inline]
+    fn work_index(&self) -> usize {
+        self.inner.work_index()
+    }
+
+    #[inline]
+    fn cons_index(&self) -> usize {
+        self.inner.cons_index()
+    }
+
+    #[inline]
+    fn set_prod_index(&self, index: usize) {
+        self.inner.set_prod_index(index);
+    }
+
+    #[inline]
+    fn set_work_index(&self, index: usize) {
+        self.inner.set_work_index(index);
+    }
+
+    #[inline]
+    fn set_cons_index(&self, index: usize) {
+        self.inner.set_cons_index(index);
+    }
+
+    fn alive_iters(&self) -> u8 {
+        self.inner.alive_iters()
+    }
+
+    #[cfg(feature = "stats")]
+    #[inline]
+    fn stats(&self) -> Option<&Stats> {
+        self.inner.stats()
+    }
+
+    #[inline(always)]
+    fn stages(&self) -> usize {
+        K
+    }
+
+    fn wake_all(&self) {
+        self.wake_prod();
+        self.work_wakers.iter().for_each(|w| w.wake());
+        self.wake_cons();
+    }
+
+    #[inline]
+    fn stage_index(&self, stage: usize) -> usize {
+        self.inner.stage_index(stage)
+    }
+
+    #[inline]
+    fn set_stage_index(&self, stage: usize, index: usize) {
+        self.inner.set_stage_index(stage, index);
+    }
+}
+
+impl<const K: usize> IterComponent for AsyncCompPipeline<K> {}
+impl<const K: usize> SingleConsIterComp for AsyncCompPipeline<K> {}
+
+impl<'buf, T, const N: usize, const K: usize>
+    OneRingBuf<StackStorage<'buf, T, N>, AsyncCompPipeline<K>>
+{
+    /// Returns a Producer, `K` Workers (one per stage, ordered) and a Consumer.
+    /// <div class="warning">Available only for pipeline buffers.</div>
+    #[allow(clippy::type_complexity)]
+    pub fn split_async_pipeline(
+        &'buf mut self,
+    ) -> (
+        AsyncProdIter<OneRingBuf<StackStorage<'buf, T, N>, AsyncCompPipeline<K>>>,
+        [AsyncWorkIter<OneRingBuf<StackStorage<'buf, T, N>, AsyncCompPipeline<K>>>; K],
+        AsyncConsIter<OneRingBuf<StackStorage<'buf, T, N>, AsyncCompPipeline<K>>>,
+    ) {
+        let r = NonDroppableRef::from(self);
+        (
+            AsyncProdIter::from_sync(ProdIter::new(r.clone())),
+            core::array::from_fn(|s| AsyncWorkIter::from_sync(WorkIter::new_stage(r.clone(), s))),
+            AsyncConsIter::from_sync(ConsIter::new(r)),
+        )
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const K: usize> OneRingBuf<HeapStorage<T>, AsyncCompPipeline<K>> {
+    /// Returns a Producer, `K` Workers (one per stage, ordered) and a Consumer.
+    /// <div class="warning">Available only for pipeline buffers.</div>
+    #[allow(clippy::type_complexity)]
+    pub fn split_async_pipeline(
+        self,
+    ) -> (
+        AsyncProdIter<OneRingBuf<HeapStorage<T>, AsyncCompPipeline<K>>>,
+        [AsyncWorkIter<OneRingBuf<HeapStorage<T>, AsyncCompPipeline<K>>>; K],
+        AsyncConsIter<OneRingBuf<HeapStorage<T>, AsyncCompPipeline<K>>>,
+    ) {
+        let r = DroppableRef::from(self);
+        (
+            AsyncProdIter::from_sync(ProdIter::new(r.clone())),
+            core::array::from_fn(|s| AsyncWorkIter::from_sync(WorkIter::new_stage(r.clone(), s))),
+            AsyncConsIter::from_sync(ConsIter::new(r)),
+        )
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "vmem", unix))]
+impl<T, const K: usize> OneRingBuf<VmemStorage<T>, AsyncCompPipeline<K>> {
+    /// Returns a Producer, `K` Workers (one per stage, ordered) and a Consumer.
+    /// <div class="warning">Available only for pipeline buffers.</div>
+    #[allow(clippy::type_complexity)]
+    pub fn split_async_pipeline(
+        self,
+    ) -> (
+        AsyncProdIter<OneRingBuf<VmemStorage<T>, AsyncCompPipeline<K>>>,
+        [AsyncWorkIter<OneRingBuf<VmemStorage<T>, AsyncCompPipeline<K>>>; K],
+        AsyncConsIter<OneRingBuf<VmemStorage<T>, AsyncCompPipeline<K>>>,
+    ) {
+        let r = DroppableRef::from(self);
+        (
+            AsyncProdIter::from_sync(ProdIter::new(r.clone())),
+            core::array::from_fn(|s| AsyncWorkIter::from_sync(WorkIter::new_stage(r.clone(), s))),
+            AsyncConsIter::from_sync(ConsIter::new(r)),
+        )
+    }
+}
@@ -1,2 +1,3 @@
 pub mod mutable;
 pub mod non_mutable;
+pub mod pipeline;
@@ -1,8 +1,8 @@
 use core::cell::UnsafeCell;
 
 use crate::{
-    iters_components::MutIterComp,
-    ring_buffer::iters_components::{IterComponent, PIterComponent},
+    iters_components::{MutIterComp, UnsplitIterComp},
+    ring_buffer::iters_components::{IterComponent, PIterComponent, SingleConsIterComp},
 };
 
 /// Mutable iterators component usable in single-threaded environments.
@@ -15,6 +15,7 @@ pub struct LocalCompMut {
 }
 
 impl MutIterComp for LocalCompMut {}
+impl UnsplitIterComp for LocalCompMut {}
 
 impl LocalCompMut {
     pub const fn default() -> Self {
@@ -41,6 +42,13 @@ impl PIterComponent for LocalCompMut {
         }
     }
 
+    fn add_iter(&self) {
+        unsafe {
+            let alive = &mut *self.alive_iters.get();
+            *alive = alive.checked_add(1).expect("too many iterators alive");
+        }
+    }
+
     fn acquire_fence(&self) {}
 
     #[inline]
@@ -82,6 +90,12 @@ impl PIterComponent for LocalCompMut {
     fn alive_iters(&self) -> u8 {
         unsafe { *self.alive_iters.get() }
     }
+
+    #[inline(always)]
+    fn stages(&self) -> usize {
+        1
+    }
 }
 
 impl IterComponent for LocalCompMut {}
+impl SingleConsIterComp for LocalCompMut {}
@@ -1,8 +1,8 @@
 use core::cell::UnsafeCell;
 
 use crate::{
-    iters_components::NonMutIterComp,
-    ring_buffer::iters_components::{IterComponent, PIterComponent},
+    iters_components::{NonMutIterComp, UnsplitIterComp},
+    ring_buffer::iters_components::{IterComponent, PIterComponent, SingleConsIterComp},
 };
 
 /// Non-mutable iterators component usable in single-threaded environments.
@@ -14,6 +14,7 @@ pub struct LocalComp {
 }
 
 impl NonMutIterComp for LocalComp {}
+impl UnsplitIterComp for LocalComp {}
 
 impl LocalComp {
     pub const fn default() -> Self {
@@ -39,6 +40,13 @@ impl PIterComponent for LocalComp {
         }
     }
 
+    fn add_iter(&self) {
+        unsafe {
+            let alive = &mut *self.alive_iters.get();
+            *alive = alive.checked_add(1).expect("too many iterators alive");
+        }
+    }
+
     fn acquire_fence(&self) {}
 
     #[inline]
@@ -79,3 +87,4 @@ impl PIterComponent for LocalComp {
 }
 
 impl IterComponent for LocalComp {}
+impl SingleConsIterComp for LocalComp {}
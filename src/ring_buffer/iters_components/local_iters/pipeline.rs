@@ -0,0 +1,114 @@
+use core::cell::UnsafeCell;
+
+use crate::{
+    iters_components::PipelineIterComp,
+    ring_buffer::iters_components::{IterComponent, PIterComponent, SingleConsIterComp},
+};
+
+/// Pipeline iterators component usable in single-threaded environments.
+///
+/// Holds `K` worker stages, each one following the previous one.
+pub struct LocalCompPipeline<const K: usize> {
+    prod_idx: UnsafeCell<usize>,
+    work_idx: [UnsafeCell<usize>; K],
+    cons_idx: UnsafeCell<usize>,
+
+    alive_iters: UnsafeCell<u8>,
+}
+
+impl<const K: usize> PipelineIterComp<K> for LocalCompPipeline<K> {}
+
+impl<const K: usize> LocalCompPipeline<K> {
+    pub const fn default() -> Self {
+        assert!(K > 0 && K <= u8::MAX as usize - 2);
+
+        Self {
+            prod_idx: UnsafeCell::new(0),
+            work_idx: [const { UnsafeCell::new(0) }; K],
+            cons_idx: UnsafeCell::new(0),
+            alive_iters: UnsafeCell::new(K as u8 + 2),
+        }
+    }
+}
+
+impl<const K: usize> PIterComponent for LocalCompPipeline<K> {
+    #[inline(always)]
+    fn middle_iter_idx(&self) -> usize {
+        self.stage_index(K - 1)
+    }
+
+    fn drop_iter(&self) -> u8 {
+        unsafe {
+            let ret = *self.alive_iters.get();
+            *self.alive_iters.get() -= 1;
+            ret
+        }
+    }
+
+    fn add_iter(&self) {
+        unsafe {
+            let alive = &mut *self.alive_iters.get();
+            *alive = alive.checked_add(1).expect("too many iterators alive");
+        }
+    }
+
+    fn acquire_fence(&self) {}
+
+    #[inline]
+    fn prod_index(&self) -> usize {
+        unsafe { *self.prod_idx.get() }
+    }
+
+    #[inline]
+    fn work_index(&self) -> usize {
+        self.stage_index(0)
+    }
+
+    #[inline]
+    fn cons_index(&self) -> usize {
+        unsafe { *self.cons_idx.get() }
+    }
+
+    #[inline]
+    fn set_prod_index(&self, index: usize) {
+        unsafe {
+            *self.prod_idx.get() = index;
+        }
+    }
+
+    #[inline]
+    fn set_work_index(&self, index: usize) {
+        self.set_stage_index(0, index);
+    }
+
+    #[inline]
+    fn set_cons_index(&self, index: usize) {
+        unsafe {
+            *self.cons_idx.get() = index;
+        }
+    }
+
+    fn alive_iters(&self) -> u8 {
+        unsafe { *self.alive_iters.get() }
+    }
+
+    #[inline(always)]
+    fn stages(&self) -> usize {
+        K
+    }
+
+    #[inline]
+    fn stage_index(&self, stage: usize) -> usize {
+        unsafe { *self.work_idx[stage].get() }
+    }
+
+    #[inline]
+    fn set_stage_index(&self, stage: usize, index: usize) {
+        unsafe {
+            *self.work_idx[stage].get() = index;
+        }
+    }
+}
+
+impl<const K: usize> IterComponent for LocalCompPipeline<K> {}
+impl<const K: usize> SingleConsIterComp for LocalCompPipeline<K> {}
@@ -1,11 +1,22 @@
 //! Components used by the buffers to manage iterators.
 
 #[cfg(feature = "async")]
-pub use async_iters::{AsyncIterComp, mutable::AsyncCompMut, non_mutable::AsyncComp};
+pub use async_iters::{
+    AsyncIterComp, mutable::AsyncCompMut, non_mutable::AsyncComp, pipeline::AsyncCompPipeline,
+};
 pub use local_iters::mutable::LocalCompMut;
 pub use local_iters::non_mutable::LocalComp;
+pub use local_iters::pipeline::LocalCompPipeline;
+pub use shared_iters::broadcast::BroadcastComp;
+pub use shared_iters::mpsc::MpscComp;
 pub use shared_iters::mutable::SharedCompMut;
 pub use shared_iters::non_mutable::SharedComp;
+pub use shared_iters::overwrite::OverwriteComp;
+pub use shared_iters::owned::OwnedComp;
+pub use shared_iters::pipeline::SharedCompPipeline;
+
+#[cfg(feature = "stats")]
+use crate::ring_buffer::stats::Stats;
 
 pub(crate) mod async_iters;
 pub(crate) mod local_iters;
@@ -14,6 +25,8 @@ pub(crate) mod shared_iters;
 pub(crate) trait PIterComponent {
     fn middle_iter_idx(&self) -> usize;
     fn drop_iter(&self) -> u8;
+    /// Registers a new reference to the buffer, such as a cloned iterator.
+    fn add_iter(&self);
     fn acquire_fence(&self);
     fn prod_index(&self) -> usize;
     fn work_index(&self) -> usize;
@@ -22,6 +35,79 @@ pub(crate) trait PIterComponent {
     fn set_work_index(&self, index: usize);
     fn set_cons_index(&self, index: usize);
     fn alive_iters(&self) -> u8;
+
+    /// Returns the index of the worker at `stage`.
+    /// Components with a single worker only know about stage `0`.
+    #[inline]
+    fn stage_index(&self, _stage: usize) -> usize {
+        self.work_index()
+    }
+    /// Sets the index of the worker at `stage`.
+    #[inline]
+    fn set_stage_index(&self, _stage: usize, index: usize) {
+        self.set_work_index(index);
+    }
+
+    /// Returns the index of the consumer `reader`.
+    /// Components with a single consumer only know about reader `0`.
+    #[inline]
+    fn reader_index(&self, _reader: usize) -> usize {
+        self.cons_index()
+    }
+    /// Sets the index of the consumer `reader`.
+    #[inline]
+    fn set_reader_index(&self, _reader: usize, index: usize) {
+        self.set_cons_index(index);
+    }
+
+    /// Returns the index of the slowest consumer among the readers for which `alive` returns `true`,
+    /// or among all of them, if none does.
+    /// Components with a single consumer return [`Self::cons_index`].
+    #[inline]
+    fn slowest_cons_index(&self, _alive: impl Fn(usize) -> bool) -> usize {
+        self.cons_index()
+    }
+
+    /// Returns the number of worker stages.
+    #[inline]
+    fn stages(&self) -> usize {
+        0
+    }
+
+    /// Returns the number of consumer readers.
+    #[inline]
+    fn readers(&self) -> usize {
+        1
+    }
+
+    /// Wakes every iterator waiting asynchronously. Called when an iterator is dropped.
+    #[inline]
+    fn wake_all(&self) {}
+
+    /// Moves every index back to the start of the buffer.
+    /// Must be called only while no iterator is alive.
+    fn reset(&self) {
+        self.set_prod_index(0);
+        for stage in 0..self.stages() {
+            self.set_stage_index(stage, 0);
+        }
+        self.set_work_index(0);
+        self.set_cons_index(0);
+    }
+
+    /// Returns the runtime statistics recorded by the component, if any.
+    #[cfg(feature = "stats")]
+    #[inline]
+    fn stats(&self) -> Option<&Stats> {
+        None
+    }
+
+    /// Returns `true` if only the items between the consumer and the producer are initialised,
+    /// so that the buffer has to drop them by itself.
+    #[inline]
+    fn owns_items(&self) -> bool {
+        false
+    }
 }
 
 /// Trait implemented by all iterator components.
@@ -30,3 +116,10 @@ pub trait IterComponent: PIterComponent {}
 pub trait MutIterComp: IterComponent {}
 /// Trait implemented by non-mutable iterator components.
 pub trait NonMutIterComp: IterComponent {}
+/// Trait implemented by iterator components whose iterators can be merged back into the buffer.
+pub trait UnsplitIterComp: IterComponent {}
+/// Trait implemented by iterator components with `K` chained worker stages.
+pub trait PipelineIterComp<const K: usize>: IterComponent {}
+/// Trait implemented by iterator components with a single consumer, which is then the only one
+/// accessing the items it reads.
+pub trait SingleConsIterComp: IterComponent {}
@@ -0,0 +1,292 @@
+use core::sync::atomic::{AtomicU8, AtomicUsize};
+
+use crossbeam_utils::CachePadded;
+
+use core::sync::atomic::Ordering::{Acquire, Relaxed, Release};
+
+#[cfg(doc)]
+use crate::ExclusiveIter;
+#[cfg(all(feature = "alloc", feature = "vmem", unix))]
+use crate::storage_components::VmemStorage;
+use crate::{
+    OneRingBuf,
+    iterators::{ConsIter, ProdIter},
+    ring_buffer::{
+        iters_components::{IterComponent, PIterComponent},
+        wrappers::refs::non_droppable::NonDroppableRef,
+    },
+    storage_components::StackStorage,
+};
+#[cfg(feature = "alloc")]
+use crate::{
+    ring_buffer::{
+        OneRB,
+        wrappers::refs::{IntoRef, droppable::DroppableRef},
+    },
+    storage_components::HeapStorage,
+};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Broadcast iterators component usable in concurrent environments.
+///
+/// Holds up to `C` consumers, each one with its own index, following the producer.
+/// The producer, in turn, follows the slowest consumer still alive.
+///
+/// The number of consumers is chosen when splitting the buffer, while `C` only bounds it:
+/// like the number of stages of pipeline components, it is a const parameter, so that indices
+/// are held inline and stack buffers need no allocator and can still be created in `const` contexts.
+pub struct BroadcastComp<const C: usize> {
+    pub(crate) prod_idx: CachePadded<AtomicUsize>,
+    pub(crate) cons_idx: [CachePadded<AtomicUsize>; C],
+
+    pub(crate) alive_iters: AtomicU8,
+}
+
+impl<const C: usize> BroadcastComp<C> {
+    pub const fn default() -> Self {
+        assert!(C > 0 && C < u8::MAX as usize);
+
+        Self {
+            prod_idx: CachePadded::new(AtomicUsize::new(0)),
+            cons_idx: [const { CachePadded::new(AtomicUsize::new(0)) }; C],
+            alive_iters: AtomicU8::new(C as u8 + 1),
+        }
+    }
+}
+
+impl<const C: usize> PIterComponent for BroadcastComp<C> {
+    #[inline(always)]
+    fn middle_iter_idx(&self) -> usize {
+        self.prod_index()
+    }
+
+    #[inline(always)]
+    fn drop_iter(&self) -> u8 {
+        self.alive_iters.fetch_sub(1, Release)
+    }
+
+    #[inline]
+    fn add_iter(&self) {
+        self.alive_iters
+            .fetch_update(Relaxed, Relaxed, |x| x.checked_add(1))
+            .expect("too many iterators alive");
+    }
+
+    #[inline(always)]
+    fn acquire_fence(&self) {
+        #[cfg(not(feature = "thread_sanitiser"))]
+        core::sync::atomic::fence(Acquire);
+
+        // ThreadSanitizer does not support memory fences. To avoid false positive
+        // reports use atomic loads for synchronization instead.
+        #[cfg(feature = "thread_sanitiser")]
+        self.alive_iters.load(Acquire);
+    }
+
+    #[inline]
+    fn prod_index(&self) -> usize {
+        self.prod_idx.load(Acquire)
+    }
+
+    #[inline]
+    fn work_index(&self) -> usize {
+        self.prod_index()
+    }
+
+    /// Returns the index of the slowest consumer, i.e. the one with the most items yet to be read.
+    #[inline]
+    fn cons_index(&self) -> usize {
+        self.slowest_cons_index(|_| true)
+    }
+
+    /// Every consumer lies behind the producer: the ones with an index greater than the producer's
+    /// one have wrapped less, so they lag behind the others.
+    /// Dropped consumers are skipped, as their index never moves again, unless every one has been dropped.
+    #[inline]
+    fn slowest_cons_index(&self, alive: impl Fn(usize) -> bool) -> usize {
+        let prod = self.prod_idx.load(Acquire);
+        let any_alive = (0..C).any(&alive);
+
+        let (mut behind, mut wrapped) = (usize::MAX, usize::MAX);
+        for (reader, idx) in self.cons_idx.iter().enumerate() {
+            if any_alive && !alive(reader) {
+                continue;
+            }
+
+            let idx = idx.load(Acquire);
+            match idx > prod {
+                true => behind = behind.min(idx),
+                false => wrapped = wrapped.min(idx),
+            }
+        }
+
+        match behind {
+            usize::MAX => wrapped,
+            _ => behind,
+        }
+    }
+
+    #[inline]
+    fn set_prod_index(&self, index: usize) {
+        self.prod_idx.store(index, Release);
+    }
+
+    #[inline]
+    fn set_work_index(&self, _index: usize) {}
+
+    #[inline]
+    fn set_cons_index(&self, index: usize) {
+        self.set_reader_index(0, index);
+    }
+
+    fn alive_iters(&self) -> u8 {
+        self.alive_iters.load(Acquire)
+    }
+
+    #[inline]
+    fn reader_index(&self, reader: usize) -> usize {
+        self.cons_idx[reader].load(Acquire)
+    }
+
+    #[inline]
+    fn set_reader_index(&self, reader: usize, index: usize) {
+        self.cons_idx[reader].store(index, Release);
+    }
+
+    #[inline]
+    fn readers(&self) -> usize {
+        C
+    }
+
+    fn reset(&self) {
+        self.set_prod_index(0);
+        for reader in 0..C {
+            self.set_reader_index(reader, 0);
+        }
+    }
+}
+
+impl<const C: usize> IterComponent for BroadcastComp<C> {}
+
+/// Checks that a broadcast buffer holding up to `c` consumers can be split into `n` ones.
+fn check_readers(n: usize, c: usize) {
+    assert!(
+        n > 0 && n <= c,
+        "the number of consumers must be between 1 and {c}, got {n}"
+    );
+}
+
+/// Returns `n` consumers of the buffer referenced by `r`, out of the `c` it can hold.
+///
+/// The consumers exceeding `n` are dropped right away, so that the producer never follows them.
+#[cfg(feature = "alloc")]
+fn split_readers<B: IntoRef + OneRB>(r: &B::TargetRef, n: usize, c: usize) -> Vec<ConsIter<B>>
+where
+    B::TargetRef: Clone,
+{
+    check_readers(n, c);
+
+    let mut cons: Vec<_> = (0..c).map(|i| ConsIter::new_reader(r.clone(), i)).collect();
+    cons.truncate(n);
+    cons
+}
+
+impl<'buf, T, const N: usize, const C: usize>
+    OneRingBuf<StackStorage<'buf, T, N>, BroadcastComp<C>>
+{
+    /// Returns a Producer and `n` Consumers, each one reading every item.
+    ///
+    /// So that no allocator is needed, Consumers are returned in an array of `C` slots,
+    /// of which only the first `n` are filled: the remaining consumers are dropped right away,
+    /// so that the producer never waits for them.
+    /// Since every consumer goes over the same items, none of them hands out mutable references
+    /// (see [`ExclusiveIter`]).
+    /// <div class="warning">Available only for broadcast buffers.</div>
+    ///
+    /// # Panics
+    /// Panics if `n` is zero or greater than `C`.
+    #[allow(clippy::type_complexity)]
+    pub fn split_broadcast(
+        &'buf mut self,
+        n: usize,
+    ) -> (
+        ProdIter<OneRingBuf<StackStorage<'buf, T, N>, BroadcastComp<C>>>,
+        [Option<ConsIter<OneRingBuf<StackStorage<'buf, T, N>, BroadcastComp<C>>>>; C],
+    ) {
+        check_readers(n, C);
+
+        let r = NonDroppableRef::from(self);
+        let cons = core::array::from_fn(|i| {
+            let cons = ConsIter::new_reader(r.clone(), i);
+            (i < n).then_some(cons)
+        });
+        (ProdIter::new(r), cons)
+    }
+
+    /// Same as [`Self::split_broadcast`], but returns all the `C` Consumers.
+    /// <div class="warning">Available only for broadcast buffers.</div>
+    #[allow(clippy::type_complexity)]
+    pub fn split_broadcast_array(
+        &'buf mut self,
+    ) -> (
+        ProdIter<OneRingBuf<StackStorage<'buf, T, N>, BroadcastComp<C>>>,
+        [ConsIter<OneRingBuf<StackStorage<'buf, T, N>, BroadcastComp<C>>>; C],
+    ) {
+        let r = NonDroppableRef::from(self);
+        let cons = core::array::from_fn(|i| ConsIter::new_reader(r.clone(), i));
+        (ProdIter::new(r), cons)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const C: usize> OneRingBuf<HeapStorage<T>, BroadcastComp<C>> {
+    /// Returns a Producer and `n` Consumers, each one reading every item.
+    ///
+    /// The buffer can hold up to `C` consumers: the remaining ones are dropped right away,
+    /// so that the producer never waits for them.
+    /// Since every consumer goes over the same items, none of them hands out mutable references
+    /// (see [`ExclusiveIter`]).
+    /// <div class="warning">Available only for broadcast buffers.</div>
+    ///
+    /// # Panics
+    /// Panics if `n` is zero or greater than `C`.
+    #[allow(clippy::type_complexity)]
+    pub fn split_broadcast(
+        self,
+        n: usize,
+    ) -> (
+        ProdIter<OneRingBuf<HeapStorage<T>, BroadcastComp<C>>>,
+        Vec<ConsIter<OneRingBuf<HeapStorage<T>, BroadcastComp<C>>>>,
+    ) {
+        let r = DroppableRef::from(self);
+        let cons = split_readers(&r, n, C);
+        (ProdIter::new(r), cons)
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "vmem", unix))]
+impl<T, const C: usize> OneRingBuf<VmemStorage<T>, BroadcastComp<C>> {
+    /// Returns a Producer and `n` Consumers, each one reading every item.
+    ///
+    /// The buffer can hold up to `C` consumers: the remaining ones are dropped right away,
+    /// so that the producer never waits for them.
+    /// Since every consumer goes over the same items, none of them hands out mutable references
+    /// (see [`ExclusiveIter`]).
+    /// <div class="warning">Available only for broadcast buffers.</div>
+    ///
+    /// # Panics
+    /// Panics if `n` is zero or greater than `C`.
+    #[allow(clippy::type_complexity)]
+    pub fn split_broadcast(
+        self,
+        n: usize,
+    ) -> (
+        ProdIter<OneRingBuf<VmemStorage<T>, BroadcastComp<C>>>,
+        Vec<ConsIter<OneRingBuf<VmemStorage<T>, BroadcastComp<C>>>>,
+    ) {
+        let r = DroppableRef::from(self);
+        let cons = split_readers(&r, n, C);
+        (ProdIter::new(r), cons)
+    }
+}
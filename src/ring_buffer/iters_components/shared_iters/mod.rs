@@ -1,2 +1,7 @@
+pub mod broadcast;
+pub mod mpsc;
 pub mod mutable;
 pub mod non_mutable;
+pub mod overwrite;
+pub mod owned;
+pub mod pipeline;
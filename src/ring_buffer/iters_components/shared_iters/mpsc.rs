@@ -0,0 +1,238 @@
+use core::hint::spin_loop;
+use core::sync::atomic::{AtomicU8, AtomicUsize};
+
+use crossbeam_utils::CachePadded;
+
+use core::sync::atomic::Ordering::{AcqRel, Acquire, Relaxed, Release};
+
+/// Spins performed while waiting for other producers to publish, before yielding.
+#[cfg(feature = "std")]
+const COMMIT_SPINS: u32 = 128;
+
+#[cfg(all(feature = "alloc", feature = "vmem", unix))]
+use crate::storage_components::VmemStorage;
+use crate::{
+    OneRingBuf,
+    iterators::{ConsIter, MpscProdIter},
+    ring_buffer::{
+        iters_components::{IterComponent, PIterComponent, SingleConsIterComp},
+        wrappers::refs::non_droppable::NonDroppableRef,
+    },
+    storage_components::StackStorage,
+};
+#[cfg(feature = "alloc")]
+use crate::{
+    ring_buffer::wrappers::refs::droppable::DroppableRef, storage_components::HeapStorage,
+};
+
+/// Multi-producer iterators component usable in concurrent environments.
+///
+/// Producers claim slots by moving a reservation index forward, then publish them
+/// in the same order they were claimed.
+pub struct MpscComp {
+    /// Wrapping counter of the claimed slots. It is a multiple of the buffer length apart
+    /// from the real index, to avoid ABA problems while claiming.
+    pub(crate) reserve_idx: CachePadded<AtomicUsize>,
+    /// Same as `reserve_idx`, but counting published slots.
+    pub(crate) commit_idx: CachePadded<AtomicUsize>,
+    pub(crate) prod_idx: CachePadded<AtomicUsize>,
+    pub(crate) cons_idx: CachePadded<AtomicUsize>,
+
+    pub(crate) alive_iters: AtomicU8,
+}
+
+impl MpscComp {
+    pub const fn default() -> Self {
+        Self {
+            reserve_idx: CachePadded::new(AtomicUsize::new(0)),
+            commit_idx: CachePadded::new(AtomicUsize::new(0)),
+            prod_idx: CachePadded::new(AtomicUsize::new(0)),
+            cons_idx: CachePadded::new(AtomicUsize::new(0)),
+            alive_iters: AtomicU8::new(2),
+        }
+    }
+
+    /// Tries to claim `count` slots, returning the value of the reservation counter
+    /// before the claim, or the number of free slots, if fewer than `count`.
+    /// `wrap` is the period of the counters.
+    #[inline]
+    pub(crate) fn reserve(&self, count: usize, len: usize, wrap: usize) -> Result<usize, usize> {
+        let mut start = self.reserve_idx.load(Acquire);
+
+        loop {
+            let idx = start % len;
+            let cons = self.cons_idx.load(Acquire);
+
+            let avail = match idx < cons {
+                true => cons - idx - 1,
+                false => len - idx + cons - 1,
+            };
+            if avail < count {
+                return Err(avail);
+            }
+
+            match self.reserve_idx.compare_exchange_weak(
+                start,
+                wrapping_add(start, count, wrap),
+                AcqRel,
+                Acquire,
+            ) {
+                Ok(_) => return Ok(start),
+                Err(cur) => start = cur,
+            }
+        }
+    }
+
+    /// Publishes `count` slots claimed with [`Self::reserve`], after all the slots claimed
+    /// before them have been published.
+    ///
+    /// With `std` feature, the thread yields if the wait gets long, e.g. because the producer
+    /// it is waiting for has been preempted. Without it, the wait is an unbounded busy loop, which
+    /// never ends if that producer cannot resume until this one returns.
+    #[inline]
+    pub(crate) fn commit(&self, start: usize, count: usize, len: usize, wrap: usize) {
+        let mut _spins = 0u32;
+
+        while self.commit_idx.load(Acquire) != start {
+            #[cfg(feature = "std")]
+            if _spins >= COMMIT_SPINS {
+                std::thread::yield_now();
+                continue;
+            }
+
+            _spins += 1;
+            spin_loop();
+        }
+
+        let next = wrapping_add(start, count, wrap);
+        self.prod_idx.store(next % len, Release);
+        self.commit_idx.store(next, Release);
+    }
+}
+
+#[inline(always)]
+fn wrapping_add(x: usize, count: usize, wrap: usize) -> usize {
+    match wrap - x > count {
+        true => x + count,
+        false => count - (wrap - x),
+    }
+}
+
+impl PIterComponent for MpscComp {
+    #[inline(always)]
+    fn middle_iter_idx(&self) -> usize {
+        self.prod_index()
+    }
+
+    #[inline(always)]
+    fn drop_iter(&self) -> u8 {
+        self.alive_iters.fetch_sub(1, Release)
+    }
+
+    #[inline]
+    fn add_iter(&self) {
+        self.alive_iters
+            .fetch_update(Relaxed, Relaxed, |x| x.checked_add(1))
+            .expect("too many iterators alive");
+    }
+
+    #[inline(always)]
+    fn acquire_fence(&self) {
+        #[cfg(not(feature = "thread_sanitiser"))]
+        core::sync::atomic::fence(Acquire);
+
+        // ThreadSanitizer does not support memory fences. To avoid false positive
+        // reports use atomic loads for synchronization instead.
+        #[cfg(feature = "thread_sanitiser")]
+        self.alive_iters.load(Acquire);
+    }
+
+    #[inline]
+    fn prod_index(&self) -> usize {
+        self.prod_idx.load(Acquire)
+    }
+
+    #[inline]
+    fn work_index(&self) -> usize {
+        self.prod_index()
+    }
+
+    #[inline]
+    fn cons_index(&self) -> usize {
+        self.cons_idx.load(Acquire)
+    }
+
+    #[inline]
+    fn set_prod_index(&self, index: usize) {
+        self.prod_idx.store(index, Release);
+    }
+
+    #[inline]
+    fn set_work_index(&self, _index: usize) {}
+
+    #[inline]
+    fn set_cons_index(&self, index: usize) {
+        self.cons_idx.store(index, Release);
+    }
+
+    fn reset(&self) {
+        self.reserve_idx.store(0, Release);
+        self.commit_idx.store(0, Release);
+        self.set_prod_index(0);
+        self.set_cons_index(0);
+    }
+
+    fn alive_iters(&self) -> u8 {
+        self.alive_iters.load(Acquire)
+    }
+}
+
+impl IterComponent for MpscComp {}
+impl SingleConsIterComp for MpscComp {}
+
+impl<'buf, T, const N: usize> OneRingBuf<StackStorage<'buf, T, N>, MpscComp> {
+    /// Returns two iterators: a cloneable Producer and a Consumer.
+    /// <div class="warning">Available only for multi-producer buffers.</div>
+    #[allow(clippy::type_complexity)]
+    pub fn split_mpsc(
+        &'buf mut self,
+    ) -> (
+        MpscProdIter<OneRingBuf<StackStorage<'buf, T, N>, MpscComp>>,
+        ConsIter<OneRingBuf<StackStorage<'buf, T, N>, MpscComp>>,
+    ) {
+        let r = NonDroppableRef::from(self);
+        (MpscProdIter::new(r.clone()), ConsIter::new(r))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> OneRingBuf<HeapStorage<T>, MpscComp> {
+    /// Returns two iterators: a cloneable Producer and a Consumer.
+    /// <div class="warning">Available only for multi-producer buffers.</div>
+    #[allow(clippy::type_complexity)]
+    pub fn split_mpsc(
+        self,
+    ) -> (
+        MpscProdIter<OneRingBuf<HeapStorage<T>, MpscComp>>,
+        ConsIter<OneRingBuf<HeapStorage<T>, MpscComp>>,
+    ) {
+        let r = DroppableRef::from(self);
+        (MpscProdIter::new(r.clone()), ConsIter::new(r))
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "vmem", unix))]
+impl<T> OneRingBuf<VmemStorage<T>, MpscComp> {
+    /// Returns two iterators: a cloneable Producer and a Consumer.
+    /// <div class="warning">Available only for multi-producer buffers.</div>
+    #[allow(clippy::type_complexity)]
+    pub fn split_mpsc(
+        self,
+    ) -> (
+        MpscProdIter<OneRingBuf<VmemStorage<T>, MpscComp>>,
+        ConsIter<OneRingBuf<VmemStorage<T>, MpscComp>>,
+    ) {
+        let r = DroppableRef::from(self);
+        (MpscProdIter::new(r.clone()), ConsIter::new(r))
+    }
+}
@@ -2,11 +2,14 @@ use core::sync::atomic::{AtomicU8, AtomicUsize};
 
 use crossbeam_utils::CachePadded;
 
-use core::sync::atomic::Ordering::{Acquire, Release};
+#[cfg(feature = "stats")]
+use crate::ring_buffer::stats::{StageStats, Stats};
+
+use core::sync::atomic::Ordering::{Acquire, Relaxed, Release};
 
 use crate::{
-    iters_components::MutIterComp,
-    ring_buffer::iters_components::{IterComponent, PIterComponent},
+    iters_components::{MutIterComp, UnsplitIterComp},
+    ring_buffer::iters_components::{IterComponent, PIterComponent, SingleConsIterComp},
 };
 
 /// Mutable iterators component usable in concurrent environments.
@@ -16,9 +19,13 @@ pub struct SharedCompMut {
     pub(crate) cons_idx: CachePadded<AtomicUsize>,
 
     pub(crate) alive_iters: AtomicU8,
+
+    #[cfg(feature = "stats")]
+    pub(crate) stats: StageStats<1>,
 }
 
 impl MutIterComp for SharedCompMut {}
+impl UnsplitIterComp for SharedCompMut {}
 
 impl SharedCompMut {
     pub const fn default() -> Self {
@@ -27,6 +34,8 @@ impl SharedCompMut {
             work_idx: CachePadded::new(AtomicUsize::new(0)),
             cons_idx: CachePadded::new(AtomicUsize::new(0)),
             alive_iters: AtomicU8::new(3),
+            #[cfg(feature = "stats")]
+            stats: StageStats::new(),
         }
     }
 }
@@ -42,6 +51,13 @@ impl PIterComponent for SharedCompMut {
         self.alive_iters.fetch_sub(1, Release)
     }
 
+    #[inline]
+    fn add_iter(&self) {
+        self.alive_iters
+            .fetch_update(Relaxed, Relaxed, |x| x.checked_add(1))
+            .expect("too many iterators alive");
+    }
+
     #[inline(always)]
     fn acquire_fence(&self) {
         #[cfg(not(feature = "thread_sanitiser"))]
@@ -86,6 +102,18 @@ impl PIterComponent for SharedCompMut {
     fn alive_iters(&self) -> u8 {
         self.alive_iters.load(Acquire)
     }
+
+    #[cfg(feature = "stats")]
+    #[inline]
+    fn stats(&self) -> Option<&Stats> {
+        Some(self.stats.as_stats())
+    }
+
+    #[inline(always)]
+    fn stages(&self) -> usize {
+        1
+    }
 }
 
 impl IterComponent for SharedCompMut {}
+impl SingleConsIterComp for SharedCompMut {}
@@ -2,11 +2,14 @@ use core::sync::atomic::{AtomicU8, AtomicUsize};
 
 use crossbeam_utils::CachePadded;
 
-use core::sync::atomic::Ordering::{Acquire, Release};
+#[cfg(feature = "stats")]
+use crate::ring_buffer::stats::{StageStats, Stats};
+
+use core::sync::atomic::Ordering::{Acquire, Relaxed, Release};
 
 use crate::{
-    iters_components::NonMutIterComp,
-    ring_buffer::iters_components::{IterComponent, PIterComponent},
+    iters_components::{NonMutIterComp, UnsplitIterComp},
+    ring_buffer::iters_components::{IterComponent, PIterComponent, SingleConsIterComp},
 };
 
 /// Non-mutable iterators component usable in concurrent environments.
@@ -15,9 +18,13 @@ pub struct SharedComp {
     pub(crate) cons_idx: CachePadded<AtomicUsize>,
 
     pub(crate) alive_iters: AtomicU8,
+
+    #[cfg(feature = "stats")]
+    pub(crate) stats: StageStats<1>,
 }
 
 impl NonMutIterComp for SharedComp {}
+impl UnsplitIterComp for SharedComp {}
 
 impl SharedComp {
     pub const fn default() -> Self {
@@ -25,6 +32,8 @@ impl SharedComp {
             prod_idx: CachePadded::new(AtomicUsize::new(0)),
             cons_idx: CachePadded::new(AtomicUsize::new(0)),
             alive_iters: AtomicU8::new(2),
+            #[cfg(feature = "stats")]
+            stats: StageStats::new(),
         }
     }
 }
@@ -40,6 +49,13 @@ impl PIterComponent for SharedComp {
         self.alive_iters.fetch_sub(1, Release)
     }
 
+    #[inline]
+    fn add_iter(&self) {
+        self.alive_iters
+            .fetch_update(Relaxed, Relaxed, |x| x.checked_add(1))
+            .expect("too many iterators alive");
+    }
+
     #[inline(always)]
     fn acquire_fence(&self) {
         #[cfg(not(feature = "thread_sanitiser"))]
@@ -82,6 +98,13 @@ impl PIterComponent for SharedComp {
     fn alive_iters(&self) -> u8 {
         self.alive_iters.load(Acquire)
     }
+
+    #[cfg(feature = "stats")]
+    #[inline]
+    fn stats(&self) -> Option<&Stats> {
+        Some(self.stats.as_stats())
+    }
 }
 
 impl IterComponent for SharedComp {}
+impl SingleConsIterComp for SharedComp {}
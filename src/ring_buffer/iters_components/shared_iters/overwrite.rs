@@ -0,0 +1,228 @@
+use core::hint::spin_loop;
+use core::sync::atomic::{AtomicU8, AtomicUsize};
+
+use crossbeam_utils::CachePadded;
+
+use core::sync::atomic::Ordering::{Acquire, Relaxed, Release};
+
+#[cfg(all(feature = "alloc", feature = "vmem", unix))]
+use crate::storage_components::VmemStorage;
+use crate::{
+    OneRingBuf,
+    iterators::{LossyConsIter, ProdIter},
+    ring_buffer::{
+        iters_components::{IterComponent, PIterComponent, SingleConsIterComp},
+        wrappers::refs::non_droppable::NonDroppableRef,
+    },
+    storage_components::StackStorage,
+};
+#[cfg(feature = "alloc")]
+use crate::{
+    ring_buffer::wrappers::refs::droppable::DroppableRef, storage_components::HeapStorage,
+};
+
+/// Bit of the consumer index which is set while either the producer or the consumer own it.
+const LOCK: usize = 1 << (usize::BITS - 1);
+
+/// Overwriting iterators component usable in concurrent environments.
+///
+/// When the buffer is full, the producer can move the consumer forward, dropping the oldest items.
+/// The consumer index is briefly locked by the side moving it, so that the producer can never
+/// overwrite items which are being read.
+pub struct OverwriteComp {
+    pub(crate) prod_idx: CachePadded<AtomicUsize>,
+    pub(crate) cons_idx: CachePadded<AtomicUsize>,
+    /// Number of items overwritten since the consumer last checked.
+    pub(crate) lost: CachePadded<AtomicUsize>,
+
+    pub(crate) alive_iters: AtomicU8,
+}
+
+impl OverwriteComp {
+    pub const fn default() -> Self {
+        Self {
+            prod_idx: CachePadded::new(AtomicUsize::new(0)),
+            cons_idx: CachePadded::new(AtomicUsize::new(0)),
+            lost: CachePadded::new(AtomicUsize::new(0)),
+            alive_iters: AtomicU8::new(2),
+        }
+    }
+
+    /// Locks the consumer index, returning its value.
+    #[inline]
+    pub(crate) fn lock_cons(&self) -> usize {
+        loop {
+            let cons = self.cons_idx.load(Relaxed);
+
+            if cons & LOCK == 0
+                && self
+                    .cons_idx
+                    .compare_exchange_weak(cons, cons | LOCK, Acquire, Relaxed)
+                    .is_ok()
+            {
+                return cons;
+            }
+
+            spin_loop();
+        }
+    }
+
+    /// Stores `index` into the consumer index, releasing the lock.
+    #[inline]
+    pub(crate) fn unlock_cons(&self, index: usize) {
+        self.cons_idx.store(index, Release);
+    }
+
+    /// Moves the consumer forward, so that at least `count` slots are free after `prod`.
+    /// Overwritten items are added to the lost ones.
+    #[inline]
+    pub(crate) fn make_room(&self, prod: usize, count: usize, len: usize) {
+        let cons = self.lock_cons();
+
+        let avail = match prod < cons {
+            true => cons - prod - 1,
+            false => len - prod + cons - 1,
+        };
+
+        match avail < count {
+            true => {
+                let need = count - avail;
+                self.lost.fetch_add(need, Relaxed);
+                self.unlock_cons((cons + need) % len);
+            }
+            false => self.unlock_cons(cons),
+        }
+    }
+
+    /// Adds `count` to the lost items.
+    #[inline]
+    pub(crate) fn add_lost(&self, count: usize) {
+        self.lost.fetch_add(count, Relaxed);
+    }
+
+    /// Returns the number of items lost since the last call, resetting it.
+    /// Must be called while holding the lock on the consumer index.
+    #[inline]
+    pub(crate) fn take_lost(&self) -> usize {
+        self.lost.swap(0, Relaxed)
+    }
+}
+
+impl PIterComponent for OverwriteComp {
+    #[inline(always)]
+    fn middle_iter_idx(&self) -> usize {
+        self.prod_index()
+    }
+
+    #[inline(always)]
+    fn drop_iter(&self) -> u8 {
+        self.alive_iters.fetch_sub(1, Release)
+    }
+
+    #[inline]
+    fn add_iter(&self) {
+        self.alive_iters
+            .fetch_update(Relaxed, Relaxed, |x| x.checked_add(1))
+            .expect("too many iterators alive");
+    }
+
+    #[inline(always)]
+    fn acquire_fence(&self) {
+        #[cfg(not(feature = "thread_sanitiser"))]
+        core::sync::atomic::fence(Acquire);
+
+        // ThreadSanitizer does not support memory fences. To avoid false positive
+        // reports use atomic loads for synchronization instead.
+        #[cfg(feature = "thread_sanitiser")]
+        self.alive_iters.load(Acquire);
+    }
+
+    #[inline]
+    fn prod_index(&self) -> usize {
+        self.prod_idx.load(Acquire)
+    }
+
+    #[inline]
+    fn work_index(&self) -> usize {
+        self.prod_index()
+    }
+
+    #[inline]
+    fn cons_index(&self) -> usize {
+        self.cons_idx.load(Acquire) & !LOCK
+    }
+
+    #[inline]
+    fn set_prod_index(&self, index: usize) {
+        self.prod_idx.store(index, Release);
+    }
+
+    #[inline]
+    fn set_work_index(&self, _index: usize) {}
+
+    #[inline]
+    fn set_cons_index(&self, index: usize) {
+        self.lock_cons();
+        self.unlock_cons(index);
+    }
+
+    fn reset(&self) {
+        self.set_prod_index(0);
+        self.set_cons_index(0);
+        self.take_lost();
+    }
+
+    fn alive_iters(&self) -> u8 {
+        self.alive_iters.load(Acquire)
+    }
+}
+
+impl IterComponent for OverwriteComp {}
+impl SingleConsIterComp for OverwriteComp {}
+
+impl<'buf, T, const N: usize> OneRingBuf<StackStorage<'buf, T, N>, OverwriteComp> {
+    /// Returns two iterators: a Producer and a lossy Consumer.
+    /// <div class="warning">Available only for overwriting buffers.</div>
+    #[allow(clippy::type_complexity)]
+    pub fn split_overwrite(
+        &'buf mut self,
+    ) -> (
+        ProdIter<OneRingBuf<StackStorage<'buf, T, N>, OverwriteComp>>,
+        LossyConsIter<OneRingBuf<StackStorage<'buf, T, N>, OverwriteComp>>,
+    ) {
+        let r = NonDroppableRef::from(self);
+        (ProdIter::new(r.clone()), LossyConsIter::new(r))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> OneRingBuf<HeapStorage<T>, OverwriteComp> {
+    /// Returns two iterators: a Producer and a lossy Consumer.
+    /// <div class="warning">Available only for overwriting buffers.</div>
+    #[allow(clippy::type_complexity)]
+    pub fn split_overwrite(
+        self,
+    ) -> (
+        ProdIter<OneRingBuf<HeapStorage<T>, OverwriteComp>>,
+        LossyConsIter<OneRingBuf<HeapStorage<T>, OverwriteComp>>,
+    ) {
+        let r = DroppableRef::from(self);
+        (ProdIter::new(r.clone()), LossyConsIter::new(r))
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "vmem", unix))]
+impl<T> OneRingBuf<VmemStorage<T>, OverwriteComp> {
+    /// Returns two iterators: a Producer and a lossy Consumer.
+    /// <div class="warning">Available only for overwriting buffers.</div>
+    #[allow(clippy::type_complexity)]
+    pub fn split_overwrite(
+        self,
+    ) -> (
+        ProdIter<OneRingBuf<VmemStorage<T>, OverwriteComp>>,
+        LossyConsIter<OneRingBuf<VmemStorage<T>, OverwriteComp>>,
+    ) {
+        let r = DroppableRef::from(self);
+        (ProdIter::new(r.clone()), LossyConsIter::new(r))
+    }
+}
@@ -0,0 +1,160 @@
+use core::sync::atomic::{AtomicU8, AtomicUsize};
+
+use crossbeam_utils::CachePadded;
+
+use core::sync::atomic::Ordering::{Acquire, Relaxed, Release};
+
+#[cfg(all(feature = "alloc", feature = "vmem", unix))]
+use crate::storage_components::VmemStorage;
+use crate::{
+    OneRingBuf,
+    iterators::{OwnedConsIter, OwnedProdIter},
+    ring_buffer::{
+        iters_components::{IterComponent, PIterComponent, SingleConsIterComp},
+        wrappers::refs::non_droppable::NonDroppableRef,
+    },
+    storage_components::StackStorage,
+};
+#[cfg(feature = "alloc")]
+use crate::{
+    ring_buffer::wrappers::refs::droppable::DroppableRef, storage_components::HeapStorage,
+};
+
+/// Owned-queue iterators component usable in concurrent environments.
+///
+/// Only the items between the consumer and the producer are initialised: the producer always
+/// moves items into uninitialised locations, the consumer always moves them out, and the buffer
+/// drops the remaining ones when dropped.
+pub struct OwnedComp {
+    pub(crate) prod_idx: CachePadded<AtomicUsize>,
+    pub(crate) cons_idx: CachePadded<AtomicUsize>,
+
+    pub(crate) alive_iters: AtomicU8,
+}
+
+impl OwnedComp {
+    pub const fn default() -> Self {
+        Self {
+            prod_idx: CachePadded::new(AtomicUsize::new(0)),
+            cons_idx: CachePadded::new(AtomicUsize::new(0)),
+            alive_iters: AtomicU8::new(2),
+        }
+    }
+}
+
+impl PIterComponent for OwnedComp {
+    #[inline(always)]
+    fn middle_iter_idx(&self) -> usize {
+        self.prod_index()
+    }
+
+    #[inline(always)]
+    fn drop_iter(&self) -> u8 {
+        self.alive_iters.fetch_sub(1, Release)
+    }
+
+    #[inline]
+    fn add_iter(&self) {
+        self.alive_iters
+            .fetch_update(Relaxed, Relaxed, |x| x.checked_add(1))
+            .expect("too many iterators alive");
+    }
+
+    #[inline(always)]
+    fn acquire_fence(&self) {
+        #[cfg(not(feature = "thread_sanitiser"))]
+        core::sync::atomic::fence(Acquire);
+
+        // ThreadSanitizer does not support memory fences. To avoid false positive
+        // reports use atomic loads for synchronization instead.
+        #[cfg(feature = "thread_sanitiser")]
+        self.alive_iters.load(Acquire);
+    }
+
+    #[inline]
+    fn prod_index(&self) -> usize {
+        self.prod_idx.load(Acquire)
+    }
+
+    #[inline]
+    fn work_index(&self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn cons_index(&self) -> usize {
+        self.cons_idx.load(Acquire)
+    }
+
+    #[inline]
+    fn set_prod_index(&self, index: usize) {
+        self.prod_idx.store(index, Release);
+    }
+
+    #[inline]
+    fn set_work_index(&self, _index: usize) {}
+
+    #[inline]
+    fn set_cons_index(&self, index: usize) {
+        self.cons_idx.store(index, Release);
+    }
+
+    fn alive_iters(&self) -> u8 {
+        self.alive_iters.load(Acquire)
+    }
+
+    #[inline]
+    fn owns_items(&self) -> bool {
+        true
+    }
+}
+
+impl IterComponent for OwnedComp {}
+impl SingleConsIterComp for OwnedComp {}
+
+impl<'buf, T, const N: usize> OneRingBuf<StackStorage<'buf, T, N>, OwnedComp> {
+    /// Returns two iterators: a Producer and a Consumer, both moving items.
+    /// <div class="warning">Available only for owned buffers.</div>
+    #[allow(clippy::type_complexity)]
+    pub fn split_owned(
+        &'buf mut self,
+    ) -> (
+        OwnedProdIter<OneRingBuf<StackStorage<'buf, T, N>, OwnedComp>>,
+        OwnedConsIter<OneRingBuf<StackStorage<'buf, T, N>, OwnedComp>>,
+    ) {
+        let r = NonDroppableRef::from(self);
+        (OwnedProdIter::new(r.clone()), OwnedConsIter::new(r))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> OneRingBuf<HeapStorage<T>, OwnedComp> {
+    /// Returns two iterators: a Producer and a Consumer, both moving items.
+    /// <div class="warning">Available only for owned buffers.</div>
+    #[allow(clippy::type_complexity)]
+    pub fn split_owned(
+        self,
+    ) -> (
+        OwnedProdIter<OneRingBuf<HeapStorage<T>, OwnedComp>>,
+        OwnedConsIter<OneRingBuf<HeapStorage<T>, OwnedComp>>,
+    ) {
+        let r = DroppableRef::from(self);
+        (OwnedProdIter::new(r.clone()), OwnedConsIter::new(r))
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "vmem", unix))]
+impl<T> OneRingBuf<VmemStorage<T>, OwnedComp> {
+    /// Returns two iterators: a Producer and a Consumer, both moving items.
+    /// <div class="warning">Available only for owned buffers.</div>
+    #[allow(clippy::type_complexity)]
+    pub fn split_owned(
+        self,
+    ) -> (
+        OwnedProdIter<OneRingBuf<VmemStorage<T>, OwnedComp>>,
+        OwnedConsIter<OneRingBuf<VmemStorage<T>, OwnedComp>>,
+    ) {
+        let r = DroppableRef::from(self);
+        (OwnedProdIter::new(r.clone()), OwnedConsIter::new(r))
+    }
+}
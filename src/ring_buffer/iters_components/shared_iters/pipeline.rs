@@ -0,0 +1,132 @@
+use core::sync::atomic::{AtomicU8, AtomicUsize};
+
+use crossbeam_utils::CachePadded;
+
+#[cfg(feature = "stats")]
+use crate::ring_buffer::stats::{StageStats, Stats};
+
+use core::sync::atomic::Ordering::{Acquire, Relaxed, Release};
+
+use crate::{
+    iters_components::PipelineIterComp,
+    ring_buffer::iters_components::{IterComponent, PIterComponent, SingleConsIterComp},
+};
+
+/// Pipeline iterators component usable in concurrent environments.
+///
+/// Holds `K` worker stages, each one following the previous one.
+pub struct SharedCompPipeline<const K: usize> {
+    pub(crate) prod_idx: CachePadded<AtomicUsize>,
+    pub(crate) work_idx: [CachePadded<AtomicUsize>; K],
+    pub(crate) cons_idx: CachePadded<AtomicUsize>,
+
+    pub(crate) alive_iters: AtomicU8,
+
+    #[cfg(feature = "stats")]
+    pub(crate) stats: StageStats<K>,
+}
+
+impl<const K: usize> PipelineIterComp<K> for SharedCompPipeline<K> {}
+
+impl<const K: usize> SharedCompPipeline<K> {
+    pub const fn default() -> Self {
+        assert!(K > 0 && K <= u8::MAX as usize - 2);
+
+        Self {
+            prod_idx: CachePadded::new(AtomicUsize::new(0)),
+            work_idx: [const { CachePadded::new(AtomicUsize::new(0)) }; K],
+            cons_idx: CachePadded::new(AtomicUsize::new(0)),
+            alive_iters: AtomicU8::new(K as u8 + 2),
+            #[cfg(feature = "stats")]
+            stats: StageStats::new(),
+        }
+    }
+}
+
+impl<const K: usize> PIterComponent for SharedCompPipeline<K> {
+    #[inline(always)]
+    fn middle_iter_idx(&self) -> usize {
+        self.stage_index(K - 1)
+    }
+
+    #[inline(always)]
+    fn drop_iter(&self) -> u8 {
+        self.alive_iters.fetch_sub(1, Release)
+    }
+
+    #[inline]
+    fn add_iter(&self) {
+        self.alive_iters
+            .fetch_update(Relaxed, Relaxed, |x| x.checked_add(1))
+            .expect("too many iterators alive");
+    }
+
+    #[inline(always)]
+    fn acquire_fence(&self) {
+        #[cfg(not(feature = "thread_sanitiser"))]
+        core::sync::atomic::fence(Acquire);
+
+        // ThreadSanitizer does not support memory fences. To avoid false positive
+        // reports use atomic loads for synchronization instead.
+        #[cfg(feature = "thread_sanitiser")]
+        self.alive_iters.load(Acquire);
+    }
+
+    #[inline]
+    fn prod_index(&self) -> usize {
+        self.prod_idx.load(Acquire)
+    }
+
+    #[inline]
+    fn work_index(&self) -> usize {
+        self.stage_index(0)
+    }
+
+    #[inline]
+    fn cons_index(&self) -> usize {
+        self.cons_idx.load(Acquire)
+    }
+
+    #[inline]
+    fn set_prod_index(&self, index: usize) {
+        self.prod_idx.store(index, Release);
+    }
+
+    #[inline]
+    fn set_work_index(&self, index: usize) {
+        self.set_stage_index(0, index);
+    }
+
+    #[inline]
+    fn set_cons_index(&self, index: usize) {
+        self.cons_idx.store(index, Release);
+    }
+
+    fn alive_iters(&self) -> u8 {
+        self.alive_iters.load(Acquire)
+    }
+
+    #[cfg(feature = "stats")]
+    #[inline]
+    fn stats(&self) -> Option<&Stats> {
+        Some(self.stats.as_stats())
+    }
+
+    #[inline(always)]
+    fn stages(&self) -> usize {
+        K
+    }
+
+    #[inline]
+    fn stage_index(&self, stage: usize) -> usize {
+        self.work_idx[stage].load(Acquire)
+    }
+
+    #[inline]
+    fn set_stage_index(&self, stage: usize, index: usize) {
+        self.work_idx[stage].store(index, Release);
+    }
+}
+
+impl<const K: usize> IterComponent for SharedCompPipeline<K> {}
+impl<const K: usize> SingleConsIterComp for SharedCompPipeline<K> {}
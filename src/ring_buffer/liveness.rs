@@ -0,0 +1,126 @@
+use core::sync::atomic::{
+    AtomicU8, AtomicU64,
+    Ordering::{AcqRel, Acquire, Relaxed, Release},
+};
+
+use crate::ring_buffer::iters_components::PIterComponent;
+
+/// Number of words used by each bitset: enough for every worker stage and consumer reader,
+/// as a buffer cannot hold more than `u8::MAX` iterators.
+const WORDS: usize = 4;
+
+/// Keeps track of which iterators have been dropped. Every buffer holds one of these.
+///
+/// The count of alive iterators held by each component cannot be used for this: it also counts
+/// observers and cloned producers, so it only tells how many references are left, not which roles.
+/// Each iterator updates its own role here when dropped, right before releasing its reference.
+pub(crate) struct Liveness {
+    /// Number of producers still alive. Multi-producer buffers can have more than one.
+    prods: AtomicU8,
+    /// Bitset of dropped worker stages.
+    stages: [AtomicU64; WORDS],
+    /// Bitset of dropped consumer readers.
+    readers: [AtomicU64; WORDS],
+}
+
+#[inline(always)]
+fn set(bits: &[AtomicU64; WORDS], i: usize) {
+    bits[i / 64].fetch_or(1 << (i % 64), Release);
+}
+
+#[inline(always)]
+fn get(bits: &[AtomicU64; WORDS], i: usize) -> bool {
+    bits[i / 64].load(Acquire) & (1 << (i % 64)) != 0
+}
+
+#[inline(always)]
+fn any(bits: &[AtomicU64; WORDS]) -> bool {
+    bits.iter().any(|w| w.load(Acquire) != 0)
+}
+
+impl Liveness {
+    pub(crate) const fn new() -> Self {
+        Self {
+            prods: AtomicU8::new(1),
+            stages: [const { AtomicU64::new(0) }; WORDS],
+            readers: [const { AtomicU64::new(0) }; WORDS],
+        }
+    }
+
+    /// Registers a new producer.
+    #[inline]
+    pub(crate) fn add_prod(&self) {
+        self.prods.fetch_add(1, Relaxed);
+    }
+
+    /// Marks a producer as dropped.
+    #[inline]
+    pub(crate) fn drop_prod(&self) {
+        self.prods.fetch_sub(1, AcqRel);
+    }
+
+    /// Marks the worker at `stage` as dropped.
+    #[inline]
+    pub(crate) fn drop_stage(&self, stage: usize) {
+        set(&self.stages, stage);
+    }
+
+    /// Marks the consumer `reader` as dropped.
+    #[inline]
+    pub(crate) fn drop_reader(&self, reader: usize) {
+        set(&self.readers, reader);
+    }
+
+    /// Returns `true` if at least one producer is alive.
+    #[inline]
+    pub(crate) fn prod_alive(&self) -> bool {
+        self.prods.load(Acquire) != 0
+    }
+
+    /// Returns `true` if the worker at `stage` is alive.
+    #[inline]
+    pub(crate) fn stage_alive(&self, stage: usize) -> bool {
+        !get(&self.stages, stage)
+    }
+
+    /// Returns `true` if the consumer `reader` is alive.
+    #[inline]
+    pub(crate) fn reader_alive(&self, reader: usize) -> bool {
+        !get(&self.readers, reader)
+    }
+
+    /// Returns `true` if the items pushed by the producer will never be consumed, i.e. if any worker
+    /// has been dropped, or if every consumer has.
+    #[inline]
+    pub(crate) fn downstream_ended(&self, iters: &impl PIterComponent) -> bool {
+        any(&self.stages) || (0..iters.readers()).all(|r| !self.reader_alive(r))
+    }
+
+    /// Returns the index of the slowest consumer still alive, which is the one the producer follows.
+    /// If every consumer has been dropped, the slowest one is returned.
+    #[inline]
+    pub(crate) fn cons_index(&self, iters: &impl PIterComponent) -> usize {
+        iters.slowest_cons_index(|r| self.reader_alive(r))
+    }
+
+    /// Walks the chain formed by the producer and the first `stages` workers.
+    ///
+    /// Returns whether the last of them will never move again, together with its index.
+    /// An iterator never moves again if it has been dropped, or if it has caught up with
+    /// its predecessor, which, in turn, will never move again.
+    #[inline]
+    pub(crate) fn chain_end(&self, iters: &impl PIterComponent, stages: usize) -> (bool, usize) {
+        let mut ended = !self.prod_alive();
+        let mut index = iters.prod_index();
+
+        for s in 0..stages {
+            let dropped = !self.stage_alive(s);
+            let s_index = iters.stage_index(s);
+
+            ended = dropped || (ended && s_index == index);
+            index = s_index;
+        }
+
+        (ended, index)
+    }
+}
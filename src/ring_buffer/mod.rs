@@ -1,16 +1,36 @@
 use core::cell::UnsafeCell;
 
 #[cfg(feature = "async")]
-use crate::iters_components::async_iters::{mutable::AsyncCompMut, non_mutable::AsyncComp};
+use crate::iters_components::async_iters::{
+    mutable::AsyncCompMut, non_mutable::AsyncComp, pipeline::AsyncCompPipeline,
+};
+#[cfg(doc)]
+use crate::{
+    ORBIterator,
+    iterators::{ConsIter, ProdIter},
+};
 use crate::{
-    iters_components::shared_iters::{mutable::SharedCompMut, non_mutable::SharedComp},
-    ring_buffer::{iters_components::IterComponent, storage_components::PStorageComponent},
+    iters_components::shared_iters::{
+        broadcast::BroadcastComp, mpsc::MpscComp, mutable::SharedCompMut, non_mutable::SharedComp,
+        overwrite::OverwriteComp, owned::OwnedComp, pipeline::SharedCompPipeline,
+    },
+    ring_buffer::{
+        iters_components::{IterComponent, NonMutIterComp, PIterComponent},
+        liveness::Liveness,
+        storage_components::PStorageComponent,
+        wait::{WaitStrategy, Waiter},
+    },
 };
 
 pub mod impls;
 pub mod iters_components;
+pub(crate) mod liveness;
+#[cfg(feature = "stats")]
+pub mod stats;
 pub mod storage_components;
 pub mod types;
+pub(crate) mod uninit;
+pub mod wait;
 pub mod wrappers;
 
 /// Trait implemented by concurrent ring buffer.
@@ -18,10 +38,17 @@ pub(crate) trait SharedRB {}
 
 impl<S: PStorageComponent> SharedRB for OneRingBuf<S, SharedComp> {}
 impl<S: PStorageComponent> SharedRB for OneRingBuf<S, SharedCompMut> {}
+impl<S: PStorageComponent, const K: usize> SharedRB for OneRingBuf<S, SharedCompPipeline<K>> {}
+impl<S: PStorageComponent> SharedRB for OneRingBuf<S, MpscComp> {}
+impl<S: PStorageComponent> SharedRB for OneRingBuf<S, OverwriteComp> {}
+impl<S: PStorageComponent> SharedRB for OneRingBuf<S, OwnedComp> {}
+impl<S: PStorageComponent, const C: usize> SharedRB for OneRingBuf<S, BroadcastComp<C>> {}
 #[cfg(feature = "async")]
 impl<S: PStorageComponent> SharedRB for OneRingBuf<S, AsyncComp> {}
 #[cfg(feature = "async")]
 impl<S: PStorageComponent> SharedRB for OneRingBuf<S, AsyncCompMut> {}
+#[cfg(feature = "async")]
+impl<S: PStorageComponent, const K: usize> SharedRB for OneRingBuf<S, AsyncCompPipeline<K>> {}
 
 /// Trait implemented by ring buffers.
 ///
@@ -44,6 +71,42 @@ pub trait OneRB {
     fn storage_mut(&self) -> &mut Self::Storage;
     /// Returns the length of the buffer.
     fn len(&self) -> usize;
+    /// Returns the waiter used by blocking methods.
+    #[doc(hidden)]
+    fn waiter(&self) -> &Waiter;
+    /// Returns the tracker of dropped iterators.
+    #[doc(hidden)]
+    fn liveness(&self) -> &Liveness;
+    /// Returns the number of consumed items kept behind the consumer.
+    #[doc(hidden)]
+    fn lookback(&self) -> usize;
+}
+
+/// Drops the items owned by `buffer`, if any. Called when the last iterator is dropped.
+///
+/// Only the items between the consumer and the producer are alive: they are dropped, then every
+/// location is zeroed, so that the cells do not drop anything else.
+pub(crate) fn drop_owned_items<B: OneRB>(buffer: &B) {
+    if !buffer.iters().owns_items() {
+        return;
+    }
+
+    let storage = buffer.storage();
+    let len = buffer.len();
+    let (mut idx, prod) = (buffer.iters().cons_index(), buffer.iters().prod_index());
+
+    while idx != prod {
+        unsafe { storage._index(idx).as_mut_ptr().drop_in_place() };
+
+        idx += 1;
+        if idx == len {
+            idx = 0;
+        }
+    }
+
+    for i in 0..len {
+        unsafe { storage._index(i).as_mut_ptr().write_bytes(0, 1) };
+    }
 }
 
 /// The One Ring aka the main struct of this crate.
@@ -51,6 +114,9 @@ pub trait OneRB {
 pub struct OneRingBuf<S: PStorageComponent, I: IterComponent> {
     pub(crate) inner: UnsafeCell<S>,
     pub(crate) iters: I,
+    pub(crate) waiter: Waiter,
+    pub(crate) liveness: Liveness,
+    pub(crate) lookback: usize,
 }
 
 impl<S: PStorageComponent, I: IterComponent> OneRB for OneRingBuf<S, I> {
@@ -77,6 +143,21 @@ impl<S: PStorageComponent, I: IterComponent> OneRB for OneRingBuf<S, I> {
     fn len(&self) -> usize {
         self.storage().len()
     }
+
+    #[inline]
+    fn waiter(&self) -> &Waiter {
+        &self.waiter
+    }
+
+    #[inline]
+    fn liveness(&self) -> &Liveness {
+        &self.liveness
+    }
+
+    #[inline]
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
 }
 
 impl<S: PStorageComponent, I: IterComponent> OneRingBuf<S, I> {
@@ -84,6 +165,69 @@ impl<S: PStorageComponent, I: IterComponent> OneRingBuf<S, I> {
         Self {
             inner: UnsafeCell::new(value),
             iters: iters,
+            waiter: Waiter::new(WaitStrategy::Spin),
+            liveness: Liveness::new(),
+            lookback: 0,
+        }
+    }
+
+    /// Sets the [`WaitStrategy`] used by blocking methods, such as [`ORBIterator::wait_for`],
+    /// [`ProdIter::push_blocking`] or [`ConsIter::pop_blocking`].
+    ///
+    /// The default strategy is [`WaitStrategy::Spin`].
+    pub const fn with_wait_strategy(mut self, strategy: WaitStrategy) -> Self {
+        self.waiter = Waiter::new(strategy);
+        self
+    }
+
+    /// Returns the [`WaitStrategy`] used by this buffer.
+    pub fn wait_strategy(&self) -> WaitStrategy {
+        self.waiter.strategy()
+    }
+
+    /// Enables the latency histogram of [`Stats`](crate::Stats), recording the time each item
+    /// spends in the buffer, from the producer to the consumer.
+    ///
+    /// Every push and pop then reads the clock, so it should be enabled only while diagnosing.
+    /// Buffers which do not record statistics are left untouched.
+    #[cfg(all(feature = "stats", feature = "std"))]
+    pub fn with_latency_stats(self) -> Self {
+        if let Some(stats) = self.iters.stats() {
+            stats.enable_latency(self.len());
         }
+        self
+    }
+
+    /// Moves every index back to the start of the buffer, so that it looks empty.
+    ///
+    /// Items left in owned buffers are dropped, while the others stay in place, to be overwritten.
+    /// Useful to reuse a buffer recovered with `unsplit`.
+    pub fn reset(&mut self) {
+        drop_owned_items(self);
+        self.iters.reset();
+        self.storage().set_wrap_mark(None);
+    }
+}
+
+impl<S: PStorageComponent, I: NonMutIterComp> OneRingBuf<S, I> {
+    /// Keeps the last `count` consumed items in place, so that the consumer can look back at them
+    /// with [`ConsIter::history`].
+    ///
+    /// The producer never overwrites them, so the buffer holds up to `count` fewer new items.
+    /// Until `count` items have been consumed, the history also holds the items the buffer was created with.
+    /// <div class="warning">Available only for non-mutable buffers.</div>
+    ///
+    /// Such buffers cannot be written with [`ProdIter::reserve`](crate::iterators::ProdIter::reserve).
+    ///
+    /// # Panics
+    /// Panics if `count` does not leave room for at least one new item.
+    pub fn with_lookback(mut self, count: usize) -> Self {
+        assert!(
+            count < self.len() - 1,
+            "lookback must leave room for at least one item"
+        );
+
+        self.lookback = count;
+        self
     }
 }
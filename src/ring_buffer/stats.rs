@@ -0,0 +1,259 @@
+//! Runtime statistics recorded by concurrent buffers.
+
+use core::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+
+use crossbeam_utils::CachePadded;
+
+#[cfg(feature = "std")]
+use alloc::boxed::Box;
+#[cfg(feature = "std")]
+use core::sync::atomic::AtomicU64;
+#[cfg(feature = "std")]
+use std::{sync::OnceLock, time::Instant};
+
+/// Number of buckets of the latency histogram returned by [`Stats::latency_histogram`].
+#[cfg(feature = "std")]
+pub const LATENCY_BUCKETS: usize = u64::BITS as usize + 1;
+
+/// Counters updated by a single iterator, kept on their own cache line.
+pub(crate) struct Counters {
+    /// Number of items moved.
+    moved: AtomicUsize,
+    /// Number of failed attempts to move.
+    failed: AtomicUsize,
+    /// Highest fill level observed.
+    max_fill: AtomicUsize,
+}
+
+impl Counters {
+    const fn new() -> Self {
+        Self {
+            moved: AtomicUsize::new(0),
+            failed: AtomicUsize::new(0),
+            max_fill: AtomicUsize::new(0),
+        }
+    }
+
+    fn reset(&self) {
+        self.moved.store(0, Relaxed);
+        self.failed.store(0, Relaxed);
+        self.max_fill.store(0, Relaxed);
+    }
+}
+
+/// Enqueue timestamps of the items, together with the histogram of their latencies.
+#[cfg(feature = "std")]
+struct Latency {
+    origin: OnceLock<Instant>,
+    /// Time at which each location was written, in nanoseconds since `origin`.
+    stamps: OnceLock<Box<[AtomicU64]>>,
+    buckets: [AtomicUsize; LATENCY_BUCKETS],
+}
+
+#[cfg(feature = "std")]
+impl Latency {
+    const fn new() -> Self {
+        Self {
+            origin: OnceLock::new(),
+            stamps: OnceLock::new(),
+            buckets: [const { AtomicUsize::new(0) }; LATENCY_BUCKETS],
+        }
+    }
+
+    fn enable(&self, len: usize) {
+        self.origin.get_or_init(Instant::now);
+        self.stamps
+            .get_or_init(|| (0..len).map(|_| AtomicU64::new(0)).collect());
+    }
+
+    /// Returns the time elapsed since `origin`, along with the timestamps, if enabled.
+    #[inline]
+    fn now(&self) -> Option<(u64, &[AtomicU64])> {
+        let stamps = self.stamps.get()?;
+        let now = self.origin.get()?.elapsed().as_nanos() as u64;
+
+        Some((now, stamps))
+    }
+
+    /// Calls `f` with the timestamp of each location in `[from, from + count)`, wrapping around.
+    #[inline]
+    fn for_each(stamps: &[AtomicU64], from: usize, count: usize, mut f: impl FnMut(&AtomicU64)) {
+        let len = stamps.len();
+        let head = count.min(len - from);
+
+        stamps[from..from + head].iter().for_each(&mut f);
+        stamps[..count - head].iter().for_each(f);
+    }
+
+    fn stamp(&self, from: usize, count: usize) {
+        if let Some((now, stamps)) = self.now() {
+            Self::for_each(stamps, from, count, |s| s.store(now, Relaxed));
+        }
+    }
+
+    fn measure(&self, from: usize, count: usize) {
+        if let Some((now, stamps)) = self.now() {
+            Self::for_each(stamps, from, count, |s| {
+                let ns = now.saturating_sub(s.load(Relaxed));
+                let bucket = (u64::BITS - ns.leading_zeros()) as usize;
+
+                self.buckets[bucket].fetch_add(1, Relaxed);
+            });
+        }
+    }
+}
+
+/// Counters of a buffer, generic over the ones of its workers, so that pipeline components can
+/// hold one for each of their `K` stages while still being viewed as [`Stats`].
+pub(crate) struct RawStats<W: ?Sized> {
+    prod: CachePadded<Counters>,
+    cons: CachePadded<Counters>,
+    #[cfg(feature = "std")]
+    latency: Latency,
+    work: W,
+}
+
+/// Statistics held by a component with `K` worker stages.
+pub(crate) type StageStats<const K: usize> = RawStats<[CachePadded<Counters>; K]>;
+
+impl<const K: usize> StageStats<K> {
+    pub(crate) const fn new() -> Self {
+        Self {
+            prod: CachePadded::new(Counters::new()),
+            cons: CachePadded::new(Counters::new()),
+            #[cfg(feature = "std")]
+            latency: Latency::new(),
+            work: [const { CachePadded::new(Counters::new()) }; K],
+        }
+    }
+
+    #[inline]
+    pub(crate) fn as_stats(&self) -> &Stats {
+        let raw: &RawStats<[CachePadded<Counters>]> = self;
+
+        // SAFETY: `Stats` is a transparent wrapper around `RawStats<[CachePadded<Counters>]>`.
+        unsafe { &*(raw as *const RawStats<[CachePadded<Counters>]> as *const Stats) }
+    }
+}
+
+/// Statistics recorded by concurrent buffers, available under the `stats` feature.
+///
+/// Every iterator updates its own counters, so that iterators running on different threads do
+/// not contend with each other. Counters are updated with relaxed ordering: they are meant for
+/// diagnostics, and may be slightly stale when read while iterators move.
+///
+/// Statistics can be obtained from any iterator with [`ORBIterator::stats`](crate::ORBIterator::stats),
+/// or from an [`Observer`](crate::iterators::Observer).
+#[repr(transparent)]
+pub struct Stats(RawStats<[CachePadded<Counters>]>);
+
+impl Stats {
+    /// Returns the highest number of items the buffer has held at once.
+    #[inline]
+    pub fn max_fill(&self) -> usize {
+        self.0.prod.max_fill.load(Relaxed)
+    }
+
+    /// Returns the number of failed pushes, i.e. the ones which found the buffer full.
+    #[inline]
+    pub fn overruns(&self) -> usize {
+        self.0.prod.failed.load(Relaxed)
+    }
+
+    /// Returns the number of failed pops, i.e. the ones which found the buffer empty.
+    #[inline]
+    pub fn underruns(&self) -> usize {
+        self.0.cons.failed.load(Relaxed)
+    }
+
+    /// Returns the number of items pushed by the producer.
+    #[inline]
+    pub fn pushed(&self) -> usize {
+        self.0.prod.moved.load(Relaxed)
+    }
+
+    /// Returns the number of items processed by the worker.
+    /// For pipeline buffers, this is the same as `Self::stage_worked(0)`.
+    #[inline]
+    pub fn worked(&self) -> usize {
+        self.0.work[0].moved.load(Relaxed)
+    }
+
+    /// Returns the number of items processed by the worker at `stage`,
+    /// or `None` if the buffer has no such stage.
+    #[inline]
+    pub fn stage_worked(&self, stage: usize) -> Option<usize> {
+        self.0.work.get(stage).map(|w| w.moved.load(Relaxed))
+    }
+
+    /// Returns the number of items consumed by the consumer.
+    #[inline]
+    pub fn popped(&self) -> usize {
+        self.0.cons.moved.load(Relaxed)
+    }
+
+    /// Returns the histogram of the time elapsed between pushing and consuming each item.
+    ///
+    /// The bucket at index `i` counts the items which spent between `2^(i-1)` (included) and `2^i`
+    /// (excluded) nanoseconds in the buffer, while the first bucket counts the ones which spent none.
+    /// Latencies are recorded only once enabled with
+    /// [`OneRingBuf::with_latency_stats`](crate::OneRingBuf::with_latency_stats).
+    #[cfg(feature = "std")]
+    pub fn latency_histogram(&self) -> [usize; LATENCY_BUCKETS] {
+        core::array::from_fn(|i| self.0.latency.buckets[i].load(Relaxed))
+    }
+
+    /// Sets every counter back to zero.
+    pub fn reset(&self) {
+        self.0.prod.reset();
+        self.0.work.iter().for_each(|w| w.reset());
+        self.0.cons.reset();
+
+        #[cfg(feature = "std")]
+        for bucket in &self.0.latency.buckets {
+            bucket.store(0, Relaxed);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    pub(crate) fn enable_latency(&self, len: usize) {
+        self.0.latency.enable(len);
+    }
+
+    /// Records `count` items pushed from `from`, leaving `fill` items in the buffer.
+    #[inline]
+    pub(crate) fn record_push(&self, _from: usize, count: usize, fill: usize) {
+        self.0.prod.moved.fetch_add(count, Relaxed);
+        self.0.prod.max_fill.fetch_max(fill, Relaxed);
+
+        #[cfg(feature = "std")]
+        self.0.latency.stamp(_from, count);
+    }
+
+    /// Records `count` items processed by the worker at `stage`.
+    #[inline]
+    pub(crate) fn record_work(&self, stage: usize, count: usize) {
+        self.0.work[stage].moved.fetch_add(count, Relaxed);
+    }
+
+    /// Records `count` items consumed from `from`.
+    #[inline]
+    pub(crate) fn record_pop(&self, _from: usize, count: usize) {
+        self.0.cons.moved.fetch_add(count, Relaxed);
+
+        #[cfg(feature = "std")]
+        self.0.latency.measure(_from, count);
+    }
+
+    /// Records a failed push.
+    #[inline]
+    pub(crate) fn record_overrun(&self) {
+        self.0.prod.failed.fetch_add(1, Relaxed);
+    }
+
+    /// Records a failed pop.
+    #[inline]
+    pub(crate) fn record_underrun(&self) {
+        self.0.cons.failed.fetch_add(1, Relaxed);
+    }
+}
@@ -4,7 +4,7 @@ use core::mem::transmute;
 use core::ops::Index;
 use core::slice;
 
-use crate::ring_buffer::storage_components::{PStorageComponent, StorageComponent};
+use crate::ring_buffer::storage_components::{PStorageComponent, StorageComponent, WrapMark};
 use crate::utils::UnsafeSyncCell;
 use alloc::boxed::Box;
 use alloc::vec::Vec;
@@ -13,6 +13,7 @@ use alloc::vec::Vec;
 pub struct HeapStorage<T> {
     inner: *mut UnsafeSyncCell<T>,
     len: usize,
+    wrap_mark: WrapMark,
 }
 
 impl<T> Drop for HeapStorage<T> {
@@ -33,6 +34,7 @@ impl<T> HeapStorage<T> {
             Self {
                 inner: (*v).as_mut_ptr(),
                 len,
+                wrap_mark: WrapMark::new(),
             }
         }
     }
@@ -151,6 +153,11 @@ impl<T> PStorageComponent for HeapStorage<T> {
         }
     }
 
+    #[inline]
+    fn next_block(&self, index: usize, count: usize) -> Option<*mut T> {
+        (index + count <= self.len).then(|| unsafe { self.inner.add(index) as *mut T })
+    }
+
     #[inline]
     fn _push_slice(
         &mut self,
@@ -192,6 +199,16 @@ impl<T> PStorageComponent for HeapStorage<T> {
             }
         }
     }
+
+    #[inline]
+    fn wrap_mark(&self) -> Option<usize> {
+        self.wrap_mark.get()
+    }
+
+    #[inline]
+    fn set_wrap_mark(&self, mark: Option<usize>) {
+        self.wrap_mark.set(mark);
+    }
 }
 
 pub mod test {
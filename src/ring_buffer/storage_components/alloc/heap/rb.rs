@@ -1,21 +1,36 @@
 #[cfg(any(feature = "async", doc))]
 use crate::AsyncHeapRB;
+use crate::BroadcastHeapRB;
 use crate::LocalHeapRB;
+use crate::MpscHeapRB;
+use crate::OverwriteHeapRB;
+use crate::OwnedHeapRB;
 use crate::SharedHeapRB;
-#[allow(unused_imports)]
-use crate::iterators::ProdIter;
+use crate::UninitRB;
 #[cfg(any(feature = "async", doc))]
 use crate::iters_components::AsyncComp;
 #[cfg(any(feature = "async", doc))]
 use crate::iters_components::AsyncCompMut;
+#[cfg(any(feature = "async", doc))]
+use crate::iters_components::AsyncCompPipeline;
+use crate::iters_components::BroadcastComp;
 use crate::iters_components::LocalComp;
 use crate::iters_components::LocalCompMut;
+use crate::iters_components::LocalCompPipeline;
+use crate::iters_components::MpscComp;
+use crate::iters_components::OverwriteComp;
+use crate::iters_components::OwnedComp;
 use crate::iters_components::SharedComp;
 use crate::iters_components::SharedCompMut;
+use crate::iters_components::SharedCompPipeline;
 #[cfg(any(feature = "async", doc))]
 use crate::ring_buffer::types::AsyncHeapRBMut;
+#[cfg(any(feature = "async", doc))]
+use crate::ring_buffer::types::AsyncHeapRBPipeline;
 use crate::ring_buffer::types::LocalHeapRBMut;
+use crate::ring_buffer::types::LocalHeapRBPipeline;
 use crate::ring_buffer::types::SharedHeapRBMut;
+use crate::ring_buffer::types::SharedHeapRBPipeline;
 use crate::storage_components::HeapStorage;
 use crate::utils::UnsafeSyncCell;
 
@@ -24,8 +39,8 @@ use alloc::vec;
 use alloc::vec::Vec;
 
 macro_rules! impl_rb {
-    ($t: tt, $i: tt) => {
-        impl<T> From<Vec<T>> for $t<T> {
+    ($t: tt, $i: tt $(, $k: ident)?) => {
+        impl<T $(, const $k: usize)?> From<Vec<T>> for $t<T $(, $k)?> {
             #[doc = concat!("Converts a `Vec<T>` into a [`", stringify!($t), "`].")]
             /// Note that the length of the buffer will be equal to the length of the vector, and *not*
             /// to its capacity.
@@ -40,25 +55,38 @@ macro_rules! impl_rb {
             }
         }
 
-        impl<T> $t<T> {
+        impl<T $(, const $k: usize)?> $t<T $(, $k)?> {
             #[doc = concat!("Creates a new [`", stringify!($t), "`] with given capacity and zeroed (uninitialised) elements.")]
-            /// # Safety
-            /// The buffer must be then initialised using proper [`ProdIter`] methods (`*_init` ones).
+            ///
+            /// The returned [`UninitRB`] has to be filled before it can be converted into this buffer,
+            /// or split into initialising iterators.
             /// # Behaviour with `vmem` feature
             /// When `vmem` feature is enabled, the capacity of the buffer must be a multiple of
             /// the system's page size.
             /// This method accepts a minimum size, which will then be used to compute the actual
             /// size (equal to or greater than it).
-            pub unsafe fn new_zeroed(capacity: usize) -> Self {
+            pub fn new_zeroed(capacity: usize) -> UninitRB<Self> {
                 assert!(capacity > 0);
 
-                Self::_from(
+                UninitRB::new(Self::_from(
                     HeapStorage::from(
                         (0..capacity)
                         .map(|_| UnsafeSyncCell::new_zeroed()).collect::<Box<[UnsafeSyncCell<T>]>>()
                     ),
                     $i::default()
-                )
+                ))
+            }
+
+            #[doc = concat!("Converts a `Vec<T>` into a [`", stringify!($t), "`] made up of blocks of `M` items,")]
+            /// so that [`ORBIterator::get_block_mut`](crate::ORBIterator::get_block_mut) never
+            /// finds a block crossing the end of the buffer, as long as iterators move by `M` items.
+            ///
+            /// # Panics
+            /// Panics if the length of `value` is not a non-zero multiple of `M`.
+            pub fn from_blocks<const M: usize>(value: Vec<T>) -> Self {
+                assert!(M > 0 && value.len() % M == 0, "buffer length must be a multiple of the block length");
+
+                Self::from(value)
             }
 
             #[doc = concat!("Creates a new [`", stringify!($t), "`] with given capacity and elements initialised to `default`.")]
@@ -80,8 +108,31 @@ macro_rules! impl_rb {
 impl_rb!(AsyncHeapRB, AsyncComp);
 #[cfg(any(feature = "async", doc))]
 impl_rb!(AsyncHeapRBMut, AsyncCompMut);
+#[cfg(any(feature = "async", doc))]
+impl_rb!(AsyncHeapRBPipeline, AsyncCompPipeline, K);
 
 impl_rb!(SharedHeapRB, SharedComp);
 impl_rb!(SharedHeapRBMut, SharedCompMut);
 impl_rb!(LocalHeapRB, LocalComp);
 impl_rb!(LocalHeapRBMut, LocalCompMut);
+impl_rb!(SharedHeapRBPipeline, SharedCompPipeline, K);
+impl_rb!(LocalHeapRBPipeline, LocalCompPipeline, K);
+impl_rb!(MpscHeapRB, MpscComp);
+impl_rb!(OverwriteHeapRB, OverwriteComp);
+impl_rb!(BroadcastHeapRB, BroadcastComp, C);
+
+impl<T> OwnedHeapRB<T> {
+    /// Creates a new empty [`OwnedHeapRB`] with given capacity.
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0);
+
+        Self::_from(
+            HeapStorage::from(
+                (0..capacity)
+                    .map(|_| UnsafeSyncCell::new_zeroed())
+                    .collect::<Box<[UnsafeSyncCell<T>]>>(),
+            ),
+            OwnedComp::default(),
+        )
+    }
+}
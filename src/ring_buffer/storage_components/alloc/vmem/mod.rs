@@ -127,6 +127,12 @@ impl<T> PStorageComponent for VmemStorage<T> {
         }
     }
 
+    #[inline]
+    fn next_block(&self, index: usize, _count: usize) -> Option<*mut T> {
+        // The storage is mapped twice in a row, so items are always contiguous.
+        Some(unsafe { self.inner.add(index) as *mut T })
+    }
+
     #[inline]
     fn _push_slice(
         &mut self,
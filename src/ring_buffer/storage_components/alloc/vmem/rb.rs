@@ -1,21 +1,36 @@
 #[cfg(any(feature = "async", doc))]
 use crate::AsyncVmemRB;
+use crate::BroadcastVmemRB;
 use crate::LocalVmemRB;
+use crate::MpscVmemRB;
+use crate::OverwriteVmemRB;
+use crate::OwnedVmemRB;
 use crate::SharedVmemRB;
-#[allow(unused_imports)]
-use crate::iterators::ProdIter;
+use crate::UninitRB;
 #[cfg(any(feature = "async", doc))]
 use crate::iters_components::AsyncComp;
 #[cfg(any(feature = "async", doc))]
 use crate::iters_components::AsyncCompMut;
+#[cfg(any(feature = "async", doc))]
+use crate::iters_components::AsyncCompPipeline;
+use crate::iters_components::BroadcastComp;
 use crate::iters_components::LocalComp;
 use crate::iters_components::LocalCompMut;
+use crate::iters_components::LocalCompPipeline;
+use crate::iters_components::MpscComp;
+use crate::iters_components::OverwriteComp;
+use crate::iters_components::OwnedComp;
 use crate::iters_components::SharedComp;
 use crate::iters_components::SharedCompMut;
+use crate::iters_components::SharedCompPipeline;
 #[cfg(any(feature = "async", doc))]
 use crate::ring_buffer::types::AsyncVmemRBMut;
+#[cfg(any(feature = "async", doc))]
+use crate::ring_buffer::types::AsyncVmemRBPipeline;
 use crate::ring_buffer::types::LocalVmemRBMut;
+use crate::ring_buffer::types::LocalVmemRBPipeline;
 use crate::ring_buffer::types::SharedVmemRBMut;
+use crate::ring_buffer::types::SharedVmemRBPipeline;
 use crate::storage_components::VmemStorage;
 use crate::utils::UnsafeSyncCell;
 use alloc::boxed::Box;
@@ -28,8 +43,8 @@ fn get_range_max<T>(capacity: usize) -> usize {
 }
 
 macro_rules! impl_rb {
-    ($t: tt, $i: tt) => {
-        impl<T> From<Vec<T>> for $t<T> {
+    ($t: tt, $i: tt $(, $k: ident)?) => {
+        impl<T $(, const $k: usize)?> From<Vec<T>> for $t<T $(, $k)?> {
             #[doc = concat!("Converts a `Vec<T>` into a [`", stringify!($t), "`].")]
             /// Note that the length of the buffer will be equal to the length of the vector, and *not*
             /// to its capacity.
@@ -44,25 +59,26 @@ macro_rules! impl_rb {
             }
         }
 
-        impl<T> $t<T> {
+        impl<T $(, const $k: usize)?> $t<T $(, $k)?> {
             #[doc = concat!("Creates a new [`", stringify!($t), "`] with given capacity and zeroed (uninitialised) elements.")]
-            /// # Safety
-            /// The buffer must be then initialised using proper [`ProdIter`] methods (`*_init` ones).
+            ///
+            /// The returned [`UninitRB`] has to be filled before it can be converted into this buffer,
+            /// or split into initialising iterators.
             /// # Behaviour with `vmem` feature
             /// When `vmem` feature is enabled, the capacity of the buffer must be a multiple of
             /// the system's page size.
             /// This method accepts a minimum size, which will then be used to compute the actual
             /// size (equal to or greater than it).
-            pub unsafe fn new_zeroed(capacity: usize) -> Self {
+            pub fn new_zeroed(capacity: usize) -> UninitRB<Self> {
                 assert!(capacity > 0);
 
-                Self::_from(
+                UninitRB::new(Self::_from(
                     VmemStorage::from(
                         (0..get_range_max::<T>(capacity))
                         .map(|_| UnsafeSyncCell::new_zeroed()).collect::<Box<[UnsafeSyncCell<T>]>>()
                     ),
                     $i::default()
-                )
+                ))
             }
 
             #[doc = concat!("Creates a new [`", stringify!($t), "`] with given capacity and elements initialised to `default`.")]
@@ -84,8 +100,35 @@ macro_rules! impl_rb {
 impl_rb!(AsyncVmemRB, AsyncComp);
 #[cfg(any(feature = "async", doc))]
 impl_rb!(AsyncVmemRBMut, AsyncCompMut);
+#[cfg(any(feature = "async", doc))]
+impl_rb!(AsyncVmemRBPipeline, AsyncCompPipeline, K);
 
 impl_rb!(SharedVmemRB, SharedComp);
 impl_rb!(SharedVmemRBMut, SharedCompMut);
 impl_rb!(LocalVmemRB, LocalComp);
 impl_rb!(LocalVmemRBMut, LocalCompMut);
+impl_rb!(SharedVmemRBPipeline, SharedCompPipeline, K);
+impl_rb!(LocalVmemRBPipeline, LocalCompPipeline, K);
+impl_rb!(MpscVmemRB, MpscComp);
+impl_rb!(OverwriteVmemRB, OverwriteComp);
+impl_rb!(BroadcastVmemRB, BroadcastComp, C);
+
+impl<T> OwnedVmemRB<T> {
+    /// Creates a new empty [`OwnedVmemRB`] with given capacity.
+    ///
+    /// The capacity of the buffer must be a multiple of the system's page size.
+    /// This method accepts a minimum size, which will then be used to compute the actual
+    /// size (equal to or greater than it).
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0);
+
+        Self::_from(
+            VmemStorage::from(
+                (0..get_range_max::<T>(capacity))
+                    .map(|_| UnsafeSyncCell::new_zeroed())
+                    .collect::<Box<[UnsafeSyncCell<T>]>>(),
+            ),
+            OwnedComp::default(),
+        )
+    }
+}
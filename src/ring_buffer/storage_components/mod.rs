@@ -1,5 +1,7 @@
 //! Components used by the buffers to store data.
 
+use core::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+
 use crate::utils::UnsafeSyncCell;
 
 #[cfg(feature = "alloc")]
@@ -23,6 +25,9 @@ pub(crate) trait PStorageComponent: StorageComponent {
     fn next_chunk<'a>(&self, index: usize, count: usize) -> Self::SliceOutput<'a>;
     /// Returns the next mutable chunk long `count` which starts from `index`.
     fn next_chunk_mut<'a>(&mut self, index: usize, count: usize) -> Self::SliceOutputMut<'a>;
+    /// Returns a pointer to the `count` items which start from `index`, if they are
+    /// contiguous in memory, i.e. if they do not cross the end of the storage.
+    fn next_block(&self, index: usize, count: usize) -> Option<*mut Self::Item>;
 
     fn _push_slice(
         &mut self,
@@ -37,6 +42,45 @@ pub(crate) trait PStorageComponent: StorageComponent {
         dst: &mut [Self::Item],
         f: fn(&[Self::Item], &mut [Self::Item]),
     );
+
+    /// Returns the index from which the producer skipped the tail of the storage,
+    /// to keep a reservation contiguous, if it did.
+    #[inline]
+    fn wrap_mark(&self) -> Option<usize> {
+        None
+    }
+    /// Sets the index from which the producer skipped the tail of the storage.
+    /// Storages which are contiguous across their end never need it.
+    #[inline]
+    fn set_wrap_mark(&self, _mark: Option<usize>) {}
+}
+
+/// Index from which the producer skipped the tail of a storage, used by
+/// [`ProdIter::reserve`](crate::iterators::ProdIter::reserve).
+///
+/// It is written before the producer publishes its index, and cleared by the consumer
+/// before it publishes its own, so relaxed accesses are enough.
+pub(crate) struct WrapMark(AtomicUsize);
+
+impl WrapMark {
+    const NONE: usize = usize::MAX;
+
+    pub(crate) const fn new() -> Self {
+        Self(AtomicUsize::new(Self::NONE))
+    }
+
+    #[inline]
+    pub(crate) fn get(&self) -> Option<usize> {
+        match self.0.load(Relaxed) {
+            Self::NONE => None,
+            mark => Some(mark),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn set(&self, mark: Option<usize>) {
+        self.0.store(mark.unwrap_or(Self::NONE), Relaxed);
+    }
 }
 
 /// Trait implemented by storage components.
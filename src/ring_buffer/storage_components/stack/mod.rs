@@ -5,12 +5,13 @@ use core::mem::transmute;
 use core::ops::Index;
 use core::slice;
 
-use crate::ring_buffer::storage_components::{PStorageComponent, StorageComponent};
+use crate::ring_buffer::storage_components::{PStorageComponent, StorageComponent, WrapMark};
 use crate::utils::UnsafeSyncCell;
 
 /// Stack-allocated storage.
 pub struct StackStorage<'buf, T, const N: usize> {
     inner: [UnsafeSyncCell<T>; N],
+    wrap_mark: WrapMark,
     phantom: PhantomData<&'buf ()>,
 }
 
@@ -22,6 +23,7 @@ impl<'buf, T, const N: usize> StackStorage<'buf, T, N> {
 
         Self {
             inner: unsafe { ptr.read() },
+            wrap_mark: WrapMark::new(),
             phantom: PhantomData,
         }
     }
@@ -31,6 +33,7 @@ impl<'buf, T, const N: usize> From<[UnsafeSyncCell<T>; N]> for StackStorage<'buf
     fn from(value: [UnsafeSyncCell<T>; N]) -> StackStorage<'buf, T, N> {
         Self {
             inner: value,
+            wrap_mark: WrapMark::new(),
             phantom: PhantomData,
         }
     }
@@ -128,6 +131,11 @@ impl<'buf, T, const N: usize> PStorageComponent for StackStorage<'buf, T, N> {
         }
     }
 
+    #[inline]
+    fn next_block(&self, index: usize, count: usize) -> Option<*mut T> {
+        (index + count <= N).then(|| unsafe { self.inner.as_ptr().add(index) as *mut T })
+    }
+
     #[inline]
     fn _push_slice(
         &mut self,
@@ -169,6 +177,16 @@ impl<'buf, T, const N: usize> PStorageComponent for StackStorage<'buf, T, N> {
             }
         }
     }
+
+    #[inline]
+    fn wrap_mark(&self) -> Option<usize> {
+        self.wrap_mark.get()
+    }
+
+    #[inline]
+    fn set_wrap_mark(&self, mark: Option<usize>) {
+        self.wrap_mark.set(mark);
+    }
 }
 
 pub mod test {
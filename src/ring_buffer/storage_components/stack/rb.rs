@@ -1,21 +1,28 @@
-#[cfg(doc)]
-use crate::iterators::ProdIter;
 #[cfg(any(feature = "async", doc))]
-use crate::iters_components::{AsyncComp, AsyncCompMut};
-use crate::iters_components::{LocalComp, LocalCompMut, SharedComp, SharedCompMut};
+use crate::iters_components::{AsyncComp, AsyncCompMut, AsyncCompPipeline};
+use crate::iters_components::{
+    BroadcastComp, LocalComp, LocalCompMut, LocalCompPipeline, MpscComp, OverwriteComp, OwnedComp,
+    SharedComp, SharedCompMut, SharedCompPipeline,
+};
 #[cfg(any(feature = "async", doc))]
-use crate::{AsyncStackRB, ring_buffer::types::AsyncStackRBMut};
+use crate::{
+    AsyncStackRB,
+    ring_buffer::types::{AsyncStackRBMut, AsyncStackRBPipeline},
+};
 
+use crate::UninitRB;
 use crate::storage_components::StackStorage;
 use crate::{
-    LocalStackRB, SharedStackRB,
-    ring_buffer::types::{LocalStackRBMut, SharedStackRBMut},
+    BroadcastStackRB, LocalStackRB, MpscStackRB, OverwriteStackRB, OwnedStackRB, SharedStackRB,
+    ring_buffer::types::{
+        LocalStackRBMut, LocalStackRBPipeline, SharedStackRBMut, SharedStackRBPipeline,
+    },
     utils::UnsafeSyncCell,
 };
 
 macro_rules! impl_rb {
-    ($t: tt, $i: tt) => {
-        impl<'buf, T, const N: usize> $t<'buf, T, N> {
+    ($t: tt, $i: tt $(, $k: ident)?) => {
+        impl<'buf, T, const N: usize $(, const $k: usize)?> $t<'buf, T, N $(, $k)?> {
             #[doc = concat!("Converts an array into a [`", stringify!($t), "`]. Can be used in const environments.")]
             pub const fn from_arr_const(value: [T; N]) -> Self {
                 assert!(N > 0);
@@ -24,7 +31,20 @@ macro_rules! impl_rb {
             }
         }
 
-        impl<'buf, T, const N: usize> From<[T; N]> for $t<'buf, T, N> {
+        impl<'buf, T, const N: usize $(, const $k: usize)?> $t<'buf, T, N $(, $k)?> {
+            #[doc = concat!("Converts an array into a [`", stringify!($t), "`] made up of blocks of `M` items,")]
+            /// so that [`ORBIterator::get_block_mut`](crate::ORBIterator::get_block_mut) never
+            /// finds a block crossing the end of the buffer, as long as iterators move by `M` items.
+            ///
+            /// Fails to compile if `N` is not a multiple of `M`.
+            pub const fn from_blocks<const M: usize>(value: [T; N]) -> Self {
+                const { assert!(M > 0 && N % M == 0, "buffer length must be a multiple of the block length") };
+
+                Self::from_arr_const(value)
+            }
+        }
+
+        impl<'buf, T, const N: usize $(, const $k: usize)?> From<[T; N]> for $t<'buf, T, N $(, $k)?> {
             #[doc = concat!("Converts an array into a [`", stringify!($t), "`].")]
             fn from(value: [T; N]) -> Self {
                 assert!(N > 0);
@@ -33,20 +53,21 @@ macro_rules! impl_rb {
             }
         }
 
-        impl<'buf, T, const N: usize> $t<'buf, T, N> {
+        impl<'buf, T, const N: usize $(, const $k: usize)?> $t<'buf, T, N $(, $k)?> {
             #[doc = concat!("Creates a new [`", stringify!($t), "`] with given capacity and zeroed (uninitialised) elements.")]
-            /// # Safety
-            /// The buffer must be then initialised using proper [`ProdIter`] methods (`*_init` ones).
-            pub unsafe fn new_zeroed() -> Self {
+            ///
+            /// The returned [`UninitRB`] has to be filled before it can be converted into this buffer,
+            /// or split into initialising iterators.
+            pub fn new_zeroed() -> UninitRB<Self> {
                 assert!(N > 0);
 
                 let v: [UnsafeSyncCell<T>; N] = core::array::from_fn(|_| UnsafeSyncCell::new_zeroed());
 
-                Self::_from(StackStorage::from(v), $i::default())
+                UninitRB::new(Self::_from(StackStorage::from(v), $i::default()))
             }
         }
 
-        impl<'buf, T: Default + Copy, const N: usize> Default for $t<'buf, T, N> {
+        impl<'buf, T: Default + Copy, const N: usize $(, const $k: usize)?> Default for $t<'buf, T, N $(, $k)?> {
             #[doc = concat!("Creates a new [`", stringify!($t), "`] with given capacity and elements initialised to `default`.")]
             fn default() -> Self {
                 assert!(N > 0);
@@ -61,8 +82,33 @@ macro_rules! impl_rb {
 impl_rb!(AsyncStackRB, AsyncComp);
 #[cfg(any(feature = "async", doc))]
 impl_rb!(AsyncStackRBMut, AsyncCompMut);
+#[cfg(any(feature = "async", doc))]
+impl_rb!(AsyncStackRBPipeline, AsyncCompPipeline, K);
 
 impl_rb!(SharedStackRB, SharedComp);
 impl_rb!(SharedStackRBMut, SharedCompMut);
 impl_rb!(LocalStackRB, LocalComp);
 impl_rb!(LocalStackRBMut, LocalCompMut);
+impl_rb!(SharedStackRBPipeline, SharedCompPipeline, K);
+impl_rb!(LocalStackRBPipeline, LocalCompPipeline, K);
+impl_rb!(MpscStackRB, MpscComp);
+impl_rb!(OverwriteStackRB, OverwriteComp);
+impl_rb!(BroadcastStackRB, BroadcastComp, C);
+
+impl<'buf, T, const N: usize> OwnedStackRB<'buf, T, N> {
+    /// Creates a new empty [`OwnedStackRB`].
+    pub fn new() -> Self {
+        assert!(N > 0);
+
+        let v: [UnsafeSyncCell<T>; N] = core::array::from_fn(|_| UnsafeSyncCell::new_zeroed());
+
+        Self::_from(StackStorage::from(v), OwnedComp::default())
+    }
+}
+
+impl<'buf, T, const N: usize> Default for OwnedStackRB<'buf, T, N> {
+    /// Creates a new empty [`OwnedStackRB`].
+    fn default() -> Self {
+        Self::new()
+    }
+}
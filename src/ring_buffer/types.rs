@@ -1,5 +1,7 @@
 #[cfg(all(feature = "async"))]
-use crate::iters_components::async_iters::{mutable::AsyncCompMut, non_mutable::AsyncComp};
+use crate::iters_components::async_iters::{
+    mutable::AsyncCompMut, non_mutable::AsyncComp, pipeline::AsyncCompPipeline,
+};
 #[cfg(feature = "alloc")]
 use crate::storage_components::HeapStorage;
 #[cfg(all(feature = "alloc", feature = "vmem", unix))]
@@ -7,8 +9,12 @@ use crate::storage_components::VmemStorage;
 use crate::{
     OneRingBuf,
     iters_components::{
-        local_iters::{mutable::LocalCompMut, non_mutable::LocalComp},
-        shared_iters::{mutable::SharedCompMut, non_mutable::SharedComp},
+        local_iters::{mutable::LocalCompMut, non_mutable::LocalComp, pipeline::LocalCompPipeline},
+        shared_iters::{
+            broadcast::BroadcastComp, mpsc::MpscComp, mutable::SharedCompMut,
+            non_mutable::SharedComp, overwrite::OverwriteComp, owned::OwnedComp,
+            pipeline::SharedCompPipeline,
+        },
     },
     storage_components::StackStorage,
 };
@@ -24,6 +30,22 @@ pub type LocalStackRBMut<'buf, T, const N: usize> =
 /// Mutable stack-allocated ring buffer suitable for multi-threaded usage.
 pub type SharedStackRBMut<'buf, T, const N: usize> =
     OneRingBuf<StackStorage<'buf, T, N>, SharedCompMut>;
+/// Stack-allocated ring buffer with `K` worker stages suitable for single-threaded usage.
+pub type LocalStackRBPipeline<'buf, T, const N: usize, const K: usize> =
+    OneRingBuf<StackStorage<'buf, T, N>, LocalCompPipeline<K>>;
+/// Stack-allocated ring buffer with `K` worker stages suitable for multi-threaded usage.
+pub type SharedStackRBPipeline<'buf, T, const N: usize, const K: usize> =
+    OneRingBuf<StackStorage<'buf, T, N>, SharedCompPipeline<K>>;
+/// Non-mutable stack-allocated ring buffer with multiple producers and a single consumer.
+pub type MpscStackRB<'buf, T, const N: usize> = OneRingBuf<StackStorage<'buf, T, N>, MpscComp>;
+/// Non-mutable stack-allocated ring buffer with a single producer and `C` consumers.
+pub type BroadcastStackRB<'buf, T, const N: usize, const C: usize> =
+    OneRingBuf<StackStorage<'buf, T, N>, BroadcastComp<C>>;
+/// Non-mutable stack-allocated ring buffer whose producer overwrites the oldest items when full.
+pub type OverwriteStackRB<'buf, T, const N: usize> =
+    OneRingBuf<StackStorage<'buf, T, N>, OverwriteComp>;
+/// Stack-allocated ring buffer whose items are moved in by the producer and out by the consumer.
+pub type OwnedStackRB<'buf, T, const N: usize> = OneRingBuf<StackStorage<'buf, T, N>, OwnedComp>;
 
 // Heap
 /// Non-mutable heap-allocated ring buffer suitable for single-threaded usage.
@@ -38,6 +60,25 @@ pub type LocalHeapRBMut<T> = OneRingBuf<HeapStorage<T>, LocalCompMut>;
 /// Mutable heap-allocated ring buffer suitable for multi-threaded usage.
 #[cfg(feature = "alloc")]
 pub type SharedHeapRBMut<T> = OneRingBuf<HeapStorage<T>, SharedCompMut>;
+/// Heap-allocated ring buffer with `K` worker stages suitable for single-threaded usage.
+#[cfg(feature = "alloc")]
+pub type LocalHeapRBPipeline<T, const K: usize> = OneRingBuf<HeapStorage<T>, LocalCompPipeline<K>>;
+/// Heap-allocated ring buffer with `K` worker stages suitable for multi-threaded usage.
+#[cfg(feature = "alloc")]
+pub type SharedHeapRBPipeline<T, const K: usize> =
+    OneRingBuf<HeapStorage<T>, SharedCompPipeline<K>>;
+/// Non-mutable heap-allocated ring buffer with multiple producers and a single consumer.
+#[cfg(feature = "alloc")]
+pub type MpscHeapRB<T> = OneRingBuf<HeapStorage<T>, MpscComp>;
+/// Non-mutable heap-allocated ring buffer with a single producer and `C` consumers.
+#[cfg(feature = "alloc")]
+pub type BroadcastHeapRB<T, const C: usize> = OneRingBuf<HeapStorage<T>, BroadcastComp<C>>;
+/// Non-mutable heap-allocated ring buffer whose producer overwrites the oldest items when full.
+#[cfg(feature = "alloc")]
+pub type OverwriteHeapRB<T> = OneRingBuf<HeapStorage<T>, OverwriteComp>;
+/// Heap-allocated ring buffer whose items are moved in by the producer and out by the consumer.
+#[cfg(feature = "alloc")]
+pub type OwnedHeapRB<T> = OneRingBuf<HeapStorage<T>, OwnedComp>;
 
 // Vmem
 /// Non-mutable ring buffer using virtual memory storage suitable for single-threaded usage.
@@ -52,6 +93,25 @@ pub type LocalVmemRBMut<T> = OneRingBuf<VmemStorage<T>, LocalCompMut>;
 /// Mutable ring buffer using virtual memory storage suitable for multi-threaded usage.
 #[cfg(all(feature = "alloc", feature = "vmem", unix))]
 pub type SharedVmemRBMut<T> = OneRingBuf<VmemStorage<T>, SharedCompMut>;
+/// Ring buffer using virtual memory storage with `K` worker stages suitable for single-threaded usage.
+#[cfg(all(feature = "alloc", feature = "vmem", unix))]
+pub type LocalVmemRBPipeline<T, const K: usize> = OneRingBuf<VmemStorage<T>, LocalCompPipeline<K>>;
+/// Ring buffer using virtual memory storage with `K` worker stages suitable for multi-threaded usage.
+#[cfg(all(feature = "alloc", feature = "vmem", unix))]
+pub type SharedVmemRBPipeline<T, const K: usize> =
+    OneRingBuf<VmemStorage<T>, SharedCompPipeline<K>>;
+/// Non-mutable ring buffer using virtual memory storage with multiple producers and a single consumer.
+#[cfg(all(feature = "alloc", feature = "vmem", unix))]
+pub type MpscVmemRB<T> = OneRingBuf<VmemStorage<T>, MpscComp>;
+/// Non-mutable ring buffer using virtual memory storage with a single producer and `C` consumers.
+#[cfg(all(feature = "alloc", feature = "vmem", unix))]
+pub type BroadcastVmemRB<T, const C: usize> = OneRingBuf<VmemStorage<T>, BroadcastComp<C>>;
+/// Non-mutable ring buffer using virtual memory storage whose producer overwrites the oldest items when full.
+#[cfg(all(feature = "alloc", feature = "vmem", unix))]
+pub type OverwriteVmemRB<T> = OneRingBuf<VmemStorage<T>, OverwriteComp>;
+/// Ring buffer using virtual memory storage whose items are moved in by the producer and out by the consumer.
+#[cfg(all(feature = "alloc", feature = "vmem", unix))]
+pub type OwnedVmemRB<T> = OneRingBuf<VmemStorage<T>, OwnedComp>;
 
 // Async Stack
 /// Non-mutable stack-allocated asynchronous ring buffer.
@@ -63,8 +123,14 @@ pub type AsyncStackRBMut<'buf, T, const N: usize> =
     OneRingBuf<StackStorage<'buf, T, N>, AsyncCompMut>;
 #[cfg(feature = "async")]
 unsafe impl<T, const N: usize> Sync for AsyncStackRB<'_, T, N> {}
+/// Stack-allocated asynchronous ring buffer with `K` worker stages.
+#[cfg(feature = "async")]
+pub type AsyncStackRBPipeline<'buf, T, const N: usize, const K: usize> =
+    OneRingBuf<StackStorage<'buf, T, N>, AsyncCompPipeline<K>>;
 #[cfg(feature = "async")]
 unsafe impl<T, const N: usize> Sync for AsyncStackRBMut<'_, T, N> {}
+#[cfg(feature = "async")]
+unsafe impl<T, const N: usize, const K: usize> Sync for AsyncStackRBPipeline<'_, T, N, K> {}
 
 // Async Heap
 /// Non-mutable heap-allocated asynchronous ring buffer.
@@ -75,8 +141,13 @@ pub type AsyncHeapRB<T> = OneRingBuf<HeapStorage<T>, AsyncComp>;
 pub type AsyncHeapRBMut<T> = OneRingBuf<HeapStorage<T>, AsyncCompMut>;
 #[cfg(all(feature = "async", feature = "alloc"))]
 unsafe impl<T> Sync for AsyncHeapRB<T> {}
+/// Heap-allocated asynchronous ring buffer with `K` worker stages.
+#[cfg(all(feature = "async", feature = "alloc"))]
+pub type AsyncHeapRBPipeline<T, const K: usize> = OneRingBuf<HeapStorage<T>, AsyncCompPipeline<K>>;
 #[cfg(all(feature = "async", feature = "alloc"))]
 unsafe impl<T> Sync for AsyncHeapRBMut<T> {}
+#[cfg(all(feature = "async", feature = "alloc"))]
+unsafe impl<T, const K: usize> Sync for AsyncHeapRBPipeline<T, K> {}
 
 // Async Vmem
 /// Non-mutable asynchronous ring buffer using virtual memory storage.
@@ -87,5 +158,10 @@ pub type AsyncVmemRB<T> = OneRingBuf<VmemStorage<T>, AsyncComp>;
 pub type AsyncVmemRBMut<T> = OneRingBuf<VmemStorage<T>, AsyncCompMut>;
 #[cfg(all(feature = "async", feature = "alloc", feature = "vmem", unix))]
 unsafe impl<T> Sync for AsyncVmemRB<T> {}
+/// Asynchronous ring buffer using virtual memory storage with `K` worker stages.
+#[cfg(all(feature = "async", feature = "alloc", feature = "vmem", unix))]
+pub type AsyncVmemRBPipeline<T, const K: usize> = OneRingBuf<VmemStorage<T>, AsyncCompPipeline<K>>;
 #[cfg(all(feature = "async", feature = "alloc", feature = "vmem", unix))]
 unsafe impl<T> Sync for AsyncVmemRBMut<T> {}
+#[cfg(all(feature = "async", feature = "alloc", feature = "vmem", unix))]
+unsafe impl<T, const K: usize> Sync for AsyncVmemRBPipeline<T, K> {}
@@ -0,0 +1,168 @@
+use crate::errors::PushError;
+use crate::iterators::ConsIter;
+use crate::iterators::sync_iterators::uninit_prod_iter::UninitProdIter;
+use crate::iters_components::NonMutIterComp;
+use crate::ring_buffer::OneRB;
+use crate::ring_buffer::storage_components::PStorageComponent;
+#[cfg(feature = "alloc")]
+use crate::ring_buffer::wrappers::refs::droppable::DroppableRef;
+use crate::ring_buffer::wrappers::refs::non_droppable::NonDroppableRef;
+#[cfg(feature = "alloc")]
+use crate::storage_components::HeapStorage;
+#[cfg(all(feature = "alloc", feature = "vmem", unix))]
+use crate::storage_components::VmemStorage;
+use crate::{OneRingBuf, storage_components::StackStorage};
+
+type UninitIters<B> = (UninitProdIter<B>, ConsIter<B>);
+
+#[doc = r##"
+Buffer whose items are not initialised yet, returned by `new_zeroed` methods (e.g. [`SharedHeapRB::new_zeroed`](crate::SharedHeapRB::new_zeroed)).
+
+Items have to be written, in order, with [`Self::push`], [`Self::push_iter`] or [`Self::fill_with`],
+until every location is initialised. Only then it can be converted into the initialised buffer with [`Self::into_init`].
+
+Alternatively, non-mutable buffers can be split right away with `split`, which returns an [`UninitProdIter`]
+and a [`ConsIter`]: items are consumed while the producer initialises the storage, and, once it has
+lapped the whole storage, [`UninitProdIter::into_init`] turns it into a normal producer.
+
+<div class="warning">
+
+Items written before splitting only initialise their locations: they are not queued, so the consumer never
+reads them. The producer starts from the beginning of the storage, dropping them as it overwrites them.
+</div>
+
+Items written so far are dropped along with this buffer.
+"##]
+pub struct UninitRB<B: OneRB> {
+    buffer: B,
+    filled: usize,
+}
+
+impl<B: OneRB<Item = T>, T> UninitRB<B> {
+    pub(crate) fn new(buffer: B) -> Self {
+        Self { buffer, filled: 0 }
+    }
+
+    /// Returns the length of the buffer.
+    #[inline]
+    pub fn buf_len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Returns the number of initialised items.
+    #[inline]
+    pub fn filled(&self) -> usize {
+        self.filled
+    }
+
+    /// Returns the number of items which still have to be written.
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.buffer.len() - self.filled
+    }
+
+    /// Returns `true` if every item has been initialised.
+    #[inline]
+    pub fn is_init(&self) -> bool {
+        self.remaining() == 0
+    }
+
+    /// Initialises the next item, moving `value` into it.
+    ///
+    /// Returns:
+    /// * `Err(PushError::Full(value))`, if every item has already been initialised;
+    /// * `Ok(())`, otherwise.
+    #[inline]
+    pub fn push(&mut self, value: T) -> Result<(), PushError<T>> {
+        if self.is_init() {
+            return Err(PushError::Full(value));
+        }
+
+        unsafe {
+            self.buffer
+                .storage()
+                ._index(self.filled)
+                .as_mut_ptr()
+                .write(value)
+        };
+        self.filled += 1;
+
+        Ok(())
+    }
+
+    /// Initialises as many items as possible, taking them from `iter`.
+    /// Items which do not fit are not consumed from the iterator.
+    ///
+    /// Returns the number of items written.
+    pub fn push_iter<I: IntoIterator<Item = T>>(&mut self, iter: I) -> usize {
+        let start = self.filled;
+
+        for value in iter.into_iter().take(self.remaining()) {
+            let ret = self.push(value);
+            debug_assert!(ret.is_ok());
+        }
+
+        self.filled - start
+    }
+
+    /// Initialises the remaining items with the values returned by `f`,
+    /// then returns the initialised buffer.
+    pub fn fill_with(mut self, mut f: impl FnMut() -> T) -> B {
+        while !self.is_init() {
+            let ret = self.push(f());
+            debug_assert!(ret.is_ok());
+        }
+
+        match self.into_init() {
+            Ok(buf) => buf,
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// Converts this into the initialised buffer.
+    ///
+    /// Returns `Err(self)` if some items have not been written yet.
+    pub fn into_init(self) -> Result<B, Self> {
+        if !self.is_init() {
+            return Err(self);
+        }
+
+        Ok(self.buffer)
+    }
+}
+
+impl<'buf, T, const N: usize, I: NonMutIterComp> UninitRB<OneRingBuf<StackStorage<'buf, T, N>, I>> {
+    /// Returns two iterators: a Producer, which only performs initialising writes, and a Consumer.
+    /// <div class="warning">Available only for non-mutable buffers.</div>
+    pub fn split(&'buf mut self) -> UninitIters<OneRingBuf<StackStorage<'buf, T, N>, I>> {
+        let filled = self.filled;
+        let r = NonDroppableRef::from(&mut self.buffer);
+        (UninitProdIter::new(r.clone(), filled), ConsIter::new(r))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, I: NonMutIterComp> UninitRB<OneRingBuf<HeapStorage<T>, I>> {
+    /// Returns two iterators: a Producer, which only performs initialising writes, and a Consumer.
+    /// <div class="warning">Available only for non-mutable buffers.</div>
+    pub fn split(self) -> UninitIters<OneRingBuf<HeapStorage<T>, I>> {
+        let r = DroppableRef::from(self.buffer);
+        (
+            UninitProdIter::new(r.clone(), self.filled),
+            ConsIter::new(r),
+        )
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "vmem", unix))]
+impl<T, I: NonMutIterComp> UninitRB<OneRingBuf<VmemStorage<T>, I>> {
+    /// Returns two iterators: a Producer, which only performs initialising writes, and a Consumer.
+    /// <div class="warning">Available only for non-mutable buffers.</div>
+    pub fn split(self) -> UninitIters<OneRingBuf<VmemStorage<T>, I>> {
+        let r = DroppableRef::from(self.buffer);
+        (
+            UninitProdIter::new(r.clone(), self.filled),
+            ConsIter::new(r),
+        )
+    }
+}
@@ -0,0 +1,254 @@
+use core::hint::spin_loop;
+use core::sync::atomic::{
+    AtomicBool,
+    Ordering::{Acquire, Release},
+};
+
+#[cfg(feature = "std")]
+use core::sync::atomic::{
+    AtomicUsize,
+    Ordering::{Relaxed, SeqCst},
+    fence,
+};
+#[cfg(feature = "std")]
+use std::{
+    sync::{Condvar, Mutex},
+    time::{Duration, Instant},
+};
+
+/// Highest exponent used by [`WaitStrategy::Backoff`].
+const BACKOFF_LIMIT: u32 = 6;
+/// Longest time a thread parked by a cancellable wait sleeps before checking its [`CancelToken`].
+#[cfg(feature = "std")]
+const CANCEL_POLL: Duration = Duration::from_millis(1);
+
+/// Strategy used by blocking methods (e.g. [`ORBIterator::wait_for`](crate::ORBIterator::wait_for))
+/// while waiting for the other iterators to move.
+///
+/// It can be chosen per buffer with [`OneRingBuf::with_wait_strategy`](crate::OneRingBuf::with_wait_strategy).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum WaitStrategy {
+    /// Busy loop, hinting the processor with [`spin_loop`].
+    #[default]
+    Spin,
+    /// Busy loop, doubling the number of spins at each failed attempt, up to a limit.
+    Backoff,
+    /// Yields the thread to the scheduler at each failed attempt.
+    #[cfg(feature = "std")]
+    Yield,
+    /// Parks the thread until another iterator moves.
+    #[cfg(feature = "std")]
+    Park,
+}
+
+/// Token used to interrupt cancellable waits, such as
+/// [`ORBIterator::wait_for_cancellable`](crate::ORBIterator::wait_for_cancellable).
+///
+/// It is meant to be shared (e.g. by reference or within an `Arc`) between the waiting threads
+/// and the one which cancels them, like a shutdown thread.
+/// Once cancelled, every wait using this token returns immediately, until [`Self::reset`] is called.
+#[derive(Debug, Default)]
+pub struct CancelToken {
+    cancelled: AtomicBool,
+}
+
+impl CancelToken {
+    /// Creates a new, non-cancelled, token.
+    pub const fn new() -> Self {
+        Self {
+            cancelled: AtomicBool::new(false),
+        }
+    }
+
+    /// Cancels the token, interrupting every wait using it.
+    ///
+    /// Threads parked with [`WaitStrategy::Park`] notice the cancellation within a millisecond.
+    #[inline]
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Release);
+    }
+
+    /// Returns `true` if the token has been cancelled.
+    #[inline]
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Acquire)
+    }
+
+    /// Resets the token, so that it can be used again.
+    #[inline]
+    pub fn reset(&self) {
+        self.cancelled.store(false, Release);
+    }
+}
+
+/// Conditions which make a wait give up.
+#[derive(Default)]
+pub(crate) struct WaitLimit<'a> {
+    #[cfg(feature = "std")]
+    deadline: Option<Instant>,
+    cancel: Option<&'a CancelToken>,
+}
+
+impl<'a> WaitLimit<'a> {
+    /// Gives up when `deadline` is reached. `None` means no deadline.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub(crate) fn until(deadline: Option<Instant>) -> Self {
+        Self {
+            deadline,
+            cancel: None,
+        }
+    }
+
+    /// Gives up when `token` is cancelled.
+    #[inline]
+    pub(crate) fn cancellable(token: &'a CancelToken) -> Self {
+        Self {
+            #[cfg(feature = "std")]
+            deadline: None,
+            cancel: Some(token),
+        }
+    }
+
+    /// Returns `true` if the wait has to give up.
+    #[inline]
+    pub(crate) fn expired(&self) -> bool {
+        #[cfg(feature = "std")]
+        if self.deadline.is_some_and(|d| Instant::now() >= d) {
+            return true;
+        }
+
+        self.cancel.is_some_and(|c| c.is_cancelled())
+    }
+
+    /// Returns how long a parked thread may sleep, if limited.
+    #[cfg(feature = "std")]
+    #[inline]
+    fn park_timeout(&self) -> Option<Duration> {
+        let remaining = self
+            .deadline
+            .map(|d| d.saturating_duration_since(Instant::now()));
+
+        match self.cancel {
+            Some(_) => Some(remaining.map_or(CANCEL_POLL, |r| r.min(CANCEL_POLL))),
+            None => remaining,
+        }
+    }
+}
+
+/// State kept by a thread while waiting.
+#[derive(Default)]
+pub(crate) struct WaitState {
+    step: u32,
+    #[cfg(feature = "std")]
+    epoch: usize,
+}
+
+/// Implements a [`WaitStrategy`]. Every buffer holds one of these.
+pub(crate) struct Waiter {
+    strategy: WaitStrategy,
+
+    /// Number of threads which are (about to be) parked.
+    #[cfg(feature = "std")]
+    sleepers: AtomicUsize,
+    /// Incremented every time parked threads are woken up.
+    #[cfg(feature = "std")]
+    epoch: AtomicUsize,
+    #[cfg(feature = "std")]
+    lock: Mutex<()>,
+    #[cfg(feature = "std")]
+    cvar: Condvar,
+}
+
+impl Waiter {
+    pub(crate) const fn new(strategy: WaitStrategy) -> Self {
+        Self {
+            strategy,
+            #[cfg(feature = "std")]
+            sleepers: AtomicUsize::new(0),
+            #[cfg(feature = "std")]
+            epoch: AtomicUsize::new(0),
+            #[cfg(feature = "std")]
+            lock: Mutex::new(()),
+            #[cfg(feature = "std")]
+            cvar: Condvar::new(),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn strategy(&self) -> WaitStrategy {
+        self.strategy
+    }
+
+    /// Must be called before checking the condition the thread is waiting for.
+    #[inline]
+    pub(crate) fn prepare(&self, _state: &mut WaitState) {
+        #[cfg(feature = "std")]
+        if self.strategy == WaitStrategy::Park {
+            self.sleepers.fetch_add(1, SeqCst);
+            fence(SeqCst);
+            _state.epoch = self.epoch.load(SeqCst);
+        }
+    }
+
+    /// Must be called when the condition is satisfied, after [`Self::prepare`].
+    #[inline]
+    pub(crate) fn cancel(&self, _state: &mut WaitState) {
+        #[cfg(feature = "std")]
+        if self.strategy == WaitStrategy::Park {
+            self.sleepers.fetch_sub(1, Relaxed);
+        }
+    }
+
+    /// Waits according to the strategy. Must be called after [`Self::prepare`],
+    /// when the condition is not satisfied.
+    #[inline]
+    pub(crate) fn wait(&self, state: &mut WaitState, _limit: &WaitLimit) {
+        match self.strategy {
+            WaitStrategy::Spin => spin_loop(),
+            WaitStrategy::Backoff => {
+                for _ in 0..1 << state.step {
+                    spin_loop();
+                }
+
+                if state.step < BACKOFF_LIMIT {
+                    state.step += 1;
+                }
+            }
+            #[cfg(feature = "std")]
+            WaitStrategy::Yield => std::thread::yield_now(),
+            #[cfg(feature = "std")]
+            WaitStrategy::Park => {
+                let guard = self.lock.lock().unwrap_or_else(|e| e.into_inner());
+
+                if self.epoch.load(Relaxed) == state.epoch {
+                    match _limit.park_timeout() {
+                        Some(t) => drop(
+                            self.cvar
+                                .wait_timeout(guard, t)
+                                .unwrap_or_else(|e| e.into_inner()),
+                        ),
+                        None => drop(self.cvar.wait(guard).unwrap_or_else(|e| e.into_inner())),
+                    }
+                }
+
+                self.sleepers.fetch_sub(1, Relaxed);
+            }
+        }
+    }
+
+    /// Wakes up parked threads, if any. Must be called after moving an index.
+    #[inline]
+    pub(crate) fn notify(&self) {
+        #[cfg(feature = "std")]
+        if self.strategy == WaitStrategy::Park {
+            fence(SeqCst);
+
+            if self.sleepers.load(SeqCst) > 0 {
+                let _guard = self.lock.lock().unwrap_or_else(|e| e.into_inner());
+                self.epoch.fetch_add(1, Relaxed);
+                self.cvar.notify_all();
+            }
+        }
+    }
+}
@@ -1,10 +1,13 @@
 #![cfg(feature = "alloc")]
 
 use alloc::boxed::Box;
+use core::mem::ManuallyDrop;
 use core::ops::Deref;
 use core::ptr::NonNull;
 
-use crate::ring_buffer::{OneRB, iters_components::PIterComponent, wrappers::refs::BufRef};
+use crate::ring_buffer::{
+    OneRB, drop_owned_items, iters_components::PIterComponent, wrappers::refs::BufRef,
+};
 
 pub struct DroppableRef<B: OneRB> {
     inner: NonNull<B>,
@@ -21,6 +24,15 @@ impl<B: OneRB> From<B> for DroppableRef<B> {
 }
 
 impl<B: OneRB> DroppableRef<B> {
+    /// Takes the buffer back, without touching the count of alive iterators.
+    /// # Safety
+    /// Every other reference to the buffer must be forgotten.
+    pub(crate) unsafe fn into_inner(self) -> B {
+        let this = ManuallyDrop::new(self);
+
+        unsafe { *Box::from_raw(this.inner.as_ptr()) }
+    }
+
     #[inline(never)]
     fn try_drop(&mut self) {
         unsafe {
@@ -51,6 +63,8 @@ impl<B: OneRB> Drop for DroppableRef<B> {
 
         self.iters().acquire_fence();
 
+        drop_owned_items(&**self);
+
         self.try_drop();
     }
 }
@@ -1,7 +1,10 @@
+use core::mem::ManuallyDrop;
 use core::ops::Deref;
 use core::ptr::NonNull;
 
-use crate::ring_buffer::{OneRB, iters_components::PIterComponent, wrappers::refs::BufRef};
+use crate::ring_buffer::{
+    OneRB, drop_owned_items, iters_components::PIterComponent, wrappers::refs::BufRef,
+};
 
 pub struct NonDroppableRef<B: OneRB> {
     inner: NonNull<B>,
@@ -15,6 +18,18 @@ impl<B: OneRB> From<&mut B> for NonDroppableRef<B> {
     }
 }
 
+impl<B: OneRB> NonDroppableRef<B> {
+    /// Gives the mutable reference back, without touching the count of alive iterators.
+    /// # Safety
+    /// Every other reference to the buffer must be forgotten, and `'a` must not outlive
+    /// the reference this was created from.
+    pub(crate) unsafe fn into_mut<'a>(self) -> &'a mut B {
+        let mut this = ManuallyDrop::new(self);
+
+        unsafe { this.inner.as_mut() }
+    }
+}
+
 impl<B: OneRB> Clone for NonDroppableRef<B> {
     fn clone(&self) -> Self {
         Self { inner: self.inner }
@@ -36,6 +51,8 @@ impl<B: OneRB> Drop for NonDroppableRef<B> {
         }
 
         self.iters().acquire_fence();
+
+        drop_owned_items(&**self);
     }
 }
 
@@ -38,7 +38,7 @@ async fn async_fibonacci_test() {
         let mut counter = 1usize;
 
         while !stop_clone.load(Acquire) {
-            as_prod.push(counter).await;
+            as_prod.push(counter).await.unwrap();
 
             // Store produced values to check them later
             produced.push(counter);
@@ -66,7 +66,7 @@ async fn async_fibonacci_test() {
         while !prod_finished_clone.load(Acquire)
             || as_work.index() != prod_last_index_clone.load(Acquire)
         {
-            if let Some(value) = as_work.get_mut().await {
+            if let Ok(value) = as_work.get_mut().await {
                 let (bt_h, bt_t) = &mut acc;
 
                 if *value == 1 {
@@ -89,7 +89,7 @@ async fn async_fibonacci_test() {
 
         while !prod_finished.load(Acquire) || as_cons.index() != prod_last_index.load(Acquire) {
             // Store consumed values to check them later
-            if let Some(value) = as_cons.peek_ref().await {
+            if let Ok(value) = as_cons.peek_ref().await {
                 consumed.push(*value);
                 unsafe {
                     as_cons.advance(1);
@@ -1,6 +1,7 @@
 use std::time::Duration;
 
 use async_scoped::TokioScope;
+use oneringbuf::PopError;
 use oneringbuf::iterators::async_iterators::AsyncIterator;
 
 use crate::common_def;
@@ -23,7 +24,7 @@ async fn test_full() {
 
         let clone = slice.clone();
         s.spawn(async move {
-            as_prod.push_slice(&clone).await;
+            as_prod.push_slice(&clone).await.unwrap();
         });
 
         s.spawn(async move {
@@ -48,9 +49,9 @@ async fn test_push_work_pop_single_and_slice() {
 
     let (mut as_prod, mut as_work, mut as_cons) = buf.split_async_mut();
 
-    as_prod.push(1).await;
+    as_prod.push(1).await.unwrap();
 
-    if let Some(res) = as_work.get_mut().await {
+    if let Ok(res) = as_work.get_mut().await {
         *res += 1;
         unsafe {
             as_work.advance(1);
@@ -64,10 +65,10 @@ async fn test_push_work_pop_single_and_slice() {
     }
 
     let slice: Vec<i32> = (0..BUFFER_SIZE as i32 / 2).collect();
-    as_prod.push_slice(&slice).await;
+    as_prod.push_slice(&slice).await.unwrap();
 
     #[cfg(not(all(feature = "vmem", unix)))]
-    if let Some((h, t)) = as_work.get_mut_slice_avail().await {
+    if let Ok((h, t)) = as_work.get_mut_slice_avail().await {
         let len = h.len() + t.len();
 
         for x in h.iter_mut().chain(t) {
@@ -80,7 +81,7 @@ async fn test_push_work_pop_single_and_slice() {
     }
 
     #[cfg(all(feature = "vmem", unix))]
-    if let Some(r) = as_work.get_mut_slice_avail().await {
+    if let Ok(r) = as_work.get_mut_slice_avail().await {
         let len = r.len();
 
         for x in r {
@@ -93,14 +94,14 @@ async fn test_push_work_pop_single_and_slice() {
     }
 
     #[cfg(not(all(feature = "vmem", unix)))]
-    if let Some((h, t)) = as_cons.peek_available().await {
+    if let Ok((h, t)) = as_cons.peek_available().await {
         for (x, y) in h.iter().chain(t).zip(&slice) {
             assert_eq!(*x, y + 1);
         }
     }
 
     #[cfg(all(feature = "vmem", unix))]
-    if let Some(r) = as_cons.peek_available().await {
+    if let Ok(r) = as_cons.peek_available().await {
         for (x, y) in r.iter().zip(&slice) {
             assert_eq!(*x, y + 1);
         }
@@ -111,3 +112,85 @@ async fn test_push_work_pop_single_and_slice() {
     drop(as_work);
     assert_eq!(as_cons.alive_iters(), 1);
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_pipeline() {
+    #[cfg(all(not(feature = "alloc"), not(all(feature = "vmem", unix))))]
+    let mut buf = oneringbuf::AsyncStackRBPipeline::<_, BUFFER_SIZE, 2>::from([0; BUFFER_SIZE]);
+    #[cfg(all(feature = "alloc", not(all(feature = "vmem", unix))))]
+    let buf = oneringbuf::AsyncHeapRBPipeline::<_, 2>::from(vec![0; BUFFER_SIZE]);
+    #[cfg(all(feature = "alloc", feature = "vmem", unix))]
+    let buf = oneringbuf::AsyncVmemRBPipeline::<_, 2>::from(vec![0; BUFFER_SIZE]);
+
+    let (mut as_prod, works, mut as_cons) = buf.split_async_pipeline();
+
+    TokioScope::scope_and_block(|s| {
+        s.spawn(async move {
+            for i in 0..100 {
+                as_prod.push(i).await.unwrap();
+            }
+        });
+
+        for mut as_work in works {
+            s.spawn(async move {
+                let stage = as_work.stage() as i32;
+                for _ in 0..100 {
+                    if let Ok(x) = as_work.get_mut().await {
+                        *x = *x * 10 + stage;
+                        unsafe {
+                            as_work.advance(1);
+                        }
+                    }
+                }
+            });
+        }
+
+        s.spawn(async move {
+            for i in 0..100 {
+                assert_eq!(as_cons.pop().await.unwrap(), i * 100 + 1);
+            }
+        });
+    });
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_disconnect() {
+    #[cfg(all(not(feature = "alloc"), not(all(feature = "vmem", unix))))]
+    let mut buf = oneringbuf::AsyncStackRB::from([0; BUFFER_SIZE]);
+    #[cfg(all(feature = "alloc", not(all(feature = "vmem", unix))))]
+    let buf = oneringbuf::AsyncHeapRB::from(vec![0; BUFFER_SIZE]);
+    #[cfg(all(feature = "alloc", feature = "vmem", unix))]
+    let buf = oneringbuf::AsyncVmemRB::from(vec![0; BUFFER_SIZE]);
+
+    let (mut as_prod, mut as_cons) = buf.split_async();
+
+    TokioScope::scope_and_block(|s| {
+        s.spawn(async move {
+            assert_eq!(as_cons.pop().await, Ok(1));
+            // Resolves once the producer is dropped.
+            assert_eq!(as_cons.pop().await, Err(PopError::Disconnected));
+            assert!(as_cons.is_disconnected());
+        });
+
+        s.spawn(async move {
+            as_prod.push(1).await.unwrap();
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            drop(as_prod);
+        });
+    });
+}
+
+#[tokio::test]
+async fn test_get_mut_disconnected() {
+    #[cfg(all(not(feature = "alloc"), not(all(feature = "vmem", unix))))]
+    let mut buf = oneringbuf::AsyncStackRBMut::from([0; BUFFER_SIZE]);
+    #[cfg(all(feature = "alloc", not(all(feature = "vmem", unix))))]
+    let buf = oneringbuf::AsyncHeapRBMut::from(vec![0; BUFFER_SIZE]);
+    #[cfg(all(feature = "alloc", feature = "vmem", unix))]
+    let buf = oneringbuf::AsyncVmemRBMut::from(vec![0; BUFFER_SIZE]);
+
+    let (as_prod, mut as_work, _as_cons) = buf.split_async_mut();
+
+    drop(as_prod);
+    assert_eq!(as_work.get_mut().await, Err(PopError::Disconnected));
+}
@@ -1,5 +1,5 @@
 use crate::common_def;
-use oneringbuf::{LocalStackRBMut, ORBIterator as ORBIt, SharedStackRBMut};
+use oneringbuf::{LocalStackRBMut, ORBIterator as ORBIt, SharedStackRB, SharedStackRBMut};
 use std::{
     sync::Arc,
     sync::atomic::Ordering::{Acquire, Release},
@@ -157,6 +157,29 @@ fn fibonacci_test_stack() {
     }
 }
 
+#[test]
+fn test_uninit_stack() {
+    let mut buf = SharedStackRB::<usize, BUFFER_SIZE>::new_zeroed();
+    let (mut prod, mut cons) = buf.split();
+
+    // Items can be consumed during the first lap.
+    prod.push_slice(&[1, 2, 3]).unwrap();
+    assert_eq!(cons.pop(), Ok(1));
+    assert_eq!(prod.filled(), 3);
+
+    let mut i = 0;
+    while !prod.is_init() {
+        prod.push_blocking(i).unwrap();
+        cons.pop().unwrap();
+        i += 1;
+    }
+    assert_eq!(i, BUFFER_SIZE - 3);
+
+    let mut prod = prod.into_init().ok().unwrap();
+    prod.push(1).unwrap();
+    assert_eq!(cons.available(), 3);
+}
+
 pub fn fib(n: usize) -> usize {
     match n {
         1 | 2 => 1,
@@ -8,25 +8,31 @@ fn test_uninit() {
 
     common_def!();
 
-    // Indices from 0 to RB_SIZE+1 are uninitialised
-    let buf = unsafe { SharedHeapRB::new_zeroed(BUFFER_SIZE) };
-    let (mut prod, mut cons) = buf.split();
-
     let slice = (0..BUFFER_SIZE - 1)
         .map(Rc::new)
         .collect::<Vec<Rc<usize>>>();
 
+    // Indices from 0 to RB_SIZE+1 are uninitialised, so the producer can only perform initialising writes.
+    let buf = SharedHeapRB::new_zeroed(BUFFER_SIZE);
+    let (mut prod, mut cons) = buf.split();
+
     for x in &slice {
-        prod.push_init(x.clone()).unwrap();
+        prod.push(x.clone()).unwrap();
     } // RB_SIZE indices out of RB_SIZE+1 are initialised.
+    assert_eq!(prod.filled(), BUFFER_SIZE - 1);
 
     unsafe {
         cons.advance(BUFFER_SIZE - 1);
     }
 
+    // The producer has not lapped the whole storage yet.
+    let mut prod = prod.into_init().err().unwrap();
+
     for x in &slice {
-        prod.push_init(x.clone()).unwrap();
-    } // All indices are now initialised. It would be now safe to use normal methods from prod instead of `*_init` ones.
+        prod.push(x.clone()).unwrap();
+    } // All indices are now initialised, so normal methods can be used.
+    assert!(prod.is_init());
+    let mut prod = prod.into_init().ok().unwrap();
 
     for _ in &slice {
         unsafe {
@@ -59,3 +65,89 @@ fn test_uninit() {
     drop(prod);
     drop(cons);
 }
+
+#[test]
+fn test_uninit_rb() {
+    use crate::common_def;
+    use oneringbuf::SharedHeapRB;
+    use std::rc::Rc;
+
+    common_def!();
+
+    let item = Rc::new(0);
+
+    let mut buf = SharedHeapRB::new_zeroed(BUFFER_SIZE);
+    buf.push_iter((0..BUFFER_SIZE / 2).map(|_| item.clone()));
+    assert_eq!(buf.filled(), BUFFER_SIZE / 2);
+    assert_eq!(Rc::strong_count(&item), BUFFER_SIZE / 2 + 1);
+
+    // The buffer cannot be converted until every item has been written.
+    let mut buf = buf.into_init().err().unwrap();
+    assert!(!buf.is_init());
+
+    while buf.push(item.clone()).is_ok() {}
+    assert!(buf.is_init());
+    assert_eq!(Rc::strong_count(&item), BUFFER_SIZE + 1);
+
+    let buf = buf.into_init().ok().unwrap();
+    drop(buf);
+    assert_eq!(Rc::strong_count(&item), 1);
+
+    // Items written into a partially initialised buffer are dropped along with it.
+    let mut buf = SharedHeapRB::new_zeroed(BUFFER_SIZE);
+    buf.push(item.clone()).unwrap();
+    assert_eq!(Rc::strong_count(&item), 2);
+    drop(buf);
+    assert_eq!(Rc::strong_count(&item), 1);
+
+    // Items written before splitting are dropped when the producer overwrites them.
+    let mut buf = SharedHeapRB::new_zeroed(BUFFER_SIZE);
+    buf.push(item.clone()).unwrap();
+    let (mut prod, cons) = buf.split();
+    assert_eq!(prod.filled(), 1);
+    prod.push(Rc::new(1)).unwrap();
+    prod.push(item.clone()).unwrap();
+    assert_eq!(prod.filled(), 2);
+    assert_eq!(Rc::strong_count(&item), 2);
+    drop(prod);
+    drop(cons);
+    assert_eq!(Rc::strong_count(&item), 1);
+}
+
+#[test]
+fn test_uninit_zero_bits_items() {
+    use crate::common_def;
+    use oneringbuf::{ORBIterator, SharedHeapRB};
+    use std::cell::Cell;
+
+    common_def!();
+
+    thread_local! {
+        static DROPS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    // Made up of zero bits, yet it has to be dropped.
+    #[derive(Clone, Debug)]
+    struct Zero(#[allow(dead_code)] usize);
+    impl Drop for Zero {
+        fn drop(&mut self) {
+            DROPS.set(DROPS.get() + 1);
+        }
+    }
+
+    let buf = SharedHeapRB::new_zeroed(BUFFER_SIZE);
+    let (mut prod, mut cons) = buf.split();
+    for _ in 0..BUFFER_SIZE {
+        prod.push(Zero(0)).unwrap();
+        unsafe { cons.advance(1) };
+    }
+    assert!(prod.is_init());
+    assert_eq!(DROPS.get(), 0);
+
+    // Initialised locations are assigned, dropping the items they held.
+    prod.push(Zero(0)).unwrap();
+    assert_eq!(DROPS.get(), 1);
+    let src = [Zero(0), Zero(0)];
+    prod.push_slice_clone(&src).unwrap();
+    assert_eq!(DROPS.get(), 3);
+}
@@ -0,0 +1,158 @@
+extern crate alloc;
+
+use crate::{common_def, get_buf, split_broadcast};
+use oneringbuf::ORBIterator;
+use std::thread;
+
+common_def!();
+
+#[test]
+fn test_broadcast_every_reader_sees_all() {
+    let mut buf = get_buf!(Broadcast, 3);
+    let (mut prod, mut readers) = split_broadcast!(buf, 3);
+    assert_eq!(prod.alive_iters(), 4);
+
+    for (i, r) in readers.iter().enumerate() {
+        assert_eq!(r.reader(), i);
+    }
+
+    prod.push_slice(&[1, 2, 3]).unwrap();
+
+    for r in readers.iter_mut() {
+        let mut dst = [0; 3];
+        r.copy_slice(&mut dst).unwrap();
+        assert_eq!(dst, [1, 2, 3]);
+        assert!(r.pop().is_err());
+    }
+}
+
+#[test]
+fn test_broadcast_slowest_reader() {
+    let mut buf = get_buf!(Broadcast, 2);
+    let (mut prod, mut readers) = split_broadcast!(buf, 2);
+    let (mut slow, mut fast) = (readers.pop().unwrap(), readers.pop().unwrap());
+
+    prod.push_slice(&[0; BUFFER_SIZE - 1]).unwrap();
+    assert_eq!(prod.available(), 0);
+
+    unsafe { fast.advance(BUFFER_SIZE - 1) };
+    assert_eq!(prod.available(), 0);
+    assert_eq!(fast.reader_index(0), Some(BUFFER_SIZE - 1));
+    assert_eq!(slow.reader_index(1), Some(0));
+
+    unsafe { slow.advance(10) };
+    assert_eq!(prod.available(), 10);
+    assert_eq!(prod.cons_index(), 10);
+
+    // Now the fast reader has wrapped less than the producer, while the slow one has not.
+    prod.push_slice(&[1; 10]).unwrap();
+    assert_eq!(prod.available(), 0);
+    assert_eq!(fast.available(), 10);
+
+    assert_eq!(slow.available(), BUFFER_SIZE - 1);
+    assert!(slow.peek_slice(BUFFER_SIZE - 1).is_ok());
+    unsafe { slow.advance(BUFFER_SIZE - 1) };
+
+    assert_eq!(prod.available(), BUFFER_SIZE - 11);
+    unsafe { fast.advance(10) };
+    assert_eq!(prod.available(), BUFFER_SIZE - 1);
+}
+
+#[test]
+fn test_broadcast_mt() {
+    const COUNT: usize = 2_000;
+
+    let mut buf = get_buf!(Broadcast, 3);
+    let (mut prod, readers) = split_broadcast!(buf, 3);
+
+    thread::scope(|s| {
+        s.spawn(move || {
+            let mut i = 0;
+            while i < COUNT {
+                match prod.push(i) {
+                    Ok(_) => i += 1,
+                    Err(_) => thread::yield_now(),
+                }
+            }
+        });
+
+        for mut r in readers {
+            s.spawn(move || {
+                for i in 0..COUNT {
+                    let v = loop {
+                        match r.pop() {
+                            Ok(v) => break v,
+                            Err(_) => thread::yield_now(),
+                        }
+                    };
+                    assert_eq!(v, i);
+                }
+            });
+        }
+    });
+}
+
+#[test]
+fn test_broadcast_dropped_reader() {
+    let mut buf = get_buf!(Broadcast, 2);
+    let (mut prod, mut readers) = split_broadcast!(buf, 2);
+    let (mut r1, r0) = (readers.pop().unwrap(), readers.pop().unwrap());
+
+    prod.push_slice(&[1, 2, 3]).unwrap();
+    assert_eq!(r1.pop(), Ok(1));
+    assert_eq!(prod.cons_index(), 0);
+
+    // The index of a dropped reader never moves again, so it no longer holds the producer back.
+    drop(r0);
+    assert_eq!(prod.cons_index(), 1);
+
+    for i in 0..BUFFER_SIZE * 2 {
+        prod.push(i).unwrap();
+        r1.pop().unwrap();
+    }
+    assert_eq!(prod.available(), BUFFER_SIZE - 3);
+}
+
+#[test]
+fn test_broadcast_fewer_readers() {
+    let mut buf = get_buf!(Broadcast, 4);
+    let (mut prod, mut readers) = split_broadcast!(buf, 2);
+    assert_eq!(readers.len(), 2);
+    assert_eq!(prod.alive_iters(), 3);
+
+    // The readers which have not been handed out never hold the producer back.
+    assert!(!prod.reader_alive(2) && !prod.reader_alive(3));
+    for i in 0..BUFFER_SIZE * 2 {
+        prod.push(i).unwrap();
+        for r in readers.iter_mut() {
+            assert_eq!(r.pop(), Ok(i));
+        }
+    }
+    assert_eq!(prod.available(), BUFFER_SIZE - 1);
+}
+
+#[test]
+#[should_panic]
+fn test_broadcast_too_many_readers() {
+    let mut buf = get_buf!(Broadcast, 2);
+    let _ = split_broadcast!(buf, 3);
+}
+
+#[cfg(not(all(feature = "vmem", unix)))]
+#[test]
+fn test_broadcast_stack_readers() {
+    let mut buf = oneringbuf::BroadcastStackRB::<usize, 16, 3>::from([0; 16]);
+    let (mut prod, readers) = buf.split_broadcast(2);
+    assert!(readers[0].is_some() && readers[1].is_some() && readers[2].is_none());
+    assert_eq!(prod.alive_iters(), 3);
+    assert!(!prod.reader_alive(2));
+
+    let [Some(mut r0), Some(mut r1), None] = readers else {
+        unreachable!()
+    };
+    prod.push_slice(&[1, 2]).unwrap();
+    assert_eq!((r0.pop(), r1.pop()), (Ok(1), Ok(1)));
+    drop(r0);
+    assert_eq!(r1.pop(), Ok(2));
+    assert_eq!(prod.available(), 15);
+}
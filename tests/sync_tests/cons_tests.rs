@@ -1,6 +1,6 @@
 use crate::{common_def, get_buf};
 use oneringbuf::iterators::ProdIter;
-use oneringbuf::{IntoRef, ORBIterator, OneRB};
+use oneringbuf::{IntoRef, ORBIterator, OneRB, SliceError};
 
 common_def!();
 
@@ -15,7 +15,7 @@ fn test_pop_exact() {
     let mut buf = get_buf!(Shared);
     let (mut prod, mut cons) = buf.split();
 
-    assert!(cons.pop().is_none());
+    assert!(cons.pop().is_err());
 
     fill_buf(&mut prod, BUFFER_SIZE - 1);
 
@@ -40,7 +40,7 @@ fn test_pop_exact() {
         }
     }
 
-    assert!(cons.pop().is_none());
+    assert!(cons.pop().is_err());
 }
 
 #[test]
@@ -55,7 +55,7 @@ fn test_pop_ref_exact() {
         unsafe { cons.advance(1) };
     }
 
-    assert!(cons.pop().is_none());
+    assert!(cons.pop().is_err());
 }
 
 #[cfg(not(all(feature = "vmem", unix)))]
@@ -74,7 +74,7 @@ fn test_pop_slice_exact() {
     }
     unsafe { cons.advance(BUFFER_SIZE - 1) };
 
-    assert!(cons.pop().is_none());
+    assert!(cons.pop().is_err());
 }
 
 #[cfg(not(all(feature = "vmem", unix)))]
@@ -93,7 +93,7 @@ fn test_pop_avail_nw_exact() {
     }
     unsafe { cons.advance(BUFFER_SIZE - 1) };
 
-    assert!(cons.pop().is_none());
+    assert!(cons.pop().is_err());
 }
 
 #[cfg(not(all(feature = "vmem", unix)))]
@@ -120,7 +120,7 @@ fn test_pop_slice_seam() {
     }
     unsafe { cons.advance(BUFFER_SIZE - 1) };
 
-    assert!(cons.pop().is_none());
+    assert!(cons.pop().is_err());
 }
 
 #[test]
@@ -132,23 +132,293 @@ fn test_pop_slice_copy() {
 
     let mut vec = vec![0; BUFFER_SIZE / 2];
 
-    assert!(cons.copy_slice(&mut vec).is_some());
-    assert!(cons.copy_slice(&mut vec).is_none());
+    assert!(cons.copy_slice(&mut vec).is_ok());
+    assert!(cons.copy_slice(&mut vec).is_err());
 
     fill_buf(&mut prod, BUFFER_SIZE / 2);
 
-    assert!(cons.clone_slice(&mut vec).is_some());
-    assert!(cons.clone_slice(&mut vec).is_none());
+    assert!(cons.clone_slice(&mut vec).is_ok());
+    assert!(cons.clone_slice(&mut vec).is_err());
 
     let _ = prod.push(1);
 
     let mut dst = 0;
 
-    assert!(cons.copy_item(&mut dst).is_some());
-    assert!(cons.copy_item(&mut dst).is_none());
+    assert!(cons.copy_item(&mut dst).is_ok());
+    assert!(cons.copy_item(&mut dst).is_err());
 
     let _ = prod.push(1);
 
-    assert!(cons.clone_item(&mut dst).is_some());
-    assert!(cons.clone_item(&mut dst).is_none());
+    assert!(cons.clone_item(&mut dst).is_ok());
+    assert!(cons.clone_item(&mut dst).is_err());
+}
+
+#[test]
+fn test_pop_slice_partial() {
+    let mut buf = get_buf!(Shared);
+    let (mut prod, mut cons) = buf.split();
+
+    let mut dst = vec![0; BUFFER_SIZE];
+    assert_eq!(cons.copy_slice_partial(&mut dst), 0);
+
+    fill_buf(&mut prod, BUFFER_SIZE / 2);
+    assert_eq!(cons.copy_slice_partial(&mut dst[..3]), 3);
+    assert_eq!(&dst[..3], &[0, 1, 2]);
+
+    assert_eq!(cons.copy_slice_partial(&mut dst), BUFFER_SIZE / 2 - 3);
+    assert_eq!(dst[0], 3);
+
+    // Read across the end of the buffer.
+    fill_buf(&mut prod, BUFFER_SIZE - 1);
+    assert_eq!(cons.clone_slice_partial(&mut dst), BUFFER_SIZE - 1);
+    for (x, i) in dst.iter().zip(0..BUFFER_SIZE - 1) {
+        assert_eq!(*x, i);
+    }
+    assert_eq!(cons.available(), 0);
+}
+
+#[test]
+fn test_iter_and_drain_cloned() {
+    let mut buf = get_buf!(Shared);
+    let (mut prod, mut cons) = buf.split();
+
+    // Move the indices, so that the items wrap around the end of the buffer.
+    fill_buf(&mut prod, BUFFER_SIZE / 2);
+    unsafe { cons.advance(BUFFER_SIZE / 2) };
+    fill_buf(&mut prod, BUFFER_SIZE - 1);
+
+    let iter = cons.iter();
+    assert_eq!(iter.len(), BUFFER_SIZE - 1);
+    assert!(iter.copied().eq(0..BUFFER_SIZE - 1));
+    // Iterating does not move the consumer.
+    assert_eq!(cons.available(), BUFFER_SIZE - 1);
+
+    let mut drain = cons.drain_cloned();
+    assert!(drain.by_ref().take(10).eq(0..10));
+    // The index is published only once the iterator is dropped.
+    assert_eq!(prod.available(), 0);
+    drop(drain);
+    assert_eq!(prod.available(), 10);
+
+    assert_eq!(
+        cons.drain_cloned().sum::<usize>(),
+        (10..BUFFER_SIZE - 1).sum()
+    );
+    assert_eq!(cons.available(), 0);
+    assert_eq!(cons.iter().next(), None);
+}
+
+#[test]
+fn test_peek_at_and_view() {
+    let mut buf = get_buf!(Shared);
+    let (mut prod, mut cons) = buf.split();
+
+    // Move the head close to the end of the buffer, so that items wrap around.
+    fill_buf(&mut prod, BUFFER_SIZE - 10);
+    unsafe { cons.advance(BUFFER_SIZE - 10) };
+    fill_buf(&mut prod, 20);
+
+    assert_eq!(cons.peek_at(0), Some(&0));
+    assert_eq!(cons.peek_at(15), Some(&15));
+    assert_eq!(cons.peek_at(20), None);
+
+    let view = cons.view();
+    assert_eq!(view.len(), 20);
+    assert_eq!(view[9], 9);
+    assert_eq!(view[10], 10);
+    assert_eq!(view.get(20), None);
+    assert!(view.iter().copied().eq(0..20));
+
+    assert_eq!(
+        cons.peek_slice_at(15, 6).unwrap_err(),
+        SliceError::Insufficient {
+            requested: 21,
+            available: 20
+        }
+    );
+    assert_eq!(cons.pop(), Ok(0));
+}
+
+#[cfg(not(all(feature = "vmem", unix)))]
+#[test]
+fn test_peek_slice_at_seam() {
+    let mut buf = get_buf!(Shared);
+    let (mut prod, mut cons) = buf.split();
+
+    fill_buf(&mut prod, BUFFER_SIZE - 10);
+    unsafe { cons.advance(BUFFER_SIZE - 10) };
+    fill_buf(&mut prod, 20);
+
+    let (head, tail) = cons.peek_slice_at(5, 10).unwrap();
+    assert_eq!(head, &[5, 6, 7, 8, 9]);
+    assert_eq!(tail, &[10, 11, 12, 13, 14]);
+
+    let (head, tail) = cons.peek_slice_at(12, 3).unwrap();
+    assert_eq!(head, &[12, 13, 14]);
+    assert!(tail.is_empty());
+
+    let (head, tail) = cons.view().as_slices();
+    assert_eq!([head, tail].concat(), (0..20).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_find_and_read_until() {
+    let mut buf = get_buf!(Shared);
+    let (mut prod, mut cons) = buf.split();
+
+    // Frames cross the end of the buffer.
+    fill_buf(&mut prod, BUFFER_SIZE - 3);
+    unsafe { cons.advance(BUFFER_SIZE - 3) };
+    prod.push_slice(&[1, 2, 0, 3, 4, 5, 0, 6]).unwrap();
+
+    assert_eq!(cons.find(|&x| x > 3), Some(4));
+    assert_eq!(cons.position_of(&0), Some(2));
+    assert_eq!(cons.position_of(&7), None);
+    assert_eq!(cons.find_subslice(&[0, 3, 4]), Some(2));
+    assert_eq!(cons.find_subslice(&[5, 0, 6]), Some(5));
+    assert_eq!(cons.find_subslice(&[6, 0]), None);
+    assert_eq!(cons.find_subslice(&[]), Some(0));
+
+    let mut dst = [usize::MAX; 4];
+    assert_eq!(cons.read_until(&0, &mut dst), Some(3));
+    assert_eq!(dst[..3], [1, 2, 0]);
+
+    // The next delimiter does not fit in `dst`.
+    assert_eq!(cons.read_until(&0, &mut dst[..3]), None);
+    assert_eq!(cons.read_until(&0, &mut dst), Some(4));
+    assert_eq!(dst, [3, 4, 5, 0]);
+
+    assert_eq!(cons.read_until(&0, &mut dst), None);
+    assert_eq!(cons.pop(), Ok(6));
+}
+
+#[cfg(not(all(feature = "vmem", unix)))]
+#[test]
+fn test_history() {
+    let mut buf = get_buf!(Shared).with_lookback(4);
+    let (mut prod, mut cons) = buf.split();
+
+    // The last items consumed are never overwritten.
+    assert_eq!(prod.available(), BUFFER_SIZE - 5);
+    fill_buf(&mut prod, BUFFER_SIZE - 5);
+    assert!(prod.push(0).is_err());
+
+    for _ in 0..BUFFER_SIZE - 8 {
+        cons.pop().unwrap();
+    }
+    assert_eq!(prod.available(), BUFFER_SIZE - 8);
+    assert_eq!(cons.observer().free(), BUFFER_SIZE - 8);
+
+    // History and new items cross the end of the buffer.
+    for i in 0..6 {
+        prod.push(100 + i).unwrap();
+    }
+    let (head, tail) = cons.history(4).unwrap();
+    assert_eq!(head.len(), 12);
+    assert_eq!(tail, &[105]);
+    assert!(
+        [head, tail]
+            .concat()
+            .into_iter()
+            .eq((BUFFER_SIZE - 12..BUFFER_SIZE - 5).chain(100..106))
+    );
+
+    let (head, tail) = cons.history(0).unwrap();
+    assert_eq!(head.len() + tail.len(), cons.available());
+    assert!(cons.history(5).is_none());
+}
+
+#[cfg(not(all(feature = "vmem", unix)))]
+#[test]
+fn test_windows() {
+    let mut buf = get_buf!(Shared);
+    let (mut prod, mut cons) = buf.split();
+
+    // Windows cross the end of the buffer.
+    fill_buf(&mut prod, BUFFER_SIZE - 4);
+    unsafe { cons.advance(BUFFER_SIZE - 4) };
+    fill_buf(&mut prod, 10);
+
+    let mut windows = cons.windows(4, 3);
+    assert_eq!(windows.next_window(), Some((&[0, 1, 2, 3][..], &[][..])));
+    assert_eq!(windows.next_window(), Some((&[3][..], &[4, 5, 6][..])));
+    assert_eq!(windows.next_window(), Some((&[6, 7, 8, 9][..], &[][..])));
+    assert_eq!(windows.next_window(), None);
+    assert_eq!(windows.next_window(), None);
+    drop(windows);
+
+    // The consumer stays at the start of the window which was not available.
+    assert_eq!(cons.available(), 1);
+    assert_eq!(cons.pop(), Ok(9));
+}
+
+#[test]
+#[should_panic]
+fn test_windows_past_lookback() {
+    let mut buf = get_buf!(Shared).with_lookback(4);
+    let (_prod, mut cons) = buf.split();
+
+    // Only `BUFFER_SIZE - 5` items can ever be available.
+    let _ = cons.windows(BUFFER_SIZE - 4, 1);
+}
+
+#[test]
+fn test_windows_blocking() {
+    const WINDOWS: usize = 1000;
+
+    let mut buf = get_buf!(Shared);
+    let (mut prod, mut cons) = buf.split();
+
+    std::thread::scope(|s| {
+        s.spawn(move || {
+            for i in 0..WINDOWS * 2 + 2 {
+                prod.push_blocking(i).unwrap();
+            }
+        });
+
+        let mut windows = cons.windows(4, 2);
+        for i in 0..WINDOWS {
+            let window = windows.next_window_blocking().unwrap();
+            let mut view = [0; 4];
+            #[cfg(all(feature = "vmem", unix))]
+            view.copy_from_slice(window);
+            #[cfg(not(all(feature = "vmem", unix)))]
+            view.copy_from_slice(&[window.0, window.1].concat());
+
+            assert_eq!(view, [i * 2, i * 2 + 1, i * 2 + 2, i * 2 + 3]);
+        }
+    });
+}
+
+#[cfg(not(all(feature = "vmem", unix)))]
+#[test]
+fn test_skip_to_latest() {
+    let mut buf = get_buf!(Shared);
+    let (mut prod, mut cons) = buf.split();
+
+    // The newest items cross the end of the buffer.
+    fill_buf(&mut prod, BUFFER_SIZE - 3);
+    unsafe { cons.advance(BUFFER_SIZE - 10) };
+    fill_buf(&mut prod, 5);
+
+    assert_eq!(cons.available(), 12);
+    assert_eq!(
+        cons.peek_latest(6).unwrap(),
+        (&[BUFFER_SIZE - 4, 0, 1, 2][..], &[3, 4][..])
+    );
+    assert_eq!(
+        cons.peek_latest(13).unwrap_err(),
+        SliceError::Insufficient {
+            requested: 13,
+            available: 12
+        }
+    );
+    assert_eq!(cons.available(), 12);
+
+    assert_eq!(cons.skip_to_latest(4), 8);
+    assert_eq!(cons.skip_to_latest(4), 0);
+    assert_eq!(cons.pop(), Ok(1));
+    assert_eq!(cons.skip_to_latest(10), 0);
+    assert_eq!(cons.skip_to_latest(0), 3);
+    assert_eq!(cons.available(), 0);
 }
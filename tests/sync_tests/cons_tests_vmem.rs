@@ -15,7 +15,7 @@ fn test_pop_exact() {
     let buf = get_buf!(Shared);
     let (mut prod, mut cons) = buf.split();
 
-    assert!(cons.pop().is_none());
+    assert!(cons.pop().is_err());
 
     fill_buf(&mut prod, BUFFER_SIZE - 1);
 
@@ -40,7 +40,7 @@ fn test_pop_exact() {
         }
     }
 
-    assert!(cons.pop().is_none());
+    assert!(cons.pop().is_err());
 }
 
 #[test]
@@ -54,7 +54,7 @@ fn test_pop_ref_exact() {
         unsafe { cons.advance(1) };
     }
 
-    assert!(cons.pop().is_none());
+    assert!(cons.pop().is_err());
 }
 
 #[test]
@@ -71,7 +71,7 @@ fn test_pop_slice_exact() {
     }
     unsafe { cons.advance(BUFFER_SIZE - 1) };
 
-    assert!(cons.pop().is_none());
+    assert!(cons.pop().is_err());
 }
 
 #[test]
@@ -88,7 +88,7 @@ fn test_pop_avail_nw_exact() {
     }
     unsafe { cons.advance(BUFFER_SIZE - 1) };
 
-    assert!(cons.pop().is_none());
+    assert!(cons.pop().is_err());
 }
 
 #[test]
@@ -111,7 +111,7 @@ fn test_pop_slice_seam() {
     }
     unsafe { cons.advance(BUFFER_SIZE - 1) };
 
-    assert!(cons.pop().is_none());
+    assert!(cons.pop().is_err());
 }
 
 #[test]
@@ -122,23 +122,108 @@ fn test_pop_slice_copy() {
 
     let mut vec = vec![0; BUFFER_SIZE / 2];
 
-    assert!(cons.copy_slice(&mut vec).is_some());
-    assert!(cons.copy_slice(&mut vec).is_none());
+    assert!(cons.copy_slice(&mut vec).is_ok());
+    assert!(cons.copy_slice(&mut vec).is_err());
 
     fill_buf(&mut prod, BUFFER_SIZE / 2);
 
-    assert!(cons.clone_slice(&mut vec).is_some());
-    assert!(cons.clone_slice(&mut vec).is_none());
+    assert!(cons.clone_slice(&mut vec).is_ok());
+    assert!(cons.clone_slice(&mut vec).is_err());
 
     let _ = prod.push(1);
 
     let mut dst = 0;
 
-    assert!(cons.copy_item(&mut dst).is_some());
-    assert!(cons.copy_item(&mut dst).is_none());
+    assert!(cons.copy_item(&mut dst).is_ok());
+    assert!(cons.copy_item(&mut dst).is_err());
 
     let _ = prod.push(1);
 
-    assert!(cons.clone_item(&mut dst).is_some());
-    assert!(cons.clone_item(&mut dst).is_none());
+    assert!(cons.clone_item(&mut dst).is_ok());
+    assert!(cons.clone_item(&mut dst).is_err());
+}
+
+#[test]
+fn test_peek_slice_at_seam() {
+    let buf = get_buf!(Shared);
+    let (mut prod, mut cons) = buf.split();
+
+    fill_buf(&mut prod, BUFFER_SIZE - 10);
+    unsafe { cons.advance(BUFFER_SIZE - 10) };
+    fill_buf(&mut prod, 20);
+
+    assert_eq!(
+        cons.peek_slice_at(5, 10).unwrap(),
+        &[5, 6, 7, 8, 9, 10, 11, 12, 13, 14]
+    );
+    assert_eq!(cons.view().as_slices(), (0..20).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_history() {
+    let buf = get_buf!(Shared).with_lookback(4);
+    let (mut prod, mut cons) = buf.split();
+
+    fill_buf(&mut prod, BUFFER_SIZE - 5);
+    for _ in 0..BUFFER_SIZE - 7 {
+        cons.pop().unwrap();
+    }
+
+    // History and new items cross the end of the buffer.
+    for i in 0..3 {
+        prod.push(100 + i).unwrap();
+    }
+    assert_eq!(
+        cons.history(4).unwrap(),
+        &[
+            BUFFER_SIZE - 11,
+            BUFFER_SIZE - 10,
+            BUFFER_SIZE - 9,
+            BUFFER_SIZE - 8,
+            BUFFER_SIZE - 7,
+            BUFFER_SIZE - 6,
+            100,
+            101,
+            102
+        ]
+    );
+    assert!(cons.history(5).is_none());
+}
+
+#[test]
+fn test_windows() {
+    let buf = get_buf!(Shared);
+    let (mut prod, mut cons) = buf.split();
+
+    // Windows cross the end of the buffer.
+    fill_buf(&mut prod, BUFFER_SIZE - 4);
+    unsafe { cons.advance(BUFFER_SIZE - 4) };
+    fill_buf(&mut prod, 10);
+
+    let mut windows = cons.windows(4, 3);
+    assert_eq!(windows.next_window(), Some(&[0, 1, 2, 3][..]));
+    assert_eq!(windows.next_window(), Some(&[3, 4, 5, 6][..]));
+    assert_eq!(windows.next_window(), Some(&[6, 7, 8, 9][..]));
+    assert_eq!(windows.next_window(), None);
+    drop(windows);
+
+    assert_eq!(cons.available(), 1);
+}
+
+#[test]
+fn test_skip_to_latest() {
+    let buf = get_buf!(Shared);
+    let (mut prod, mut cons) = buf.split();
+
+    // The newest items cross the end of the buffer.
+    fill_buf(&mut prod, BUFFER_SIZE - 3);
+    unsafe { cons.advance(BUFFER_SIZE - 10) };
+    fill_buf(&mut prod, 5);
+
+    assert_eq!(
+        cons.peek_latest(6).unwrap(),
+        &[BUFFER_SIZE - 4, 0, 1, 2, 3, 4]
+    );
+    assert_eq!(cons.skip_to_latest(4), 8);
+    assert_eq!(cons.pop(), Ok(1));
 }
@@ -9,7 +9,7 @@ common_def!();
 
 fn fill_buf(prod: &mut ProdIter<impl IntoRef + OneRB<Item = usize>>) {
     let slice = (0..BUFFER_SIZE - 1).collect::<Vec<usize>>();
-    prod.push_slice(&slice);
+    prod.push_slice(&slice).unwrap();
 }
 
 #[allow(clippy::type_complexity)]
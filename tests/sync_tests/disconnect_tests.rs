@@ -0,0 +1,153 @@
+extern crate alloc;
+
+use crate::{common_def, get_buf, split_broadcast};
+use oneringbuf::{ORBIterator, PopError, PushError, SliceError, WaitStrategy};
+use std::thread;
+
+common_def!();
+
+#[test]
+fn test_liveness() {
+    let mut buf = get_buf!(SharedMut);
+    let (prod, work, cons) = buf.split_mut();
+
+    assert!(cons.prod_alive() && cons.work_alive() && prod.cons_alive());
+
+    drop(work);
+    assert!(!prod.work_alive() && !cons.stage_alive(0));
+    assert!(prod.is_disconnected());
+
+    drop(cons);
+    assert!(!prod.cons_alive() && !prod.reader_alive(0));
+    assert!(prod.prod_alive());
+}
+
+#[test]
+fn test_cons_disconnected_after_drain() {
+    let mut buf = get_buf!(Shared);
+    let (mut prod, mut cons) = buf.split();
+
+    prod.push_slice(&[1, 2]).unwrap();
+    drop(prod);
+
+    assert!(!cons.prod_alive());
+    assert!(!cons.is_disconnected());
+    assert_eq!(cons.pop(), Ok(1));
+    assert!(!cons.is_disconnected());
+    assert_eq!(cons.pop(), Ok(2));
+    assert!(cons.is_disconnected());
+    assert_eq!(cons.pop_blocking(), Err(PopError::Disconnected));
+}
+
+#[test]
+fn test_chain_disconnected() {
+    let mut buf = get_buf!(SharedMut);
+    let (mut prod, mut work, mut cons) = buf.split_mut();
+
+    prod.push_slice(&[1, 2, 3]).unwrap();
+    drop(prod);
+
+    // The worker is still alive and has items left: the consumer has to wait.
+    unsafe { work.advance(1) };
+    assert_eq!(cons.pop(), Ok(1));
+    assert!(!cons.is_disconnected());
+
+    // Once the worker catches up with the dead producer, no more items can reach the consumer.
+    unsafe { work.advance(2) };
+    assert!(work.is_disconnected());
+    assert!(!cons.is_disconnected());
+    assert_eq!(cons.pop(), Ok(2));
+    assert_eq!(cons.pop(), Ok(3));
+    assert!(cons.is_disconnected());
+}
+
+#[test]
+fn test_dropped_worker() {
+    let mut buf = get_buf!(SharedPipeline, 2);
+    let (mut prod, [mut first, second], mut cons) = buf.split_pipeline();
+
+    prod.push_slice(&[1, 2, 3]).unwrap();
+    unsafe { first.advance(3) };
+
+    // Items processed by the first stage will never reach the consumer.
+    drop(second);
+    assert!(cons.is_disconnected());
+    assert!(!first.is_disconnected());
+    assert!(prod.is_disconnected());
+}
+
+#[test]
+fn test_mpsc_disconnected() {
+    let mut buf = get_buf!(Mpsc);
+    let (prod, mut cons) = buf.split_mpsc();
+    let mut prod2 = prod.clone();
+
+    drop(prod);
+    assert!(cons.prod_alive());
+    assert!(!prod2.is_disconnected());
+    prod2.push(1).unwrap();
+    drop(prod2);
+
+    assert!(!cons.prod_alive());
+    assert_eq!(cons.pop(), Ok(1));
+    assert!(cons.is_disconnected());
+}
+
+#[test]
+fn test_blocking_disconnected() {
+    let strategies = [
+        WaitStrategy::Spin,
+        WaitStrategy::Backoff,
+        #[cfg(feature = "std")]
+        WaitStrategy::Park,
+    ];
+
+    for strategy in strategies {
+        let mut buf = get_buf!(Shared).with_wait_strategy(strategy);
+        let (mut prod, mut cons) = buf.split();
+
+        thread::scope(|s| {
+            s.spawn(move || {
+                let mut dst = [0; 4];
+                assert_eq!(cons.copy_slice_blocking(&mut dst), Ok(()));
+                assert_eq!(
+                    cons.copy_slice_blocking(&mut dst),
+                    Err(SliceError::Disconnected)
+                );
+                assert_eq!(cons.pop_blocking(), Ok(8));
+                assert_eq!(cons.pop_blocking(), Err(PopError::Disconnected));
+            });
+
+            s.spawn(move || {
+                prod.push_slice(&[0; 4]).unwrap();
+                prod.push_slice(&[8]).unwrap();
+            });
+        });
+
+        let mut buf = get_buf!(Shared).with_wait_strategy(strategy);
+        let (mut prod, cons) = buf.split();
+        prod.push_slice(&[0; BUFFER_SIZE - 1]).unwrap();
+
+        thread::scope(|s| {
+            s.spawn(move || assert_eq!(prod.push_blocking(1), Err(PushError::Disconnected(1))));
+            s.spawn(move || drop(cons));
+        });
+    }
+}
+
+#[test]
+fn test_broadcast_prod_disconnected() {
+    let mut buf = get_buf!(Broadcast, 2);
+    let (mut prod, mut readers) = split_broadcast!(buf, 2);
+    let (r1, r0) = (readers.pop().unwrap(), readers.pop().unwrap());
+
+    // The producer is disconnected only once every reader is gone.
+    drop(r0);
+    assert!(!prod.is_disconnected());
+    prod.push_slice(&[0; BUFFER_SIZE - 1]).unwrap();
+    assert_eq!(prod.push(1), Err(PushError::Full(1)));
+
+    drop(r1);
+    assert!(prod.is_disconnected());
+    assert_eq!(prod.push(1), Err(PushError::Disconnected(1)));
+}
@@ -0,0 +1,91 @@
+extern crate alloc;
+
+use crate::{common_def, get_buf};
+use oneringbuf::{ORBIterator, PopError, PushError, SliceError};
+
+common_def!();
+
+#[test]
+fn test_push_errors() {
+    let mut buf = get_buf!(Shared);
+    let (mut prod, mut cons) = buf.split();
+
+    prod.push_slice(&[0; BUFFER_SIZE - 1]).unwrap();
+    assert_eq!(prod.push(1), Err(PushError::Full(1)));
+    assert_eq!(
+        prod.push_slice(&[1, 2]),
+        Err(SliceError::Insufficient {
+            requested: 2,
+            available: 0
+        })
+    );
+
+    cons.pop().unwrap();
+    drop(cons);
+
+    // There is room left, so the item is pushed anyway.
+    prod.push(1).unwrap();
+
+    let err = prod.push(2).unwrap_err();
+    assert!(err.is_disconnected());
+    assert_eq!(err.into_inner(), 2);
+    assert_eq!(prod.push_slice(&[1, 2]), Err(SliceError::Disconnected));
+}
+
+#[test]
+fn test_pop_errors() {
+    let mut buf = get_buf!(Shared);
+    let (mut prod, mut cons) = buf.split();
+
+    assert_eq!(cons.pop(), Err(PopError::Empty));
+
+    prod.push(1).unwrap();
+    let mut dst = [0; 2];
+    assert_eq!(
+        cons.copy_slice(&mut dst),
+        Err(SliceError::Insufficient {
+            requested: 2,
+            available: 1
+        })
+    );
+
+    drop(prod);
+
+    // The last item is still there, but a second one will never come.
+    assert_eq!(cons.copy_slice(&mut dst), Err(SliceError::Disconnected));
+    assert!(cons.peek_slice(2).is_err());
+    assert_eq!(cons.pop(), Ok(1));
+    assert_eq!(cons.pop(), Err(PopError::Disconnected));
+    assert_eq!(cons.copy_item(&mut dst[0]), Err(PopError::Disconnected));
+}
+
+#[test]
+fn test_get_mut_slice_exact_error() {
+    let mut buf = get_buf!(SharedMut);
+    let (mut prod, mut work, _cons) = buf.split_mut();
+
+    prod.push(1).unwrap();
+    assert_eq!(
+        work.get_mut_slice_exact(2).err(),
+        Some(SliceError::Insufficient {
+            requested: 2,
+            available: 1
+        })
+    );
+}
+
+#[test]
+fn test_error_display() {
+    assert_eq!(PushError::Full(1).to_string(), "pushing into a full buffer");
+    assert_eq!(
+        SliceError::Insufficient {
+            requested: 4,
+            available: 1
+        }
+        .to_string(),
+        "requested 4 items, but only 1 are available"
+    );
+
+    let err: Box<dyn core::error::Error> = Box::new(PopError::Empty);
+    assert_eq!(err.to_string(), "popping from an empty buffer");
+}
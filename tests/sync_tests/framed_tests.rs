@@ -0,0 +1,187 @@
+use crate::{common_def, get_buf};
+use oneringbuf::{FrameError, ORBIterator};
+use std::thread;
+
+common_def!();
+
+#[test]
+fn test_framed_roundtrip() {
+    let mut buf = get_buf!(Shared);
+    let (prod, cons) = buf.split();
+    let (mut prod, mut cons) = (prod.framed(200), cons.framed(200));
+
+    let long = [7u8; 150];
+    prod.push(b"hello").unwrap();
+    prod.push(&[]).unwrap();
+    prod.push(&long).unwrap();
+
+    // 1 + 5, 1 + 0 and 2 + 150 bytes.
+    assert_eq!(cons.available(), 159);
+
+    let mut dst = [0; 200];
+    assert_eq!(cons.pop_frame(&mut dst), Ok(5));
+    assert_eq!(&dst[..5], b"hello");
+
+    let frame = cons.next_frame().unwrap();
+    assert!(frame.is_empty());
+    drop(frame);
+
+    let frame = cons.next_frame().unwrap();
+    assert_eq!(frame.len(), 150);
+    drop(frame);
+
+    assert_eq!(cons.available(), 0);
+}
+
+#[test]
+fn test_framed_errors() {
+    let mut buf = get_buf!(Shared);
+    let (prod, cons) = buf.split();
+    let (mut prod, mut cons) = (prod.framed(200), cons.framed(200));
+
+    assert_eq!(
+        prod.push(&[0; 201]),
+        Err(FrameError::TooLarge { len: 201, max: 200 })
+    );
+    assert_eq!(
+        cons.pop_frame(&mut [0; 10]),
+        Err(FrameError::Insufficient {
+            requested: 1,
+            available: 0
+        })
+    );
+
+    // Fill the buffer, so that a whole frame no longer fits.
+    while prod.push(&[1; 100]).is_ok() {}
+    let free = prod.available();
+    assert!(free < 101);
+    assert_eq!(
+        prod.push(&[1; 100]),
+        Err(FrameError::Insufficient {
+            requested: 101,
+            available: free
+        })
+    );
+
+    assert_eq!(
+        cons.pop_frame(&mut [0; 10]),
+        Err(FrameError::TooLarge { len: 100, max: 10 })
+    );
+
+    let mut prod = prod.into_inner();
+    let mut cons = cons.into_inner();
+    // A header which exceeds the maximum frame length.
+    while cons.pop().is_ok() {}
+    prod.push_slice(&[0xff, 0x01]).unwrap();
+    let mut cons = cons.framed(200);
+    assert_eq!(
+        cons.next_frame().err(),
+        Some(FrameError::TooLarge { len: 255, max: 200 })
+    );
+
+    drop(prod);
+    let mut cons = cons.into_inner();
+    while cons.pop().is_ok() {}
+    let mut cons = cons.framed(200);
+    assert_eq!(cons.pop_frame(&mut [0; 10]), Err(FrameError::Disconnected));
+}
+
+#[test]
+#[should_panic]
+fn test_framed_max_too_large() {
+    let mut buf = get_buf!(Shared);
+    let (prod, _cons) = buf.split();
+
+    let _ = prod.framed(BUFFER_SIZE);
+}
+
+#[test]
+#[should_panic]
+fn test_framed_max_past_lookback() {
+    let mut buf = get_buf!(Shared).with_lookback(4);
+    let (_prod, cons) = buf.split();
+
+    // A 2 bytes header, with `BUFFER_SIZE - 5` bytes available at most.
+    let _ = cons.framed(BUFFER_SIZE - 6);
+}
+
+#[cfg(not(all(feature = "vmem", unix)))]
+#[test]
+fn test_framed_wrap_around() {
+    let mut buf = get_buf!(Shared);
+    let (mut prod, mut cons) = buf.split();
+
+    // Frames cross the end of the buffer.
+    prod.push_slice(&[0; BUFFER_SIZE - 4]).unwrap();
+    unsafe { cons.advance(BUFFER_SIZE - 4) };
+    let (mut prod, mut cons) = (prod.framed(100), cons.framed(100));
+
+    prod.push(&[1, 2, 3, 4, 5, 6]).unwrap();
+    prod.push(&[7, 8]).unwrap();
+
+    let frame = cons.next_frame().unwrap();
+    let (head, tail) = frame.slices();
+    assert_eq!(head, &[1, 2, 3]);
+    assert_eq!(tail, &[4, 5, 6]);
+    drop(frame);
+
+    let mut dst = [0; 10];
+    assert_eq!(cons.pop_frame(&mut dst), Ok(2));
+    assert_eq!(&dst[..2], &[7, 8]);
+}
+
+#[cfg(all(feature = "vmem", unix))]
+#[test]
+fn test_framed_wrap_around() {
+    let mut buf = get_buf!(Shared);
+    let (mut prod, mut cons) = buf.split();
+
+    // Frames cross the end of the buffer.
+    prod.push_slice(&[0; BUFFER_SIZE - 4]).unwrap();
+    unsafe { cons.advance(BUFFER_SIZE - 4) };
+    let (mut prod, mut cons) = (prod.framed(100), cons.framed(100));
+
+    prod.push(&[1, 2, 3, 4, 5, 6]).unwrap();
+    prod.push(&[7, 8]).unwrap();
+
+    assert_eq!(cons.next_frame().unwrap().slices(), &[1, 2, 3, 4, 5, 6]);
+
+    let mut dst = [0; 10];
+    assert_eq!(cons.pop_frame(&mut dst), Ok(2));
+    assert_eq!(&dst[..2], &[7, 8]);
+}
+
+#[test]
+fn test_framed_threads() {
+    const FRAMES: usize = 1000;
+
+    let mut buf = get_buf!(Shared);
+    let (prod, cons) = buf.split();
+    let (mut prod, mut cons) = (prod.framed(150), cons.framed(150));
+
+    thread::scope(|s| {
+        s.spawn(move || {
+            for i in 0..FRAMES {
+                let payload: Vec<u8> = (0..i % 150).map(|x| x as u8).collect();
+                prod.push_blocking(&payload).unwrap();
+            }
+        });
+
+        s.spawn(move || {
+            let mut dst = [0; 150];
+
+            for i in 0..FRAMES {
+                let len = loop {
+                    match cons.pop_frame(&mut dst) {
+                        Ok(len) => break len,
+                        Err(FrameError::Insufficient { .. }) => thread::yield_now(),
+                        Err(e) => panic!("{e}"),
+                    }
+                };
+
+                assert_eq!(len, i % 150);
+                assert!(dst[..len].iter().enumerate().all(|(x, &y)| x as u8 == y));
+            }
+        });
+    });
+}
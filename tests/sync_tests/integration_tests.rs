@@ -51,21 +51,21 @@ fn test_push_work_pop_slice() {
     assert_eq!(work.available(), 0);
     assert_eq!(cons.available(), 0);
 
-    prod.push_slice(&slice);
+    prod.push_slice(&slice).unwrap();
 
     assert_eq!(prod.available(), 0);
     assert_eq!(work.available(), BUFFER_SIZE - 1);
     assert_eq!(cons.available(), 0);
 
     #[cfg(not(all(feature = "vmem", unix)))]
-    if let Some((h, t)) = work.get_mut_slice_exact(BUFFER_SIZE - 1) {
+    if let Ok((h, t)) = work.get_mut_slice_exact(BUFFER_SIZE - 1) {
         for i in h.iter_mut().chain(t) {
             *i += 1;
         }
         unsafe { work.advance(BUFFER_SIZE - 1) };
     }
     #[cfg(all(feature = "vmem", unix))]
-    if let Some(s) = work.get_mut_slice_exact(BUFFER_SIZE - 1) {
+    if let Ok(s) = work.get_mut_slice_exact(BUFFER_SIZE - 1) {
         for i in s {
             *i += 1;
         }
@@ -77,13 +77,13 @@ fn test_push_work_pop_slice() {
     assert_eq!(cons.available(), BUFFER_SIZE - 1);
 
     #[cfg(not(all(feature = "vmem", unix)))]
-    if let Some((h, t)) = cons.peek_slice(BUFFER_SIZE - 1) {
+    if let Ok((h, t)) = cons.peek_slice(BUFFER_SIZE - 1) {
         for (consumed, i) in [h, t].concat().iter().zip(slice) {
             assert_eq!(*consumed, i + 1);
         }
     }
     #[cfg(all(feature = "vmem", unix))]
-    if let Some(s) = cons.peek_slice(BUFFER_SIZE - 1) {
+    if let Ok(s) = cons.peek_slice(BUFFER_SIZE - 1) {
         for (consumed, i) in s.iter().zip(slice) {
             assert_eq!(*consumed, i + 1);
         }
@@ -108,7 +108,7 @@ fn test_reset() {
     assert_eq!(work.available(), 0);
     assert_eq!(cons.available(), 0);
 
-    prod.push_slice(&two_thirds_slice);
+    prod.push_slice(&two_thirds_slice).unwrap();
 
     assert_eq!(prod.available(), BUFFER_SIZE / 3);
     assert_eq!(work.available(), BUFFER_SIZE / 3 * 2);
@@ -132,7 +132,7 @@ fn test_reset() {
     assert_eq!(work.available(), 0);
     assert_eq!(cons.available(), 0);
 
-    prod.push_slice(&slice);
+    prod.push_slice(&slice).unwrap();
 
     assert_eq!(prod.available(), 0);
     assert_eq!(work.available(), BUFFER_SIZE - 1);
@@ -54,13 +54,13 @@ fn test_push_work_pop_slice() {
     assert_eq!(work.available(), 0);
     assert_eq!(cons.available(), 0);
 
-    prod.push_slice(&slice);
+    prod.push_slice(&slice).unwrap();
 
     assert_eq!(prod.available(), 0);
     assert_eq!(work.available(), BUFFER_SIZE - 1);
     assert_eq!(cons.available(), 0);
 
-    if let Some(res) = work.get_mut_slice_exact(BUFFER_SIZE - 1) {
+    if let Ok(res) = work.get_mut_slice_exact(BUFFER_SIZE - 1) {
         for i in res {
             *i += 1;
         }
@@ -71,7 +71,7 @@ fn test_push_work_pop_slice() {
     assert_eq!(work.available(), 0);
     assert_eq!(cons.available(), BUFFER_SIZE - 1);
 
-    if let Some(res) = cons.peek_slice(BUFFER_SIZE - 1) {
+    if let Ok(res) = cons.peek_slice(BUFFER_SIZE - 1) {
         for (consumed, i) in res.iter().zip(slice) {
             assert_eq!(*consumed, i + 1);
         }
@@ -94,7 +94,7 @@ fn test_reset() {
     assert_eq!(work.available(), 0);
     assert_eq!(cons.available(), 0);
 
-    prod.push_slice(&two_thirds_slice);
+    prod.push_slice(&two_thirds_slice).unwrap();
 
     assert_eq!(prod.available(), BUFFER_SIZE / 3);
     assert_eq!(work.available(), BUFFER_SIZE / 3 * 2);
@@ -118,7 +118,7 @@ fn test_reset() {
     assert_eq!(work.available(), 0);
     assert_eq!(cons.available(), 0);
 
-    prod.push_slice(&slice);
+    prod.push_slice(&slice).unwrap();
 
     assert_eq!(prod.available(), 0);
     assert_eq!(work.available(), BUFFER_SIZE - 1);
@@ -1,14 +1,29 @@
+pub mod broadcast_tests;
 pub mod concurrent_fib;
 pub mod cons_tests;
 #[cfg(all(feature = "vmem", unix))]
 pub mod cons_tests_vmem;
 pub mod detached_work_tests;
+pub mod disconnect_tests;
 pub mod drop;
+pub mod error_tests;
+pub mod framed_tests;
 pub mod integration_tests;
 #[cfg(all(feature = "vmem", unix))]
 pub mod integration_tests_vmem;
+pub mod mpsc_tests;
 pub mod multithreading;
+pub mod observer_tests;
+pub mod overwrite_tests;
+pub mod owned_tests;
+pub mod pipeline_tests;
 pub mod prod_tests;
+#[cfg(not(all(feature = "vmem", unix)))]
+pub mod reserve_tests;
+#[cfg(feature = "stats")]
+pub mod stats_tests;
+pub mod unsplit_tests;
+pub mod wait_tests;
 pub mod work_tests;
 #[cfg(all(feature = "vmem", unix))]
 pub mod work_tests_vmem;
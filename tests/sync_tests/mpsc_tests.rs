@@ -0,0 +1,133 @@
+extern crate alloc;
+
+use crate::{common_def, get_buf};
+use oneringbuf::{ORBIterator, PushError};
+use std::thread;
+
+common_def!();
+
+#[test]
+fn test_mpsc_push_pop() {
+    let mut buf = get_buf!(Mpsc);
+    let (mut prod, mut cons) = buf.split_mpsc();
+
+    assert_eq!(prod.alive_iters(), 2);
+    let mut prod2 = prod.clone();
+    assert_eq!(prod.alive_iters(), 3);
+
+    prod.push(1).unwrap();
+    prod2.push_slice(&[2, 3]).unwrap();
+    prod.push_slice_clone(&[4]).unwrap();
+
+    assert_eq!(cons.available(), 4);
+    for i in 1..=4 {
+        assert_eq!(cons.pop().unwrap(), i);
+    }
+    assert!(cons.pop().is_err());
+
+    drop(prod2);
+    assert_eq!(cons.alive_iters(), 2);
+}
+
+#[test]
+fn test_mpsc_full() {
+    let mut buf = get_buf!(Mpsc);
+    let (mut prod, mut cons) = buf.split_mpsc();
+
+    assert!(prod.push_slice(&[0; BUFFER_SIZE]).is_err());
+    prod.push_slice(&[0; BUFFER_SIZE - 1]).unwrap();
+    assert_eq!(prod.push(1), Err(PushError::Full(1)));
+
+    cons.pop().unwrap();
+    prod.push(1).unwrap();
+    assert_eq!(cons.available(), BUFFER_SIZE - 1);
+}
+
+#[test]
+fn test_mpsc_wrap() {
+    let mut buf = get_buf!(Mpsc);
+    let (mut prod, mut cons) = buf.split_mpsc();
+
+    let slice = [7; 3];
+    for _ in 0..BUFFER_SIZE {
+        prod.push_slice(&slice).unwrap();
+
+        let mut dst = [0; 3];
+        cons.copy_slice(&mut dst).unwrap();
+        assert_eq!(dst, slice);
+    }
+}
+
+#[test]
+fn test_mpsc_mt() {
+    const PRODUCERS: usize = 4;
+    const COUNT: usize = 2_000;
+
+    let mut buf = get_buf!(Mpsc);
+    let (prod, mut cons) = buf.split_mpsc();
+
+    thread::scope(|s| {
+        for p in 0..PRODUCERS {
+            let mut prod = prod.clone();
+            s.spawn(move || {
+                let mut i = 0;
+                while i < COUNT {
+                    // Slices are never interleaved.
+                    match prod.push_slice(&[p, i]) {
+                        Ok(_) => i += 1,
+                        Err(_) => thread::yield_now(),
+                    }
+                }
+            });
+        }
+        drop(prod);
+
+        s.spawn(move || {
+            let mut next = [0; PRODUCERS];
+            let mut dst = [0; 2];
+
+            for _ in 0..PRODUCERS * COUNT {
+                while cons.copy_slice(&mut dst).is_err() {
+                    thread::yield_now();
+                }
+
+                let [p, i] = dst;
+                assert_eq!(next[p], i);
+                next[p] += 1;
+            }
+
+            assert_eq!(next, [COUNT; PRODUCERS]);
+        });
+    });
+}
+
+#[test]
+fn test_mpsc_clone_panic() {
+    #[derive(Debug, PartialEq)]
+    struct Bomb(u32);
+
+    impl Clone for Bomb {
+        fn clone(&self) -> Self {
+            assert_ne!(self.0, 0, "boom");
+            Bomb(self.0)
+        }
+    }
+
+    let mut buf = oneringbuf::MpscStackRB::<Bomb, 8>::from(core::array::from_fn(|_| Bomb(9)));
+    let (mut prod, mut cons) = buf.split_mpsc();
+    let mut prod2 = prod.clone();
+
+    let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        prod.push_slice_clone(&[Bomb(1), Bomb(0), Bomb(3)])
+    }));
+    assert!(res.is_err());
+
+    // The claimed slots are published anyway, so other producers are not blocked.
+    thread::scope(|s| {
+        s.spawn(|| prod2.push(Bomb(4)).unwrap()).join().unwrap();
+    });
+
+    assert_eq!(cons.available(), 4);
+    let items: Vec<_> = cons.iter().map(|x| x.0).collect();
+    assert_eq!(items, [1, 9, 9, 4]);
+}
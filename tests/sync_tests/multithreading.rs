@@ -13,7 +13,7 @@ macro_rules! get_prod {
             let start = Instant::now();
 
             while start.elapsed().as_millis() < 5 {
-                $prod.push_slice(&[0; 3000]);
+                let _ = $prod.push_slice(&[0; 3000]);
             }
         })
     };
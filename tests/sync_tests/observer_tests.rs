@@ -0,0 +1,95 @@
+use crate::{common_def, get_buf};
+use oneringbuf::ORBIterator;
+use std::thread;
+
+common_def!();
+
+#[test]
+fn test_observer_fill_level() {
+    let mut buf = get_buf!(Shared);
+    let (mut prod, mut cons) = buf.split();
+    let obs = prod.observer();
+
+    assert!(obs.is_empty());
+    assert_eq!(obs.free(), BUFFER_SIZE - 1);
+
+    for i in 0..BUFFER_SIZE - 1 {
+        prod.push(i).unwrap();
+    }
+    assert!(obs.is_full());
+    assert_eq!(obs.len(), BUFFER_SIZE - 1);
+
+    for _ in 0..10 {
+        cons.pop().unwrap();
+    }
+    assert_eq!(obs.len(), BUFFER_SIZE - 11);
+    assert_eq!(obs.free(), 10);
+    assert_eq!(obs.cons_index(), 10);
+    assert_eq!(obs.prod_index(), BUFFER_SIZE - 1);
+    assert_eq!(prod.available(), 10);
+}
+
+#[test]
+fn test_observer_backlog() {
+    let mut buf = get_buf!(SharedMut);
+    let (mut prod, mut work, cons) = buf.split_mut();
+    let obs = cons.observer();
+
+    for i in 0..10 {
+        prod.push(i).unwrap();
+    }
+    unsafe { work.advance(4) };
+
+    assert_eq!(obs.stages(), 1);
+    assert_eq!(obs.stage_backlog(0), 6);
+    assert_eq!(obs.cons_backlog(), 4);
+    assert_eq!(obs.len(), 10);
+}
+
+#[test]
+fn test_observer_liveness() {
+    let mut buf = get_buf!(Shared);
+    let (prod, cons) = buf.split();
+    let obs = prod.observer();
+
+    assert_eq!(cons.alive_iters(), 3);
+    let other = obs.clone();
+    assert_eq!(obs.alive_iters(), 4);
+    drop(other);
+
+    drop(prod);
+    assert!(!obs.prod_alive());
+    assert!(obs.reader_alive(0));
+
+    drop(cons);
+    assert!(!obs.reader_alive(0));
+    assert_eq!(obs.alive_iters(), 1);
+}
+
+#[test]
+fn test_observer_multithread() {
+    let mut buf = get_buf!(Shared);
+    let (mut prod, mut cons) = buf.split();
+    let obs = prod.observer();
+
+    thread::scope(|s| {
+        s.spawn(move || {
+            for i in 0..BUFFER_SIZE * 10 {
+                prod.push_blocking(i).unwrap();
+            }
+        });
+        s.spawn(move || {
+            for i in 0..BUFFER_SIZE * 10 {
+                assert_eq!(cons.pop_blocking(), Ok(i));
+            }
+        });
+        s.spawn(|| {
+            let obs = obs.clone();
+            while obs.prod_alive() || obs.reader_alive(0) {
+                assert!(obs.len() < BUFFER_SIZE);
+            }
+        });
+    });
+
+    assert!(obs.is_empty());
+}
@@ -0,0 +1,121 @@
+extern crate alloc;
+
+use crate::{common_def, get_buf};
+use oneringbuf::ORBIterator;
+use oneringbuf::iterators::Overrun;
+use std::thread;
+
+common_def!();
+
+#[test]
+fn test_overwrite_push_pop() {
+    let mut buf = get_buf!(Overwrite);
+    let (mut prod, mut cons) = buf.split_overwrite();
+
+    assert_eq!(cons.alive_iters(), 2);
+
+    for i in 0..BUFFER_SIZE as i32 + 2 {
+        prod.push_overwrite(i);
+    }
+    assert_eq!(prod.available(), 0);
+
+    assert_eq!(cons.pop(), Err(Overrun { lost: 3 }));
+    assert_eq!(cons.available(), BUFFER_SIZE - 1);
+
+    for i in 3..BUFFER_SIZE as i32 + 2 {
+        assert_eq!(cons.pop_clone(), Ok(Some(i)));
+    }
+    assert_eq!(cons.pop(), Ok(None));
+}
+
+#[test]
+fn test_overwrite_no_loss() {
+    let mut buf = get_buf!(Overwrite);
+    let (mut prod, mut cons) = buf.split_overwrite();
+
+    prod.push_overwrite(1);
+    prod.push_slice_overwrite(&[2, 3, 4]);
+
+    let mut dst = [0; 4];
+    assert_eq!(cons.copy_slice(&mut dst), Ok(Some(())));
+    assert_eq!(dst, [1, 2, 3, 4]);
+    assert_eq!(cons.clone_slice(&mut dst), Ok(None));
+}
+
+#[test]
+fn test_overwrite_slice() {
+    let mut buf = get_buf!(Overwrite);
+    let (mut prod, mut cons) = buf.split_overwrite();
+
+    let src: Vec<i32> = (0..BUFFER_SIZE as i32 + 10).collect();
+
+    prod.push_slice_overwrite(&src[..10]);
+    prod.push_slice_overwrite(&src);
+
+    // The first 10 items are overwritten, the next 11 don't fit.
+    assert_eq!(cons.pop(), Err(Overrun { lost: 21 }));
+
+    let mut dst = vec![0; BUFFER_SIZE - 1];
+    assert_eq!(cons.copy_slice(&mut dst), Ok(Some(())));
+    assert_eq!(dst, src[11..]);
+}
+
+#[test]
+fn test_overwrite_mt() {
+    const COUNT: i32 = 2_000;
+
+    let mut buf = get_buf!(Overwrite);
+    let (mut prod, mut cons) = buf.split_overwrite();
+
+    thread::scope(|s| {
+        s.spawn(move || {
+            for i in 0..COUNT {
+                prod.push_overwrite(i);
+                if i % 64 == 0 {
+                    thread::yield_now();
+                }
+            }
+        });
+
+        s.spawn(move || {
+            let mut next = 0;
+            while next < COUNT {
+                match cons.pop() {
+                    Ok(Some(x)) => {
+                        assert_eq!(x, next);
+                        next += 1;
+                    }
+                    Ok(None) => thread::yield_now(),
+                    Err(Overrun { lost }) => next += lost as i32,
+                }
+            }
+        });
+    });
+}
+
+#[test]
+fn test_overwrite_clone_panic() {
+    #[derive(Debug, PartialEq)]
+    struct Bomb(u32);
+
+    impl Clone for Bomb {
+        fn clone(&self) -> Self {
+            assert_ne!(self.0, 0, "boom");
+            Bomb(self.0)
+        }
+    }
+
+    let mut buf = oneringbuf::OverwriteStackRB::<Bomb, 4>::from(core::array::from_fn(|_| Bomb(9)));
+    let (mut prod, mut cons) = buf.split_overwrite();
+
+    prod.push_overwrite(Bomb(0));
+    let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| cons.pop_clone()));
+    assert!(res.is_err());
+
+    // The consumer index is unlocked without moving the consumer, so the producer is not blocked.
+    for i in 1..=3 {
+        prod.push_overwrite(Bomb(i));
+    }
+    assert_eq!(cons.pop_clone(), Err(Overrun { lost: 1 }));
+    assert_eq!(cons.pop_clone(), Ok(Some(Bomb(1))));
+}
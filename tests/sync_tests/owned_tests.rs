@@ -0,0 +1,104 @@
+use crate::{common_def, get_buf};
+use core::sync::atomic::{AtomicUsize, Ordering};
+use oneringbuf::PopError;
+use std::thread;
+
+common_def!();
+
+/// Counts how many times it gets dropped. Its bit pattern is all zeros.
+struct Tracked<'a>(u64, &'a AtomicUsize);
+
+impl Drop for Tracked<'_> {
+    fn drop(&mut self) {
+        self.1.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[test]
+fn test_owned_push_pop() {
+    let mut buf = get_buf!(Owned);
+    let (mut prod, mut cons) = buf.split_owned();
+
+    for i in 0..BUFFER_SIZE - 1 {
+        prod.push(i).unwrap();
+    }
+    assert!(prod.push(0).unwrap_err().is_full());
+
+    assert_eq!(cons.peek_ref(), Some(&0));
+    for i in 0..BUFFER_SIZE - 1 {
+        assert_eq!(cons.pop(), Ok(i));
+    }
+    assert_eq!(cons.pop(), Err(PopError::Empty));
+
+    drop(prod);
+    assert_eq!(cons.pop(), Err(PopError::Disconnected));
+}
+
+#[test]
+fn test_owned_drops_live_range() {
+    let drops = AtomicUsize::new(0);
+
+    {
+        let mut buf = get_buf!(Owned);
+        let (mut prod, mut cons) = buf.split_owned();
+
+        // Wrap around the end of the buffer, so that stale copies are left behind.
+        for _ in 0..2 {
+            for _ in 0..BUFFER_SIZE / 2 {
+                assert!(prod.push(Tracked(0, &drops)).is_ok());
+            }
+            for _ in 0..BUFFER_SIZE / 2 - 2 {
+                assert_eq!(cons.pop().unwrap().0, 0);
+            }
+        }
+
+        assert_eq!(drops.load(Ordering::Relaxed), BUFFER_SIZE - 4);
+        assert_eq!(cons.available(), 4);
+    }
+
+    // Items left in the buffer are dropped exactly once, despite being all zeros.
+    assert_eq!(drops.load(Ordering::Relaxed), BUFFER_SIZE);
+}
+
+#[test]
+fn test_owned_multithread() {
+    let mut buf = get_buf!(Owned);
+    let (mut prod, mut cons) = buf.split_owned();
+
+    thread::scope(|s| {
+        s.spawn(move || {
+            for i in 0..BUFFER_SIZE * 4 {
+                prod.push_blocking(Box::new(i)).unwrap();
+            }
+        });
+
+        for i in 0..BUFFER_SIZE * 4 {
+            assert_eq!(*cons.pop_blocking().unwrap(), i);
+        }
+        assert_eq!(cons.pop_blocking(), Err(PopError::Disconnected));
+    });
+}
+
+#[test]
+fn test_owned_skip_to_latest() {
+    let drops = AtomicUsize::new(0);
+
+    {
+        let mut buf = get_buf!(Owned);
+        let (mut prod, mut cons) = buf.split_owned();
+
+        for i in 0..10 {
+            assert!(prod.push(Tracked(i, &drops)).is_ok());
+        }
+
+        assert_eq!(cons.skip_to_latest(3), 7);
+        assert_eq!(drops.load(Ordering::Relaxed), 7);
+        assert_eq!(cons.skip_to_latest(3), 0);
+
+        assert!(cons.peek_latest(3).is_ok());
+        assert!(cons.peek_latest(4).is_err());
+        assert_eq!(cons.pop().unwrap().0, 7);
+    }
+
+    assert_eq!(drops.load(Ordering::Relaxed), 10);
+}
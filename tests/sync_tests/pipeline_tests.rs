@@ -0,0 +1,149 @@
+extern crate alloc;
+
+use crate::{common_def, get_buf};
+use oneringbuf::ORBIterator;
+use std::thread;
+
+common_def!();
+
+#[test]
+fn test_pipeline_stages_order() {
+    let mut buf = get_buf!(LocalPipeline, 3);
+    let (mut prod, mut works, mut cons) = buf.split_pipeline();
+
+    for (i, w) in works.iter().enumerate() {
+        assert_eq!(w.stage(), i);
+    }
+
+    prod.push_slice(&[1, 2, 3, 4]).unwrap();
+
+    assert_eq!(works[0].available(), 4);
+    assert_eq!(works[1].available(), 0);
+    assert_eq!(works[2].available(), 0);
+    assert_eq!(cons.available(), 0);
+
+    for (i, w) in works.iter_mut().enumerate() {
+        while let Some(x) = w.get_mut() {
+            *x = *x * 10 + i as i32;
+            unsafe { w.advance(1) };
+        }
+    }
+
+    assert_eq!(cons.available(), 4);
+    for v in [1, 2, 3, 4] {
+        assert_eq!(cons.pop().unwrap(), ((v * 10) * 10 + 1) * 10 + 2);
+    }
+
+    assert_eq!(prod.available(), BUFFER_SIZE - 1);
+}
+
+#[test]
+fn test_pipeline_stage_follows_predecessor() {
+    let mut buf = get_buf!(SharedPipeline, 2);
+    let (mut prod, [mut first, mut second], mut cons) = buf.split_pipeline();
+
+    prod.push_slice(&[0; 10]).unwrap();
+
+    unsafe { first.advance(6) };
+    assert_eq!(first.stage_index(0), Some(6));
+    assert_eq!(second.available(), 6);
+    assert_eq!(cons.available(), 0);
+
+    unsafe { second.advance(2) };
+    assert_eq!(cons.available(), 2);
+    assert_eq!(cons.stage_index(1), Some(2));
+
+    // The producer is bounded by the consumer, not by the workers.
+    assert_eq!(prod.available(), BUFFER_SIZE - 1 - 10);
+    cons.pop().unwrap();
+    assert_eq!(prod.available(), BUFFER_SIZE - 1 - 9);
+
+    second.reset_index();
+    assert_eq!(cons.available(), 5);
+}
+
+#[test]
+fn test_pipeline_single_stage() {
+    let mut buf = get_buf!(SharedPipeline, 1);
+    let (mut prod, [mut work], mut cons) = buf.split_pipeline();
+
+    prod.push(41).unwrap();
+    *work.get_mut().unwrap() += 1;
+    unsafe { work.advance(1) };
+
+    assert_eq!(cons.pop().unwrap(), 42);
+}
+
+#[test]
+fn test_pipeline_out_of_bounds() {
+    let mut buf = get_buf!(SharedPipeline, 2);
+    let (prod, _works, _cons) = buf.split_pipeline();
+
+    // Every component reports stages and readers it does not have in the same way.
+    assert_eq!(prod.stage_index(2), None);
+    assert!(!prod.stage_alive(2) && !prod.stage_alive(300));
+    assert_eq!(prod.reader_index(1), None);
+    assert!(!prod.reader_alive(1) && !prod.reader_alive(300));
+    assert_eq!(prod.observer().stage_index(2), None);
+
+    let mut buf = get_buf!(SharedMut);
+    let (prod, _work, _cons) = buf.split_mut();
+    assert_eq!(prod.stage_index(0), Some(prod.work_index()));
+    assert_eq!(prod.stage_index(1), None);
+    assert!(prod.work_alive() && !prod.stage_alive(1));
+
+    let mut buf = get_buf!(Shared);
+    let (prod, _cons) = buf.split();
+    assert_eq!(prod.stage_index(0), None);
+    assert!(!prod.work_alive());
+    assert_eq!(prod.reader_index(0), Some(prod.cons_index()));
+}
+
+#[test]
+#[should_panic]
+fn test_pipeline_zero_stages() {
+    let _ = get_buf!(SharedPipeline, 0);
+}
+
+#[test]
+fn test_pipeline_mt() {
+    const STAGES: usize = 4;
+    const COUNT: i32 = 10_000;
+
+    let mut buf = get_buf!(SharedPipeline, STAGES);
+    let (mut prod, works, mut cons) = buf.split_pipeline();
+
+    thread::scope(|s| {
+        s.spawn(move || {
+            let mut i = 0;
+            while i < COUNT {
+                if prod.push(i).is_ok() {
+                    i += 1;
+                }
+            }
+        });
+
+        for mut w in works {
+            s.spawn(move || {
+                let mut done = 0;
+                while done < COUNT {
+                    if let Some(x) = w.get_mut() {
+                        *x += 1;
+                        unsafe { w.advance(1) };
+                        done += 1;
+                    }
+                }
+            });
+        }
+
+        s.spawn(move || {
+            let mut i = 0;
+            while i < COUNT {
+                if let Ok(x) = cons.pop() {
+                    assert_eq!(x, i + STAGES as i32);
+                    i += 1;
+                }
+            }
+        });
+    });
+}
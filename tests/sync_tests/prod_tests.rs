@@ -28,17 +28,40 @@ fn test_push_slice() {
 
     assert_eq!(prod.available(), BUFFER_SIZE - 1);
 
-    assert!(prod.push_slice(&half_slice).is_some());
+    assert!(prod.push_slice(&half_slice).is_ok());
 
     assert_eq!(prod.available(), BUFFER_SIZE / 2);
 
-    assert!(prod.push_slice(&slice).is_none());
+    assert!(prod.push_slice(&slice).is_err());
 
-    assert!(prod.push_slice(&half_slice).is_some());
+    assert!(prod.push_slice(&half_slice).is_ok());
 
     assert_eq!(prod.available(), 1);
 }
 
+#[test]
+fn test_push_slice_partial() {
+    let mut buf = get_buf!(Shared);
+    let (mut prod, mut cons) = buf.split();
+
+    let slice = (0..BUFFER_SIZE).collect::<Vec<usize>>();
+
+    // Only `BUFFER_SIZE - 1` items fit.
+    assert_eq!(prod.push_slice_partial(&slice), BUFFER_SIZE - 1);
+    assert_eq!(prod.push_slice_partial(&slice), 0);
+
+    // Make room at the beginning of the buffer, so that the next push wraps around.
+    let mut dst = vec![0; BUFFER_SIZE / 2];
+    cons.copy_slice(&mut dst).unwrap();
+
+    assert_eq!(prod.push_slice_clone_partial(&slice), BUFFER_SIZE / 2);
+    assert_eq!(prod.available(), 0);
+
+    unsafe { cons.advance(BUFFER_SIZE / 2 - 1) };
+    cons.copy_slice(&mut dst).unwrap();
+    assert_eq!(dst, &slice[..BUFFER_SIZE / 2]);
+}
+
 #[test]
 #[allow(clippy::unnecessary_cast)]
 fn test_push_mut_ref_init() {
@@ -58,7 +81,7 @@ fn test_push_mut_ref_init() {
     assert!(prod.push(1).is_err());
 
     for i in 0..BUFFER_SIZE - 1 {
-        assert_eq!(cons.pop(), Some(i));
+        assert_eq!(cons.pop(), Ok(i));
     }
 }
 
@@ -81,6 +104,136 @@ fn test_push_mut_ref() {
     assert!(prod.push(1).is_err());
 
     for i in 0..BUFFER_SIZE - 1 {
-        assert_eq!(cons.pop(), Some(i));
+        assert_eq!(cons.pop(), Ok(i));
     }
 }
+
+#[test]
+fn test_push_iter() {
+    let mut buf = get_buf!(Shared);
+    let (mut prod, mut cons) = buf.split();
+
+    let mut iter = 0..BUFFER_SIZE;
+    assert_eq!(prod.push_iter(iter.by_ref()), BUFFER_SIZE - 1);
+    // The item which did not fit has not been consumed.
+    assert_eq!(iter.next(), Some(BUFFER_SIZE - 1));
+    assert_eq!(prod.push_iter(0..1), 0);
+
+    unsafe { cons.advance(2) };
+    assert_eq!(prod.push_iter([7, 8, 9]), 2);
+    assert_eq!(prod.available(), 0);
+
+    let mut dst = vec![0; BUFFER_SIZE - 1];
+    cons.copy_slice(&mut dst).unwrap();
+    assert_eq!(&dst[BUFFER_SIZE - 3..], &[7, 8]);
+}
+
+#[test]
+fn test_extend() {
+    const COUNT: usize = BUFFER_SIZE * 3;
+
+    let mut buf = get_buf!(Shared);
+    let (mut prod, mut cons) = buf.split();
+
+    std::thread::scope(|s| {
+        // Waits for room instead of dropping the items which do not fit.
+        s.spawn(move || prod.extend(0..COUNT));
+
+        for i in 0..COUNT {
+            cons.wait_for(1);
+            assert_eq!(cons.pop(), Ok(i));
+        }
+    });
+}
+
+#[test]
+fn test_extend_disconnected() {
+    let mut buf = get_buf!(Shared);
+    let (mut prod, cons) = buf.split();
+
+    drop(cons);
+    // Stops once the buffer is full, dropping the remaining items.
+    prod.extend(0..BUFFER_SIZE * 2);
+    assert_eq!(prod.available(), 0);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_push_vec_drain() {
+    let buf = oneringbuf::SharedHeapRB::from(vec![String::new(); 4]);
+    let (mut prod, mut cons) = buf.split();
+
+    let mut vec: Vec<String> = ["a", "b", "c", "d", "e"].map(String::from).into();
+    assert_eq!(prod.push_vec_drain(&mut vec), 3);
+    assert_eq!(vec, ["d", "e"]);
+
+    // Moving items out leaves their locations uninitialised.
+    assert_eq!(unsafe { cons.pop_move() }.unwrap(), "a");
+    assert_eq!(prod.push_vec_drain(&mut vec), 1);
+    assert_eq!(unsafe { cons.pop_move() }.unwrap(), "b");
+    assert_eq!(prod.push_vec_drain(&mut vec), 1);
+    assert!(vec.is_empty());
+
+    assert_eq!(cons.pop_clone().unwrap(), "c");
+    assert_eq!(cons.pop_clone().unwrap(), "d");
+    assert_eq!(cons.pop_clone().unwrap(), "e");
+}
+
+#[test]
+fn test_push_pop_array() {
+    let mut buf = get_buf!(Shared);
+    let (mut prod, mut cons) = buf.split();
+
+    // Arrays cross the end of the buffer.
+    prod.push_slice(&[0; BUFFER_SIZE - 2]).unwrap();
+    unsafe { cons.advance(BUFFER_SIZE - 2) };
+
+    assert!(prod.push_array([1, 2, 3, 4]).is_ok());
+    assert_eq!(cons.pop_array::<3>(), Ok([1, 2, 3]));
+    assert_eq!(
+        cons.pop_array::<2>(),
+        Err(oneringbuf::SliceError::Insufficient {
+            requested: 2,
+            available: 1
+        })
+    );
+    assert_eq!(cons.pop_array::<1>(), Ok([4]));
+
+    prod.push_slice(&[0; BUFFER_SIZE - 3]).unwrap();
+    assert_eq!(
+        prod.push_array([5, 6, 7]),
+        Err(oneringbuf::PushError::Full([5, 6, 7]))
+    );
+}
+
+#[test]
+fn test_get_block_mut() {
+    let mut buf = oneringbuf::SharedStackRB::<u32, 12>::from_blocks::<4>([0; 12]);
+    let (mut prod, mut cons) = buf.split();
+
+    for i in 0..10 {
+        let Some(block) = prod.get_block_mut::<4>() else {
+            // The last block is left free, to tell a full buffer from an empty one.
+            assert_eq!(i % 3, 2);
+            assert_eq!(cons.pop_array::<4>(), Ok([i - 2; 4]));
+            assert_eq!(cons.pop_array::<4>(), Ok([i - 1; 4]));
+            continue;
+        };
+
+        *block = [i; 4];
+        unsafe { prod.advance(4) };
+    }
+
+    // Blocks crossing the end of the buffer are never returned.
+    unsafe { prod.advance(6) };
+    while cons.pop().is_ok() {}
+    assert_eq!(prod.available(), 11);
+    assert!(prod.get_block_mut::<4>().is_none());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+#[should_panic]
+fn test_from_blocks_not_multiple() {
+    let _ = oneringbuf::SharedHeapRB::from_blocks::<4>(vec![0; 10]);
+}
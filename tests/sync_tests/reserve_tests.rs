@@ -0,0 +1,242 @@
+use crate::{common_def, get_buf};
+use oneringbuf::{ORBIterator, SliceError};
+use std::thread;
+
+common_def!();
+
+#[test]
+fn test_reserve_contiguous() {
+    let mut buf = get_buf!(Shared);
+    let (mut prod, mut cons) = buf.split();
+
+    let mut res = prod.reserve(10).unwrap();
+    assert_eq!(res.len(), 10);
+    assert_eq!(res.padding(), 0);
+    res.copy_from_slice(&[1; 10]);
+    res.commit(4);
+
+    assert_eq!(cons.available(), 4);
+    assert_eq!(cons.peek_slice(4).unwrap(), (&[1; 4][..], &[][..]));
+
+    prod.reserve(3).unwrap().abort();
+    assert_eq!(cons.available(), 4);
+
+    // A panicking commit publishes nothing.
+    let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        prod.reserve(3).unwrap().commit(4);
+    }));
+    assert!(res.is_err());
+    assert_eq!(cons.available(), 4);
+}
+
+#[test]
+fn test_reserve_skips_tail() {
+    let mut buf = get_buf!(Shared);
+    let (mut prod, mut cons) = buf.split();
+
+    prod.push_slice(&[0; BUFFER_SIZE - 5]).unwrap();
+    unsafe { cons.advance(BUFFER_SIZE - 8) };
+
+    // Three items are left before the tail.
+    let mut res = prod.reserve(10).unwrap();
+    assert_eq!(res.padding(), 5);
+    res.copy_from_slice(&(1..=10).collect::<Vec<_>>());
+    drop(res);
+
+    assert_eq!(prod.available(), BUFFER_SIZE - 1 - 3 - 5 - 10);
+
+    // The consumer stops before the skipped tail...
+    assert_eq!(cons.available(), 3);
+    assert!(cons.peek_slice(4).is_err());
+    unsafe { cons.advance(3) };
+
+    // ...then goes past it.
+    assert_eq!(cons.available(), 10);
+    assert_eq!(
+        cons.peek_slice(10).unwrap().0,
+        &(1..=10).collect::<Vec<_>>()
+    );
+
+    prod.push_slice(&[11, 12]).unwrap();
+    let mut dst = [0; 12];
+    cons.copy_slice(&mut dst).unwrap();
+    assert_eq!(dst, core::array::from_fn(|i| i as u32 + 1));
+    assert_eq!(prod.available(), BUFFER_SIZE - 1);
+}
+
+#[test]
+fn test_reserve_detached() {
+    let mut buf = get_buf!(Shared);
+    let (mut prod, cons) = buf.split();
+
+    prod.push_slice(&[0; BUFFER_SIZE - 5]).unwrap();
+    let mut cons = cons.detach();
+    unsafe { cons.advance(BUFFER_SIZE - 6) };
+    cons.sync_index();
+    unsafe { cons.advance(1) };
+
+    let mut res = prod.reserve(10).unwrap();
+    assert_eq!(res.padding(), 5);
+    res.copy_from_slice(&[1; 10]);
+    drop(res);
+    let avail = prod.available();
+
+    // Going past the skipped tail does not publish the index of the consumer...
+    assert_eq!(cons.available(), 10);
+    assert_eq!(cons.cons_index(), BUFFER_SIZE - 6);
+    assert_eq!(prod.available(), avail);
+
+    unsafe { cons.advance(10) };
+    assert_eq!(prod.available(), avail);
+
+    // Going back skips the tail as well.
+    unsafe { cons.go_back(11) };
+    assert_eq!(cons.index(), BUFFER_SIZE - 6);
+    assert_eq!(cons.available(), 1);
+    unsafe { cons.advance(1) };
+    assert_eq!(cons.available(), 10);
+    unsafe { cons.advance(10) };
+
+    // ...until it is synchronised.
+    cons.sync_index();
+    assert_eq!(cons.cons_index(), 10);
+    assert_eq!(prod.available(), BUFFER_SIZE - 1);
+}
+
+#[test]
+fn test_reserve_latest() {
+    let mut buf = get_buf!(Shared);
+    let (mut prod, mut cons) = buf.split();
+
+    prod.push_slice(&[0; BUFFER_SIZE - 5]).unwrap();
+    unsafe { cons.advance(BUFFER_SIZE - 8) };
+
+    let mut res = prod.reserve(6).unwrap();
+    assert_eq!(res.padding(), 5);
+    res.copy_from_slice(&[1, 2, 3, 4, 5, 6]);
+    drop(res);
+
+    // The newest items are the ones after the skipped tail.
+    assert_eq!(cons.peek_latest(2).unwrap(), (&[5, 6][..], &[][..]));
+    assert_eq!(
+        cons.peek_latest(6).unwrap(),
+        (&[1, 2, 3, 4, 5, 6][..], &[][..])
+    );
+    assert_eq!(
+        cons.peek_latest(7).err(),
+        Some(SliceError::Insufficient {
+            requested: 7,
+            available: 6
+        })
+    );
+
+    // Items before the tail are discarded first...
+    assert_eq!(cons.skip_to_latest(7), 2);
+    assert_eq!(cons.available(), 1);
+
+    // ...then the consumer goes past it.
+    assert_eq!(cons.skip_to_latest(4), 3);
+    assert_eq!(cons.pop().unwrap(), 3);
+
+    assert_eq!(cons.skip_to_latest(1), 2);
+    assert_eq!(cons.pop().unwrap(), 6);
+    assert_eq!(prod.available(), BUFFER_SIZE - 1);
+}
+
+#[test]
+fn test_reserve_errors() {
+    let mut buf = get_buf!(Shared);
+    let (mut prod, mut cons) = buf.split();
+
+    assert!(matches!(
+        prod.reserve(BUFFER_SIZE),
+        Err(SliceError::Insufficient { .. })
+    ));
+
+    prod.push_slice(&[0; BUFFER_SIZE - 5]).unwrap();
+    unsafe { cons.advance(10) };
+
+    // 10 items would fit, but not along with the tail.
+    assert_eq!(prod.available(), 14);
+    assert_eq!(
+        prod.reserve(10).err(),
+        Some(SliceError::Insufficient {
+            requested: 15,
+            available: 14
+        })
+    );
+    assert_eq!(prod.reserve(9).unwrap().padding(), 5);
+    assert_eq!(prod.available(), 0);
+
+    // Aborted reservations do not skip the tail.
+    unsafe { cons.advance(BUFFER_SIZE - 15) };
+    assert_eq!(cons.available(), 9);
+    unsafe { cons.advance(9) };
+    prod.push_slice(&[0; BUFFER_SIZE - 14]).unwrap();
+    unsafe { cons.advance(BUFFER_SIZE - 14) };
+
+    prod.reserve(10).unwrap().abort();
+    prod.push_slice(&[1, 2, 3, 4, 5, 6, 7]).unwrap();
+    assert_eq!(cons.available(), 7);
+    assert_eq!(
+        cons.peek_slice(7).unwrap(),
+        (&[1, 2, 3, 4, 5][..], &[6, 7][..])
+    );
+
+    drop(cons);
+    assert_eq!(
+        prod.reserve(BUFFER_SIZE).err(),
+        Some(SliceError::Disconnected)
+    );
+}
+
+#[test]
+fn test_reserve_threads() {
+    const ITEMS: u32 = 100_000;
+
+    let mut buf = get_buf!(Shared);
+    let (mut prod, mut cons) = buf.split();
+
+    thread::scope(|s| {
+        s.spawn(move || {
+            let mut next = 0;
+
+            while next < ITEMS {
+                let count = (next as usize % 37 + 1).min((ITEMS - next) as usize);
+
+                let Ok(mut res) = prod.reserve(count) else {
+                    thread::yield_now();
+                    continue;
+                };
+
+                for x in res.iter_mut() {
+                    *x = next;
+                    next += 1;
+                }
+            }
+        });
+
+        s.spawn(move || {
+            let mut next = 0;
+
+            while next < ITEMS {
+                match cons.pop() {
+                    Ok(x) => {
+                        assert_eq!(x, next);
+                        next += 1;
+                    }
+                    Err(_) => thread::yield_now(),
+                }
+            }
+        });
+    });
+}
+
+#[test]
+#[should_panic]
+fn test_reserve_lookback() {
+    let mut buf = get_buf!(Shared).with_lookback(4);
+    let (mut prod, _cons) = buf.split();
+
+    let _ = prod.reserve(1);
+}
@@ -0,0 +1,159 @@
+use crate::{common_def, get_buf};
+use oneringbuf::ORBIterator;
+
+common_def!();
+
+#[test]
+fn test_stats_counters() {
+    let mut buf = get_buf!(SharedMut);
+    let (mut prod, mut work, mut cons) = buf.split_mut();
+    let obs = prod.observer();
+    let stats = obs.stats().unwrap();
+
+    assert!(cons.pop().is_err());
+    for i in 0..BUFFER_SIZE - 1 {
+        prod.push(i).unwrap();
+    }
+    assert!(prod.push(0).is_err());
+    assert!(prod.push_slice(&[0; 2]).is_err());
+
+    unsafe { work.advance(10) };
+    for _ in 0..5 {
+        cons.pop().unwrap();
+    }
+
+    assert_eq!(stats.pushed(), BUFFER_SIZE - 1);
+    assert_eq!(stats.worked(), 10);
+    assert_eq!(stats.popped(), 5);
+    assert_eq!(stats.overruns(), 2);
+    assert_eq!(stats.underruns(), 1);
+    assert_eq!(stats.max_fill(), BUFFER_SIZE - 1);
+
+    stats.reset();
+    assert_eq!(stats.pushed(), 0);
+    assert_eq!(stats.max_fill(), 0);
+}
+
+#[test]
+fn test_stats_peek_no_underrun() {
+    let mut buf = get_buf!(Shared);
+    let (mut prod, mut cons) = buf.split();
+    let obs = prod.observer();
+    let stats = obs.stats().unwrap();
+
+    prod.push(1).unwrap();
+    assert!(cons.peek_slice(3).is_err());
+    assert!(cons.peek_slice_at(0, 4).is_err());
+    assert!(cons.peek_at(1).is_none());
+    assert!(cons.peek_latest(2).is_err());
+    assert!(cons.read_grant(2).is_err());
+    assert!(cons.windows(2, 1).next_window().is_none());
+    assert_eq!(stats.underruns(), 0);
+
+    // Failing to pop is still counted.
+    assert!(cons.copy_slice(&mut [0; 2]).is_err());
+    assert_eq!(stats.underruns(), 1);
+}
+
+#[test]
+fn test_stats_pipeline_stages() {
+    let mut buf = get_buf!(SharedPipeline, 3);
+    let (mut prod, [mut first, mut second, mut third], _cons) = buf.split_pipeline();
+    let obs = prod.observer();
+    let stats = obs.stats().unwrap();
+
+    prod.push_slice(&[0; 10]).unwrap();
+    unsafe {
+        first.advance(10);
+        second.advance(6);
+        third.advance(2);
+    }
+
+    assert_eq!(stats.worked(), 10);
+    assert_eq!(stats.stage_worked(0), Some(10));
+    assert_eq!(stats.stage_worked(1), Some(6));
+    assert_eq!(stats.stage_worked(2), Some(2));
+    assert_eq!(stats.stage_worked(3), None);
+
+    stats.reset();
+    assert_eq!(stats.stage_worked(1), Some(0));
+}
+
+#[test]
+fn test_stats_unsupported() {
+    let mut buf = get_buf!(Mpsc);
+    let (_prod, cons) = buf.split_mpsc();
+
+    assert!(cons.stats().is_none());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_stats_latency() {
+    let mut buf = get_buf!(Shared).with_latency_stats();
+    let (mut prod, mut cons) = buf.split();
+
+    for i in 0..10 {
+        prod.push(i).unwrap();
+    }
+    std::thread::sleep(std::time::Duration::from_millis(1));
+    for _ in 0..10 {
+        cons.pop().unwrap();
+    }
+
+    let histogram = cons.stats().unwrap().latency_histogram();
+    assert_eq!(histogram.iter().sum::<usize>(), 10);
+    // Every item spent at least a millisecond in the buffer.
+    assert_eq!(histogram[..20].iter().sum::<usize>(), 0);
+}
+
+#[test]
+fn test_stats_drain_detached() {
+    let mut buf = get_buf!(SharedMut);
+    let (mut prod, mut work, mut cons) = buf.split_mut();
+    let obs = prod.observer();
+    let stats = obs.stats().unwrap();
+
+    prod.push_slice(&[0; 10]).unwrap();
+    assert_eq!(work.for_each_mut(|_| {}), 10);
+    assert_eq!(stats.worked(), 10);
+
+    assert_eq!(cons.drain_cloned().take(3).count(), 3);
+    assert_eq!(stats.popped(), 3);
+
+    let mut detached = cons.detach();
+    unsafe { detached.advance(2) };
+    assert_eq!(stats.popped(), 3);
+    detached.sync_index();
+    assert_eq!(stats.popped(), 5);
+
+    let mut cons = detached.attach();
+    cons.reset_index();
+    assert_eq!(stats.popped(), 10);
+
+    prod.push_slice(&[0; 4]).unwrap();
+    work.reset_index();
+    assert_eq!(stats.worked(), 14);
+}
+
+#[cfg(not(all(feature = "vmem", unix)))]
+#[test]
+fn test_stats_reserve_padding() {
+    let mut buf = get_buf!(Shared);
+    let (mut prod, mut cons) = buf.split();
+    let obs = prod.observer();
+    let stats = obs.stats().unwrap();
+
+    prod.push_slice(&[0; BUFFER_SIZE - 5]).unwrap();
+    unsafe { cons.advance(BUFFER_SIZE - 5) };
+
+    // The skipped tail is counted on both sides.
+    let res = prod.reserve(10).unwrap();
+    assert_eq!(res.padding(), 5);
+    drop(res);
+    assert_eq!(stats.pushed(), BUFFER_SIZE + 10);
+
+    assert_eq!(cons.available(), 10);
+    unsafe { cons.advance(10) };
+    assert_eq!(stats.popped(), stats.pushed());
+}
@@ -0,0 +1,122 @@
+use crate::common_def;
+use oneringbuf::{ORBIterator, SharedStackRB, SharedStackRBMut};
+#[cfg(feature = "alloc")]
+use oneringbuf::{SharedHeapRB, SharedHeapRBMut};
+
+common_def!();
+
+#[test]
+fn test_unsplit_stack() {
+    let mut buf = SharedStackRB::from([0; BUFFER_SIZE]);
+    let (mut prod, mut cons) = buf.split();
+
+    for i in 0..10 {
+        prod.push(i).unwrap();
+    }
+    for i in 0..4 {
+        assert_eq!(cons.pop(), Ok(i));
+    }
+
+    let buf = SharedStackRB::unsplit(prod, cons).ok().unwrap();
+    let (mut prod, mut cons) = buf.split();
+
+    assert_eq!(prod.alive_iters(), 2);
+    assert_eq!(cons.available(), 6);
+    prod.push(10).unwrap();
+    for i in 4..11 {
+        assert_eq!(cons.pop(), Ok(i));
+    }
+
+    let buf = SharedStackRB::unsplit(prod, cons).ok().unwrap();
+    buf.reset();
+    let (mut prod, mut cons) = buf.split();
+    assert_eq!(cons.available(), 0);
+    assert_eq!(prod.available(), BUFFER_SIZE - 1);
+}
+
+#[test]
+fn test_unsplit_mut_stack() {
+    let mut buf = SharedStackRBMut::from([0; BUFFER_SIZE]);
+    let (mut prod, mut work, cons) = buf.split_mut();
+
+    for i in 0..10 {
+        prod.push(i).unwrap();
+    }
+    for _ in 0..5 {
+        *work.get_mut().unwrap() += 1;
+        unsafe { work.advance(1) };
+    }
+
+    let buf = SharedStackRBMut::unsplit_mut(prod, work, cons)
+        .ok()
+        .unwrap();
+    let (_prod, mut work, mut cons) = buf.split_mut();
+
+    assert_eq!(work.available(), 5);
+    for i in 0..5 {
+        assert_eq!(cons.pop(), Ok(i + 1));
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_unsplit_mismatch() {
+    let mut first = SharedHeapRB::from(vec![0; BUFFER_SIZE]);
+    let mut second = SharedHeapRB::from(vec![0; BUFFER_SIZE]);
+    let (prod, _) = first.split();
+    let (_, cons) = second.split();
+
+    let (prod, cons) = SharedHeapRB::unsplit(prod, cons).err().unwrap();
+    assert_eq!(prod.alive_iters(), 1);
+    assert_eq!(cons.alive_iters(), 1);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_unsplit_heap() {
+    let mut buf = SharedHeapRBMut::from(vec![0; BUFFER_SIZE]);
+    let (mut prod, mut work, cons) = buf.split_mut();
+
+    prod.push(1).unwrap();
+    unsafe { work.advance(1) };
+
+    let mut buf = SharedHeapRBMut::unsplit_mut(prod, work, cons).ok().unwrap();
+    let (_prod, _work, mut cons) = buf.split_mut();
+
+    assert_eq!(cons.alive_iters(), 3);
+    assert_eq!(cons.pop(), Ok(1));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_into_vec() {
+    let mut buf = SharedHeapRB::from(vec![0; BUFFER_SIZE]);
+    let (mut prod, mut cons) = buf.split();
+
+    // Wrap around the end of the buffer.
+    for i in 0..BUFFER_SIZE - 1 {
+        prod.push(i).unwrap();
+    }
+    for i in 0..BUFFER_SIZE / 2 {
+        assert_eq!(cons.pop(), Ok(i));
+    }
+    for i in 0..BUFFER_SIZE / 4 {
+        prod.push(BUFFER_SIZE - 1 + i).unwrap();
+    }
+
+    let buf = SharedHeapRB::unsplit(prod, cons).ok().unwrap();
+    let expected: Vec<_> = (BUFFER_SIZE / 2..BUFFER_SIZE - 1 + BUFFER_SIZE / 4).collect();
+    assert_eq!(buf.into_vec(), expected);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_unsplit_with_observer() {
+    let mut buf = SharedHeapRB::from(vec![0; BUFFER_SIZE]);
+    let (prod, cons) = buf.split();
+    let obs = prod.observer();
+
+    let (prod, cons) = SharedHeapRB::unsplit(prod, cons).err().unwrap();
+    drop(obs);
+    assert!(SharedHeapRB::unsplit(prod, cons).is_ok());
+}
@@ -0,0 +1,178 @@
+extern crate alloc;
+
+use crate::{common_def, get_buf};
+use oneringbuf::{CancelToken, ORBIterator, WaitStrategy};
+use std::thread;
+#[cfg(feature = "std")]
+use std::time::{Duration, Instant};
+
+common_def!();
+
+fn blocking_mt(strategy: WaitStrategy) {
+    const COUNT: usize = 2_000;
+
+    let mut buf = get_buf!(Shared).with_wait_strategy(strategy);
+    assert_eq!(buf.wait_strategy(), strategy);
+    let (mut prod, mut cons) = buf.split();
+
+    thread::scope(|s| {
+        s.spawn(move || {
+            for i in 0..COUNT {
+                prod.push_blocking(i).unwrap();
+            }
+        });
+
+        s.spawn(move || {
+            let mut dst = [0; 3];
+            let mut i = 0;
+
+            while i < COUNT {
+                match COUNT - i >= dst.len() && i % 2 == 0 {
+                    true => {
+                        cons.copy_slice_blocking(&mut dst).unwrap();
+                        assert_eq!(dst, [i, i + 1, i + 2]);
+                        i += dst.len();
+                    }
+                    false => {
+                        assert_eq!(cons.pop_blocking(), Ok(i));
+                        i += 1;
+                    }
+                }
+            }
+        });
+    });
+}
+
+#[test]
+fn test_default_strategy() {
+    let buf = get_buf!(Shared);
+    assert_eq!(buf.wait_strategy(), WaitStrategy::Spin);
+}
+
+#[test]
+fn test_blocking_spin() {
+    blocking_mt(WaitStrategy::Spin);
+}
+
+#[test]
+fn test_blocking_backoff() {
+    blocking_mt(WaitStrategy::Backoff);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_blocking_yield() {
+    blocking_mt(WaitStrategy::Yield);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_blocking_park() {
+    blocking_mt(WaitStrategy::Park);
+}
+
+#[test]
+fn test_wait_for() {
+    let mut buf = get_buf!(Shared).with_wait_strategy(WaitStrategy::Backoff);
+    let (mut prod, mut cons) = buf.split();
+
+    prod.wait_for(BUFFER_SIZE - 1);
+    prod.push_slice(&[1; 10]).unwrap();
+
+    cons.wait_for(10);
+    let mut dst = [0; 10];
+    cons.copy_slice_blocking(&mut dst).unwrap();
+    assert_eq!(dst, [1; 10]);
+}
+
+#[test]
+#[should_panic]
+fn test_copy_slice_blocking_too_long() {
+    let mut buf = get_buf!(Shared);
+    let (_prod, mut cons) = buf.split();
+
+    let mut dst = [0; BUFFER_SIZE];
+    let _ = cons.copy_slice_blocking(&mut dst);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_wait_for_timeout() {
+    for strategy in [WaitStrategy::Backoff, WaitStrategy::Park] {
+        let mut buf = get_buf!(Shared).with_wait_strategy(strategy);
+        let (mut prod, mut cons) = buf.split();
+
+        let start = Instant::now();
+        assert!(!cons.wait_for_timeout(1, Duration::from_millis(20)));
+        assert!(start.elapsed() >= Duration::from_millis(20));
+
+        prod.push(1).unwrap();
+        assert!(cons.wait_for_timeout(1, Duration::MAX));
+        assert!(!cons.wait_for_until(2, Instant::now()));
+
+        let mut cons = cons.detach();
+        assert!(cons.wait_for_until(1, Instant::now()));
+        assert!(!cons.wait_for_timeout(2, Duration::ZERO));
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_wait_for_timeout_woken() {
+    let mut buf = get_buf!(Shared).with_wait_strategy(WaitStrategy::Park);
+    let (mut prod, mut cons) = buf.split();
+
+    thread::scope(|s| {
+        s.spawn(move || assert!(cons.wait_for_timeout(2, Duration::from_secs(60))));
+
+        s.spawn(move || {
+            prod.push_blocking(1).unwrap();
+            prod.push_blocking(2).unwrap();
+        });
+    });
+}
+
+#[test]
+fn test_wait_for_cancellable() {
+    let token = CancelToken::new();
+
+    let mut buf = get_buf!(Shared);
+    let (mut prod, mut cons) = buf.split();
+
+    prod.push(1).unwrap();
+    assert!(cons.wait_for_cancellable(1, &token));
+
+    token.cancel();
+    assert!(token.is_cancelled());
+    assert!(!cons.wait_for_cancellable(2, &token));
+    assert!(!prod.detach().wait_for_cancellable(BUFFER_SIZE, &token));
+
+    token.reset();
+    assert!(cons.wait_for_cancellable(1, &token));
+}
+
+fn cancel_mt(strategy: WaitStrategy) {
+    let token = CancelToken::new();
+
+    let mut buf = get_buf!(Shared).with_wait_strategy(strategy);
+    let (mut prod, mut cons) = buf.split();
+
+    prod.push_slice(&[0; BUFFER_SIZE - 1]).unwrap();
+
+    thread::scope(|s| {
+        let token = &token;
+
+        s.spawn(move || assert!(!prod.wait_for_cancellable(1, token)));
+        s.spawn(move || assert!(!cons.wait_for_cancellable(BUFFER_SIZE, token)));
+        s.spawn(move || token.cancel());
+    });
+}
+
+#[test]
+fn test_cancel_mt() {
+    cancel_mt(WaitStrategy::Backoff);
+    #[cfg(feature = "std")]
+    cancel_mt(WaitStrategy::Yield);
+    #[cfg(feature = "std")]
+    cancel_mt(WaitStrategy::Park);
+}
@@ -10,7 +10,7 @@ const MULTIPLE: usize = 42;
 
 fn fill_buf(prod: &mut ProdIter<impl IntoRef + OneRB<Item = usize>>) {
     let slice = (0..BUFFER_SIZE - 1).collect::<Vec<usize>>();
-    prod.push_slice(&slice);
+    prod.push_slice(&slice).unwrap();
 }
 
 #[test]
@@ -140,7 +140,7 @@ fn test_work_exact() {
     let max = 30;
     #[cfg(not(all(feature = "vmem", unix)))]
     for _ in 0..max {
-        if let Some((h, t)) = work.get_mut_slice_exact(step) {
+        if let Ok((h, t)) = work.get_mut_slice_exact(step) {
             let len = h.len() + t.len();
 
             h.iter_mut().for_each(|v| *v += 1);
@@ -151,7 +151,7 @@ fn test_work_exact() {
     }
     #[cfg(all(feature = "vmem", unix))]
     for _ in 0..max {
-        if let Some(s) = work.get_mut_slice_exact(step) {
+        if let Ok(s) = work.get_mut_slice_exact(step) {
             s.iter_mut().for_each(|v| *v += 1);
             unsafe { work.advance(s.len()) };
         }
@@ -169,3 +169,132 @@ fn test_work_exact() {
     assert_eq!(work.available(), BUFFER_SIZE - 1 - max * step);
     assert_eq!(cons.available(), 0);
 }
+
+#[test]
+fn test_for_each_mut() {
+    let mut buf = get_buf!(SharedMut);
+    let (mut prod, mut work, mut cons) = buf.split_mut();
+
+    fill_buf(&mut prod);
+
+    assert_eq!(work.for_each_mut(|x| *x *= 2), BUFFER_SIZE - 1);
+    assert_eq!(work.available(), 0);
+    assert_eq!(work.for_each_mut(|_| unreachable!()), 0);
+
+    let mut dst = vec![0; BUFFER_SIZE / 2];
+    cons.copy_slice(&mut dst).unwrap();
+    assert!(dst.into_iter().eq((0..BUFFER_SIZE / 2).map(|i| i * 2)));
+
+    // Items cross the end of the buffer.
+    prod.push_slice(&vec![0; BUFFER_SIZE / 2]).unwrap();
+    assert_eq!(work.for_each_mut(|x| *x += 1), BUFFER_SIZE / 2);
+
+    let mut dst = vec![0; BUFFER_SIZE - 1];
+    cons.copy_slice(&mut dst).unwrap();
+    assert_eq!(dst[BUFFER_SIZE / 2 - 2], (BUFFER_SIZE - 2) * 2);
+    assert!(dst[BUFFER_SIZE / 2 - 1..].iter().all(|&x| x == 1));
+}
+
+#[test]
+fn test_iter_mut() {
+    let mut buf = get_buf!(SharedMut);
+    let (mut prod, mut work, mut cons) = buf.split_mut();
+
+    fill_buf(&mut prod);
+
+    let mut refs: Vec<_> = work.iter_mut().take(BUFFER_SIZE / 2).collect();
+    refs.iter_mut().for_each(|x| **x *= 2);
+    assert_eq!(work.available(), BUFFER_SIZE / 2 - 1);
+    // The index is published only once the worker is synchronised.
+    assert_eq!(cons.available(), 0);
+    work.sync_index();
+    assert_eq!(cons.available(), BUFFER_SIZE / 2);
+
+    for x in work.iter_mut() {
+        *x += 1;
+    }
+    assert_eq!(work.available(), 0);
+    assert_eq!(cons.available(), BUFFER_SIZE / 2);
+    unsafe { work.advance(0) };
+
+    let mut dst = vec![0; BUFFER_SIZE - 1];
+    cons.copy_slice(&mut dst).unwrap();
+    for (i, x) in dst.into_iter().enumerate() {
+        assert_eq!(x, if i < BUFFER_SIZE / 2 { i * 2 } else { i + 1 });
+    }
+}
+
+#[cfg(not(all(feature = "vmem", unix)))]
+#[test]
+fn test_grants() {
+    let mut buf = get_buf!(SharedMut);
+    let (mut prod, mut work, mut cons) = buf.split_mut();
+
+    let mut grant = prod.grant(10).unwrap();
+    assert_eq!(grant.len(), 10);
+    let (head, tail) = grant.slices_mut();
+    for (i, x) in head.iter_mut().chain(tail.iter_mut()).enumerate() {
+        *x = i;
+    }
+    // Only the first 6 items are released.
+    grant.commit(6);
+    assert_eq!(work.available(), 6);
+
+    // Aborted grants leave the iterator where it was.
+    prod.grant(4).unwrap().abort();
+    assert_eq!(work.available(), 6);
+
+    assert!(work.work_grant(7).is_err());
+    {
+        let mut grant = work.work_grant(6).unwrap();
+        grant.iter_mut().for_each(|x| *x *= 2);
+        assert_eq!(grant.slices().0[1], 2);
+        grant[5] += 1;
+        assert_eq!(grant[5], 11);
+        // Nothing is released until the grant is dropped.
+        assert_eq!(cons.available(), 0);
+    }
+    assert_eq!(cons.available(), 6);
+
+    let grant = cons.read_grant(6).unwrap();
+    let (head, tail) = grant.slices();
+    assert!(head.iter().chain(tail).copied().eq([0, 2, 4, 6, 8, 11]));
+    assert_eq!(grant.as_slices(), (head, tail));
+    grant.commit(2);
+    assert_eq!(cons.available(), 4);
+
+    drop(cons.read_grant(4).unwrap());
+    assert_eq!(cons.available(), 0);
+    assert_eq!(prod.available(), BUFFER_SIZE - 1);
+
+    // Items wrap around to the start of the buffer.
+    unsafe {
+        prod.advance(BUFFER_SIZE - 10);
+        work.advance(BUFFER_SIZE - 10);
+        cons.advance(BUFFER_SIZE - 10);
+    }
+    let mut grant = prod.grant(8).unwrap();
+    grant.iter_mut().zip(0..).for_each(|(x, i)| *x = i);
+    let (head, tail) = grant.as_mut_slices();
+    assert_eq!(head.len() + tail.len(), 8);
+    assert_eq!(grant[7], 7);
+    drop(grant);
+
+    unsafe { work.advance(8) };
+    let grant = cons.read_grant(8).unwrap();
+    assert!(grant.iter().copied().eq(0..8));
+    assert_eq!(grant.slices().0.len(), grant.as_slices().0.len());
+}
+
+#[test]
+fn test_grant_commit_too_many() {
+    let mut buf = get_buf!(SharedMut);
+    let (mut prod, _work, _cons) = buf.split_mut();
+
+    // A panicking commit must not publish the whole grant while unwinding.
+    let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        prod.grant(4).unwrap().commit(5);
+    }));
+    assert!(res.is_err());
+    assert_eq!(prod.available(), BUFFER_SIZE - 1);
+}
@@ -9,7 +9,7 @@ const MULTIPLE: usize = 42;
 
 fn fill_buf(prod: &mut ProdIter<impl IntoRef + OneRB<Item = usize>>) {
     let slice = (0..BUFFER_SIZE - 1).collect::<Vec<usize>>();
-    prod.push_slice(&slice);
+    prod.push_slice(&slice).unwrap();
 }
 
 #[test]
@@ -114,7 +114,7 @@ fn test_work_exact() {
     let step = 10;
     let max = 30;
     for _ in 0..max {
-        if let Some(res) = work.get_mut_slice_exact(step) {
+        if let Ok(res) = work.get_mut_slice_exact(step) {
             res.iter_mut().for_each(|v| *v += 1);
             unsafe { work.advance(res.len()) };
         }
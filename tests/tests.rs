@@ -34,6 +34,24 @@ macro_rules! get_buf {
     (SharedMut) => {
         oneringbuf::SharedVmemRBMut::from(vec![0; BUFFER_SIZE])
     };
+    (Mpsc) => {
+        oneringbuf::MpscVmemRB::from(vec![0; BUFFER_SIZE])
+    };
+    (Overwrite) => {
+        oneringbuf::OverwriteVmemRB::from(vec![0; BUFFER_SIZE])
+    };
+    (Owned) => {
+        oneringbuf::OwnedVmemRB::new(BUFFER_SIZE)
+    };
+    (Broadcast, $c: expr) => {
+        oneringbuf::BroadcastVmemRB::<_, $c>::from(vec![0; BUFFER_SIZE])
+    };
+    (LocalPipeline, $k: expr) => {
+        oneringbuf::LocalVmemRBPipeline::<_, $k>::from(vec![0; BUFFER_SIZE])
+    };
+    (SharedPipeline, $k: expr) => {
+        oneringbuf::SharedVmemRBPipeline::<_, $k>::from(vec![0; BUFFER_SIZE])
+    };
 }
 #[cfg(all(feature = "alloc", not(all(feature = "vmem", unix))))]
 macro_rules! get_buf {
@@ -49,6 +67,24 @@ macro_rules! get_buf {
     (SharedMut) => {
         oneringbuf::SharedHeapRBMut::from(vec![0; BUFFER_SIZE])
     };
+    (Mpsc) => {
+        oneringbuf::MpscHeapRB::from(vec![0; BUFFER_SIZE])
+    };
+    (Overwrite) => {
+        oneringbuf::OverwriteHeapRB::from(vec![0; BUFFER_SIZE])
+    };
+    (Owned) => {
+        oneringbuf::OwnedHeapRB::new(BUFFER_SIZE)
+    };
+    (Broadcast, $c: expr) => {
+        oneringbuf::BroadcastHeapRB::<_, $c>::from(vec![0; BUFFER_SIZE])
+    };
+    (LocalPipeline, $k: expr) => {
+        oneringbuf::LocalHeapRBPipeline::<_, $k>::from(vec![0; BUFFER_SIZE])
+    };
+    (SharedPipeline, $k: expr) => {
+        oneringbuf::SharedHeapRBPipeline::<_, $k>::from(vec![0; BUFFER_SIZE])
+    };
 }
 #[cfg(all(not(feature = "alloc"), not(all(feature = "vmem", unix))))]
 macro_rules! get_buf {
@@ -64,8 +100,40 @@ macro_rules! get_buf {
     (SharedMut) => {
         oneringbuf::SharedStackRBMut::from([0; BUFFER_SIZE])
     };
+    (Mpsc) => {
+        oneringbuf::MpscStackRB::from([0; BUFFER_SIZE])
+    };
+    (Overwrite) => {
+        oneringbuf::OverwriteStackRB::from([0; BUFFER_SIZE])
+    };
+    (Owned) => {
+        oneringbuf::OwnedStackRB::<_, BUFFER_SIZE>::new()
+    };
+    (Broadcast, $c: expr) => {
+        oneringbuf::BroadcastStackRB::<_, BUFFER_SIZE, $c>::from([0; BUFFER_SIZE])
+    };
+    (LocalPipeline, $k: expr) => {
+        oneringbuf::LocalStackRBPipeline::<_, BUFFER_SIZE, $k>::from([0; BUFFER_SIZE])
+    };
+    (SharedPipeline, $k: expr) => {
+        oneringbuf::SharedStackRBPipeline::<_, BUFFER_SIZE, $k>::from([0; BUFFER_SIZE])
+    };
+}
+// Splits a broadcast buffer into a producer and a `Vec` of `n` consumers, whatever its storage.
+#[cfg(feature = "alloc")]
+macro_rules! split_broadcast {
+    ($buf: expr, $n: expr) => {
+        $buf.split_broadcast($n)
+    };
+}
+#[cfg(not(feature = "alloc"))]
+macro_rules! split_broadcast {
+    ($buf: expr, $n: expr) => {{
+        let (prod, cons) = $buf.split_broadcast($n);
+        (prod, cons.into_iter().flatten().collect::<Vec<_>>())
+    }};
 }
-pub(crate) use {common_def, get_buf};
+pub(crate) use {common_def, get_buf, split_broadcast};
 
 #[cfg(feature = "alloc")]
 #[test]